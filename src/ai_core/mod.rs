@@ -6,7 +6,11 @@ pub mod multi_layer_security;
 pub use multi_layer_security::{
     MultiLayerSecurityEngine,
     TransactionRiskProfile,
+    TransactionRiskProfileBuilder,
+    MAX_FEE_TO_AMOUNT_RATIO,
+    TransactionClass,
     ThreatAssessment,
+    ThreatAssessor,
     ThreatType,
     RiskLevel,
     SecurityAction,
@@ -8,13 +8,45 @@ use std::sync::Arc;
 use parking_lot::RwLock;
 use std::time::{SystemTime, UNIX_EPOCH};
 use crate::error::AxiomError;
+use crate::guardian::SovereignInvariants;
 
 const ANOMALY_MEMORY_SIZE: usize = 10000;
 const BEHAVIORAL_ANALYSIS_WINDOW: usize = 1000;
 const THREAT_INTELLIGENCE_CACHE: usize = 5000;
 
+/// Largest multiple of the transferred amount a fee may reach before the
+/// profile is considered malformed
+pub const MAX_FEE_TO_AMOUNT_RATIO: u64 = 1000;
+
 // ==================== THREAT CLASSIFICATION ====================
 
+/// Origin of a transaction; only user transactions go through AI assessment.
+/// Derived from the transaction's position in its block, never from the
+/// transaction itself, so a sender can't claim a trusted class.
+///
+/// There is deliberately no `System` class for internal protocol
+/// transactions: nothing in a block identifies them without trusting the
+/// transaction's own claim. One can be added once consensus defines where
+/// they sit in a block, the way it does for the coinbase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TransactionClass {
+    #[default]
+    User,
+    /// Block reward paid to the miner
+    Coinbase,
+}
+
+impl TransactionClass {
+    /// Class of the transaction at `index` in a block: the first is the coinbase
+    pub fn at_block_position(index: usize) -> Self {
+        if index == 0 {
+            TransactionClass::Coinbase
+        } else {
+            TransactionClass::User
+        }
+    }
+}
+
 /// Comprehensive transaction risk profile
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionRiskProfile {
@@ -37,6 +69,163 @@ pub struct TransactionRiskProfile {
     pub vdf_verification_time_ms: u64,
 }
 
+impl TransactionRiskProfile {
+    pub fn builder() -> TransactionRiskProfileBuilder {
+        TransactionRiskProfileBuilder::default()
+    }
+}
+
+/// Guarded constructor for `TransactionRiskProfile`. `build()` rejects
+/// profiles that could never describe a real transaction so they are
+/// caught before reaching the guardian bridge.
+#[derive(Debug, Clone)]
+pub struct TransactionRiskProfileBuilder {
+    profile: TransactionRiskProfile,
+}
+
+impl Default for TransactionRiskProfileBuilder {
+    fn default() -> Self {
+        Self {
+            profile: TransactionRiskProfile {
+                hash: String::new(),
+                timestamp: 0,
+                sender: String::new(),
+                recipient: String::new(),
+                amount: 0,
+                gas_price: 0,
+                zk_proof_size: 0,
+                sender_history_count: 0,
+                recipient_history_count: 0,
+                sender_reputation_score: 0.5,
+                time_since_last_sender_tx: 0,
+                time_since_last_recipient_tx: 0,
+                is_contract_deployment: false,
+                contract_bytecode_size: 0,
+                vdf_verification_time_ms: 0,
+            },
+        }
+    }
+}
+
+impl TransactionRiskProfileBuilder {
+    pub fn hash(mut self, hash: impl Into<String>) -> Self {
+        self.profile.hash = hash.into();
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: u64) -> Self {
+        self.profile.timestamp = timestamp;
+        self
+    }
+
+    pub fn sender(mut self, sender: impl Into<String>) -> Self {
+        self.profile.sender = sender.into();
+        self
+    }
+
+    pub fn recipient(mut self, recipient: impl Into<String>) -> Self {
+        self.profile.recipient = recipient.into();
+        self
+    }
+
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.profile.amount = amount;
+        self
+    }
+
+    pub fn gas_price(mut self, gas_price: u64) -> Self {
+        self.profile.gas_price = gas_price;
+        self
+    }
+
+    pub fn zk_proof_size(mut self, size: usize) -> Self {
+        self.profile.zk_proof_size = size;
+        self
+    }
+
+    pub fn sender_history_count(mut self, count: u64) -> Self {
+        self.profile.sender_history_count = count;
+        self
+    }
+
+    pub fn recipient_history_count(mut self, count: u64) -> Self {
+        self.profile.recipient_history_count = count;
+        self
+    }
+
+    pub fn sender_reputation_score(mut self, score: f64) -> Self {
+        self.profile.sender_reputation_score = score;
+        self
+    }
+
+    pub fn time_since_last_sender_tx(mut self, secs: u64) -> Self {
+        self.profile.time_since_last_sender_tx = secs;
+        self
+    }
+
+    pub fn time_since_last_recipient_tx(mut self, secs: u64) -> Self {
+        self.profile.time_since_last_recipient_tx = secs;
+        self
+    }
+
+    /// Marks the transaction as a contract deployment with the given bytecode size
+    pub fn contract_deployment(mut self, bytecode_size: usize) -> Self {
+        self.profile.is_contract_deployment = true;
+        self.profile.contract_bytecode_size = bytecode_size;
+        self
+    }
+
+    pub fn vdf_verification_time_ms(mut self, ms: u64) -> Self {
+        self.profile.vdf_verification_time_ms = ms;
+        self
+    }
+
+    pub fn build(self) -> Result<TransactionRiskProfile, AxiomError> {
+        let p = self.profile;
+
+        if p.hash.is_empty() || p.sender.is_empty() || p.recipient.is_empty() {
+            return Err(AxiomError::InvalidTransaction(
+                "Risk profile requires hash, sender and recipient".to_string(),
+            ));
+        }
+        if p.amount > SovereignInvariants::MAX_TOTAL_SUPPLY {
+            return Err(AxiomError::InvalidTransaction(format!(
+                "Amount {} exceeds total supply {}",
+                p.amount, SovereignInvariants::MAX_TOTAL_SUPPLY
+            )));
+        }
+        let total = match p.amount.checked_add(p.gas_price) {
+            Some(total) if total <= SovereignInvariants::MAX_TOTAL_SUPPLY => total,
+            _ => {
+                return Err(AxiomError::InvalidTransaction(format!(
+                    "Amount {} plus fee {} exceeds total supply",
+                    p.amount, p.gas_price
+                )));
+            }
+        };
+        // Zero-value transfers (contract calls, deployments) only carry the fee
+        if p.amount > 0 && p.gas_price / p.amount >= MAX_FEE_TO_AMOUNT_RATIO {
+            return Err(AxiomError::InvalidTransaction(format!(
+                "Fee {} is more than {}x the amount {} (total {})",
+                p.gas_price, MAX_FEE_TO_AMOUNT_RATIO, p.amount, total
+            )));
+        }
+        if !(0.0..=1.0).contains(&p.sender_reputation_score) {
+            return Err(AxiomError::InvalidTransaction(format!(
+                "Sender reputation {} outside [0, 1]",
+                p.sender_reputation_score
+            )));
+        }
+        if !p.is_contract_deployment && p.contract_bytecode_size > 0 {
+            return Err(AxiomError::InvalidTransaction(
+                "Bytecode size set on a non-deployment transaction".to_string(),
+            ));
+        }
+
+        Ok(p)
+    }
+}
+
 /// Multi-dimensional threat types
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum ThreatType {
@@ -106,6 +295,35 @@ pub struct ThreatAssessment {
     pub guardian_override_required: bool,
 }
 
+/// Source of transaction threat assessments. The guardian bridge depends on
+/// this rather than on the concrete engine so alternative or mock engines
+/// can be plugged in.
+pub trait ThreatAssessor: Send + Sync {
+    fn assess_transaction_threat(
+        &self,
+        profile: &TransactionRiskProfile,
+        current_block_height: u64,
+    ) -> Result<ThreatAssessment, AxiomError>;
+
+    /// Threat score (0-100) at which this assessor starts recommending
+    /// `AcceptWithMonitoring`, if it uses a fixed threshold
+    fn monitoring_threshold(&self) -> Option<f64> {
+        None
+    }
+
+    /// Threat scores (0-100) at which this assessor's recommended action
+    /// changes; defaults to the monitoring threshold alone
+    fn action_thresholds(&self) -> Vec<f64> {
+        self.monitoring_threshold().into_iter().collect()
+    }
+
+    /// Reject a configuration that contradicts `SovereignInvariants`;
+    /// checked whenever a bridge is built around the assessor
+    fn validate_against_manifest(&self) -> Result<(), AxiomError> {
+        Ok(())
+    }
+}
+
 // ==================== CORE SECURITY ENGINE ====================
 
 pub struct MultiLayerSecurityEngine {
@@ -144,6 +362,47 @@ impl Default for SecurityConfig {
     }
 }
 
+impl SecurityConfig {
+    /// Reject thresholds that would keep the Guardian from ever seeing a
+    /// threat: every threshold a finite fraction in (0, 1], ordered
+    /// monitoring ≤ quarantine ≤ escalation (a band above the next one is
+    /// unreachable), and an assessment budget inside the manifest's
+    /// shortest block interval
+    pub fn validate_against_manifest(&self) -> Result<(), AxiomError> {
+        let thresholds = [
+            ("anomaly_threshold", self.anomaly_threshold),
+            ("auto_quarantine_threshold", self.auto_quarantine_threshold),
+            ("guardian_escalation_threshold", self.guardian_escalation_threshold),
+        ];
+        for (name, value) in thresholds {
+            if !value.is_finite() || value <= 0.0 || value > 1.0 {
+                return Err(AxiomError::InvalidConfig(format!(
+                    "Security {} {} outside (0, 1]: scores never reach it, so the Guardian would never act on it",
+                    name, value
+                )));
+            }
+        }
+        for pair in thresholds.windows(2) {
+            let ((lower, low), (upper, high)) = (pair[0], pair[1]);
+            if low > high {
+                return Err(AxiomError::InvalidConfig(format!(
+                    "Security {} {} above {} {}: the {} band is unreachable",
+                    lower, low, upper, high, lower
+                )));
+            }
+        }
+
+        let budget_ms = SovereignInvariants::ABSOLUTE_MIN_BLOCK_TIME_SECS * 1_000;
+        if self.max_processing_time_ms == 0 || self.max_processing_time_ms >= budget_ms {
+            return Err(AxiomError::InvalidConfig(format!(
+                "Security max_processing_time_ms {} outside (0, {}) ms, the manifest's minimum block interval",
+                self.max_processing_time_ms, budget_ms
+            )));
+        }
+        Ok(())
+    }
+}
+
 // ==================== ANOMALY DETECTION CORE ====================
 
 struct AnomalyDetectionCore {
@@ -331,7 +590,40 @@ struct Cluster {
 
 // ==================== IMPLEMENTATION ====================
 
+impl ThreatAssessor for MultiLayerSecurityEngine {
+    fn assess_transaction_threat(
+        &self,
+        profile: &TransactionRiskProfile,
+        current_block_height: u64,
+    ) -> Result<ThreatAssessment, AxiomError> {
+        MultiLayerSecurityEngine::assess_transaction_threat(self, profile, current_block_height)
+    }
+
+    fn monitoring_threshold(&self) -> Option<f64> {
+        Some(self.config.anomaly_threshold * 100.0)
+    }
+
+    fn validate_against_manifest(&self) -> Result<(), AxiomError> {
+        self.config.validate_against_manifest()
+    }
+
+    fn action_thresholds(&self) -> Vec<f64> {
+        [
+            self.config.anomaly_threshold,
+            self.config.auto_quarantine_threshold,
+            self.config.guardian_escalation_threshold,
+        ]
+        .iter()
+        .map(|threshold| threshold * 100.0)
+        .collect()
+    }
+}
+
 impl MultiLayerSecurityEngine {
+    pub fn config(&self) -> &SecurityConfig {
+        &self.config
+    }
+
     pub fn new(config: SecurityConfig) -> Self {
         Self {
             anomaly_detector: Arc::new(RwLock::new(AnomalyDetectionCore::new())),
@@ -739,4 +1031,37 @@ mod tests {
         let assessment = engine.assess_transaction_threat(&profile, 1000);
         assert!(assessment.is_ok());
     }
+
+    #[test]
+    fn test_risk_profile_builder() {
+        let profile = TransactionRiskProfile::builder()
+            .hash("tx_built")
+            .sender("alice")
+            .recipient("bob")
+            .amount(100_00000000)
+            .gas_price(1000)
+            .sender_reputation_score(0.9)
+            .build()
+            .unwrap();
+        assert_eq!(profile.amount, 100_00000000);
+
+        let engine = MultiLayerSecurityEngine::new(SecurityConfig::default());
+        assert!(engine.assess_transaction_threat(&profile, 1000).is_ok());
+    }
+
+    #[test]
+    fn test_risk_profile_builder_rejects_impossible_inputs() {
+        let base = || TransactionRiskProfile::builder().hash("tx").sender("alice").recipient("bob");
+
+        let over_supply = base().amount(SovereignInvariants::MAX_TOTAL_SUPPLY + 1).build();
+        assert!(matches!(over_supply, Err(AxiomError::InvalidTransaction(_))));
+
+        let overflow = base().amount(SovereignInvariants::MAX_TOTAL_SUPPLY).gas_price(u64::MAX).build();
+        assert!(overflow.is_err());
+
+        let absurd_fee = base().amount(10).gas_price(10 * MAX_FEE_TO_AMOUNT_RATIO).build();
+        assert!(absurd_fee.is_err());
+
+        assert!(base().amount(0).gas_price(1000).build().is_ok());
+    }
 }
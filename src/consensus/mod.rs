@@ -2,7 +2,7 @@
 pub mod vdf;
 pub mod lwma;
 
-pub use vdf::{VDF, VDFProof, VDFBlockHeader};
+pub use vdf::{VDF, VDFProof, VDFBlockHeader, AggregatedProof, CancellationToken, VerificationStrictness};
 pub use lwma::{
     calculate_lwma_difficulty,
     detect_flash_mining,
@@ -2,11 +2,24 @@
 // Implements Wesolowski VDF for deterministic, sequential proof-of-time
 
 use num_bigint::BigUint;
-use num_traits::One;
+use num_traits::{One, Zero};
 use num_integer::Integer;
 use serde::{Serialize, Deserialize, Serializer, Deserializer};
 use sha2::{Sha256, Digest};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
+use crate::error::AxiomError;
+use crate::genesis::GENESIS_TIMESTAMP;
+use crate::guardian::SovereignInvariants;
+
+/// How many squarings run between cancellation checks.
+/// A relaxed atomic load every 4096 squarings is negligible next to the
+/// 2048-bit modular arithmetic, while still aborting within milliseconds.
+const CANCELLATION_CHECK_INTERVAL: u64 = 4_096;
+
+/// How far past the local clock a header timestamp may lie (2 hours)
+const MAX_HEADER_FUTURE_DRIFT_SECS: u64 = 2 * 60 * 60;
 
 // Custom serialization for BigUint
 fn serialize_biguint<S>(value: &BigUint, serializer: S) -> Result<S::Ok, S::Error>
@@ -24,6 +37,31 @@ where
     Ok(BigUint::from_bytes_be(&bytes))
 }
 
+/// Expected hashes per unit of difficulty (2^32), matching the LWMA estimator
+pub const HASHES_PER_DIFFICULTY_UNIT: f64 = 4_294_967_296.0;
+
+/// Shared flag used to abort a running VDF computation from another thread
+/// (e.g. on shutdown or when a reorg makes the current work obsolete)
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation; every clone of this token observes it
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
 /// VDF (Verifiable Delay Function) for time-lock consensus
 /// Uses Wesolowski construction with RSA modulus
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,7 +73,9 @@ pub struct VDF {
     pub time_param: u64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Equality and hashing cover the output and proof, so identical proofs
+/// deduplicate in sets and caches
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct VDFProof {
     /// Output: y = x^(2^T) mod N
     #[serde(serialize_with = "serialize_biguint", deserialize_with = "deserialize_biguint")]
@@ -45,6 +85,39 @@ pub struct VDFProof {
     pub proof: BigUint,
 }
 
+/// One Wesolowski proof covering a whole segment of VDF evaluations
+///
+/// For evaluations `y_i = x_i^(2^T)`, Fiat-Shamir weights `α_i` are derived
+/// from every `(x_i, y_i)` and the segment is folded into a single claim
+/// `Y = X^(2^T)` with `X = Π x_i^α_i` and `Y = Π y_i^α_i`, proven by one
+/// Wesolowski proof.
+///
+/// Security assumptions:
+/// - The modulus comes from a trusted setup and its factorization (group
+///   order) is unknown to everyone; otherwise proofs can be forged.
+/// - The adaptive root assumption holds in the RSA group (as for any
+///   Wesolowski proof).
+/// - The weights are 128-bit, so a segment containing a wrong output
+///   passes with probability about 2^-128. Outputs are only bound up to
+///   elements of small order (e.g. `-1 mod N`), a limitation shared with
+///   the single-proof check.
+/// - The challenge inherits `hash_to_prime_challenge`, which currently
+///   returns an odd number rather than a proven prime.
+///
+/// Building an aggregate costs about one VDF evaluation for the whole
+/// segment. Verifying costs one Wesolowski check plus two short
+/// exponentiations per block, instead of a full proof check per block.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregatedProof {
+    /// Squarings per evaluation (T) shared by every constituent
+    pub iterations: u64,
+    /// Individual outputs `y_i`, in segment order
+    pub outputs: Vec<BigUint>,
+    /// Wesolowski proof for the folded claim `Y = X^(2^T)`
+    #[serde(serialize_with = "serialize_biguint", deserialize_with = "deserialize_biguint")]
+    pub proof: BigUint,
+}
+
 impl VDF {
     /// Create new VDF with RSA-2048 modulus (from trusted setup ceremony)
     pub fn new(modulus: BigUint, time_param: u64) -> Self {
@@ -68,6 +141,18 @@ impl VDF {
     /// This is SLOW by design (sequential squaring)
     /// Takes ~1 hour for T calibrated to target block time
     pub fn compute(&self, input: &[u8]) -> Result<VDFProof, String> {
+        self.compute_cancellable(input, &CancellationToken::new())
+            .map_err(|e| e.to_string())
+    }
+
+    /// Compute VDF like `compute`, but abort early once `cancel` is set.
+    /// The token is polled every `CANCELLATION_CHECK_INTERVAL` squarings and
+    /// `AxiomError::Cancelled` is returned without generating a proof.
+    pub fn compute_cancellable(
+        &self,
+        input: &[u8],
+        cancel: &CancellationToken,
+    ) -> Result<VDFProof, AxiomError> {
         // Hash input to get starting point
         let x = self.hash_to_prime(input);
         
@@ -77,6 +162,11 @@ impl VDF {
         // Compute y = x^(2^T) mod N via repeated squaring
         let mut y = x.clone();
         for i in 0..self.time_param {
+            if i % CANCELLATION_CHECK_INTERVAL == 0 && cancel.is_cancelled() {
+                println!("VDF: Cancelled after {}/{} steps", i, self.time_param);
+                return Err(AxiomError::Cancelled);
+            }
+
             y = (&y * &y) % &self.modulus;
             
             if i % 1_000_000 == 0 && i > 0 {
@@ -91,7 +181,9 @@ impl VDF {
         println!("VDF: Completed in {:.2}s", elapsed.as_secs_f64());
         
         // Generate proof (Wesolowski)
-        let proof = self.generate_proof(&x, &y)?;
+        let proof = self
+            .generate_proof(&x, &y)
+            .map_err(AxiomError::VDFComputationFailed)?;
         
         Ok(VDFProof {
             output: y,
@@ -121,6 +213,88 @@ impl VDF {
         Ok(lhs == *y)
     }
     
+    /// Fold a verified segment of proofs into one `AggregatedProof`.
+    /// Every constituent is checked first; an invalid one aborts aggregation.
+    pub fn aggregate_proofs(
+        &self,
+        inputs: &[&[u8]],
+        proofs: &[VDFProof],
+    ) -> Result<AggregatedProof, AxiomError> {
+        if inputs.len() != proofs.len() || proofs.is_empty() {
+            return Err(AxiomError::VDFComputationFailed(format!(
+                "Cannot aggregate {} proofs over {} inputs",
+                proofs.len(),
+                inputs.len()
+            )));
+        }
+
+        for (index, (input, proof)) in inputs.iter().zip(proofs).enumerate() {
+            if !self.verify(input, proof).unwrap_or(false) {
+                return Err(AxiomError::VDFComputationFailed(format!(
+                    "Proof {} in segment is invalid",
+                    index
+                )));
+            }
+        }
+
+        let outputs: Vec<BigUint> = proofs.iter().map(|p| p.output.clone()).collect();
+        let (x, y) = self.fold_segment(inputs, &outputs);
+        let proof = self
+            .generate_proof(&x, &y)
+            .map_err(AxiomError::VDFComputationFailed)?;
+
+        Ok(AggregatedProof {
+            iterations: self.time_param,
+            outputs,
+            proof,
+        })
+    }
+
+    /// Verify a segment aggregate against its inputs with a single
+    /// Wesolowski check. See `AggregatedProof` for the security assumptions.
+    pub fn verify_aggregate(&self, aggregate: &AggregatedProof, inputs: &[&[u8]], iterations: u64) -> bool {
+        if aggregate.iterations != iterations
+            || aggregate.outputs.len() != inputs.len()
+            || inputs.is_empty()
+        {
+            return false;
+        }
+
+        let (x, y) = self.fold_segment(inputs, &aggregate.outputs);
+        let ell = self.hash_to_prime_challenge(&x, &y);
+        let r = BigUint::from(2u32).modpow(&BigUint::from(iterations), &ell);
+
+        let lhs = (aggregate.proof.modpow(&ell, &self.modulus) * x.modpow(&r, &self.modulus)) % &self.modulus;
+        lhs == y
+    }
+
+    /// `(Π x_i^α_i, Π y_i^α_i)` with 128-bit Fiat-Shamir weights bound to
+    /// every input and output in the segment
+    fn fold_segment(&self, inputs: &[&[u8]], outputs: &[BigUint]) -> (BigUint, BigUint) {
+        let xs: Vec<BigUint> = inputs.iter().map(|input| self.hash_to_prime(input)).collect();
+
+        let mut transcript = Sha256::new();
+        for (x, y) in xs.iter().zip(outputs) {
+            transcript.update(x.to_bytes_be());
+            transcript.update(y.to_bytes_be());
+        }
+        let seed = transcript.finalize();
+
+        let mut x_acc = BigUint::one();
+        let mut y_acc = BigUint::one();
+        for (index, (x, y)) in xs.iter().zip(outputs).enumerate() {
+            let mut hasher = Sha256::new();
+            hasher.update(seed);
+            hasher.update((index as u64).to_le_bytes());
+            let alpha = BigUint::from_bytes_be(&hasher.finalize()[..16]);
+
+            x_acc = (x_acc * x.modpow(&alpha, &self.modulus)) % &self.modulus;
+            y_acc = (y_acc * y.modpow(&alpha, &self.modulus)) % &self.modulus;
+        }
+
+        (x_acc, y_acc)
+    }
+
     /// Generate Wesolowski proof: π = x^q mod N where q = floor(2^T / ℓ)
     fn generate_proof(&self, x: &BigUint, y: &BigUint) -> Result<BigUint, String> {
         // Challenge ℓ = H(x, y)
@@ -164,6 +338,15 @@ impl VDF {
         candidate
     }
     
+    /// Canonical network hashrate estimate (H/s) for a block:
+    /// `difficulty * HASHES_PER_DIFFICULTY_UNIT / block_time`.
+    /// Every node must derive `hashrate_estimate` this way so telemetry agrees.
+    /// A zero block time (equal timestamps) is treated as one second.
+    pub fn estimate_hashrate(difficulty: u64, block_time: u64) -> f64 {
+        let block_time = block_time.max(1) as f64;
+        difficulty as f64 * HASHES_PER_DIFFICULTY_UNIT / block_time
+    }
+    
     /// Calibrate time_param to target a specific wall-clock duration
     /// Run this on representative hardware to determine optimal T
     pub fn calibrate(target_duration: Duration) -> u64 {
@@ -193,13 +376,35 @@ impl VDF {
     }
 }
 
+/// How thoroughly `VDFBlockHeader::verify_with` checks a header's proof
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum VerificationStrictness {
+    /// Structural checks plus the Wesolowski proof check (archival nodes)
+    #[default]
+    Full,
+    /// Well-formedness, input binding and iteration bounds only; the proof
+    /// itself is trusted (nodes syncing from a trusted checkpoint)
+    Structural,
+    /// Any well-formed proof is accepted. Insecure: opt-in only, and every
+    /// use is logged.
+    Skip,
+}
+
 /// VDF-based block header
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Every field is committed content, so equality and hashing compare all of
+/// them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct VDFBlockHeader {
     pub prev_block_hash: [u8; 32],
     pub timestamp: u64,
     pub vdf_input: Vec<u8>,
     pub vdf_proof: VDFProof,
+    /// Sequential squarings the proof claims (the VDF time parameter).
+    /// Headers encoded before this field existed decode with 0, which
+    /// `validate` and `verify` reject; see `fill_legacy_iterations`.
+    #[serde(default)]
+    pub iterations: u64,
 }
 
 impl VDFBlockHeader {
@@ -223,20 +428,111 @@ impl VDFBlockHeader {
             timestamp,
             vdf_input,
             vdf_proof,
+            iterations: vdf.time_param,
         })
     }
     
+    pub fn prev_block_hash(&self) -> &[u8; 32] {
+        &self.prev_block_hash
+    }
+    
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    
+    pub fn vdf_input(&self) -> &[u8] {
+        &self.vdf_input
+    }
+    
+    pub fn proof(&self) -> &VDFProof {
+        &self.vdf_proof
+    }
+    
+    pub fn iterations(&self) -> u64 {
+        self.iterations
+    }
+
+    /// Whether the header was decoded from the encoding that predates the
+    /// `iterations` field
+    pub fn is_legacy(&self) -> bool {
+        self.iterations == 0
+    }
+
+    /// Migrate a legacy header by recording the time parameter of the VDF
+    /// it was mined with. Returns false (and leaves the header alone) if it
+    /// already carries an iteration count. `verify` still checks the proof
+    /// against that parameter, so a wrong one is caught there.
+    pub fn fill_legacy_iterations(&mut self, vdf: &VDF) -> bool {
+        if !self.is_legacy() {
+            return false;
+        }
+        self.iterations = vdf.time_param;
+        true
+    }
+    
+    /// Cheap structural checks, run before the expensive `verify`: the
+    /// iteration count meets the manifest minimum, the timestamp lies
+    /// between genesis and a little past `now` (Unix seconds, from the
+    /// caller's clock), and the input and proof are present
+    pub fn validate(&self, now: u64) -> Result<(), AxiomError> {
+        if self.iterations < SovereignInvariants::MINIMUM_VDF_ITERATIONS {
+            return Err(AxiomError::InvalidBlock(format!(
+                "Header VDF iterations {} below minimum {}",
+                self.iterations,
+                SovereignInvariants::MINIMUM_VDF_ITERATIONS
+            )));
+        }
+        
+        let latest = now.saturating_add(MAX_HEADER_FUTURE_DRIFT_SECS);
+        if self.timestamp < GENESIS_TIMESTAMP || self.timestamp > latest {
+            return Err(AxiomError::InvalidBlock(format!(
+                "Header timestamp {} outside [{}, {}]",
+                self.timestamp, GENESIS_TIMESTAMP, latest
+            )));
+        }
+        
+        if self.vdf_input.is_empty() {
+            return Err(AxiomError::InvalidBlock("Header has an empty VDF input".to_string()));
+        }
+        if self.vdf_proof.output.is_zero() || self.vdf_proof.proof.is_zero() {
+            return Err(AxiomError::InvalidBlock("Header has an empty VDF proof".to_string()));
+        }
+        Ok(())
+    }
+    
     /// Verify block VDF proof
     pub fn verify(&self, vdf: &VDF) -> Result<bool, String> {
+        self.verify_with(vdf, VerificationStrictness::Full)
+    }
+
+    /// Verify the header at the given strictness. Every level requires a
+    /// well-formed proof (non-zero elements reduced mod N); `Structural`
+    /// also checks the input binding and iteration bounds, and `Full`
+    /// recomputes the proof check on top.
+    pub fn verify_with(&self, vdf: &VDF, strictness: VerificationStrictness) -> Result<bool, String> {
+        let well_formed = [&self.vdf_proof.output, &self.vdf_proof.proof]
+            .iter()
+            .all(|element| !element.is_zero() && **element < vdf.modulus);
+        if !well_formed {
+            return Ok(false);
+        }
+        if strictness == VerificationStrictness::Skip {
+            log::warn!("⚠️ VDF verification skipped for header at {}: insecure", self.timestamp);
+            return Ok(true);
+        }
+
         // Recompute VDF input
         let mut hasher = Sha256::new();
         hasher.update(self.prev_block_hash);
         hasher.update(self.timestamp.to_le_bytes());
         let expected_input = hasher.finalize().to_vec();
         
-        if self.vdf_input != expected_input {
+        if self.vdf_input != expected_input || self.iterations != vdf.time_param {
             return Ok(false);
         }
+        if strictness == VerificationStrictness::Structural {
+            return Ok(self.iterations >= SovereignInvariants::MINIMUM_VDF_ITERATIONS);
+        }
         
         vdf.verify(&self.vdf_input, &self.vdf_proof)
     }
@@ -294,6 +590,69 @@ mod tests {
         println!("✓ VDF block valid!");
     }
     
+    #[test]
+    fn test_vdf_compute_cancelled_from_another_thread() {
+        // Far more steps than could finish during the test
+        let vdf = VDF::with_default_modulus(u64::MAX);
+        let token = CancellationToken::new();
+
+        let canceller = {
+            let token = token.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(Duration::from_millis(50));
+                token.cancel();
+            })
+        };
+
+        let start = Instant::now();
+        let result = vdf.compute_cancellable(b"long_running_input", &token);
+        canceller.join().unwrap();
+
+        assert!(matches!(result, Err(AxiomError::Cancelled)));
+        assert!(start.elapsed() < Duration::from_secs(5), "cancellation should be prompt");
+    }
+
+    #[test]
+    fn test_estimate_hashrate_scales_with_block_time() {
+        let slow = VDF::estimate_hashrate(1_000_000, 1_800);
+        let fast = VDF::estimate_hashrate(1_000_000, 900);
+        
+        assert!(slow > 0.0);
+        assert!((fast / slow - 2.0).abs() < 1e-9, "halving block time should double hashrate");
+        
+        // Zero block time must not divide by zero
+        let zero = VDF::estimate_hashrate(1_000_000, 0);
+        assert!(zero.is_finite());
+        assert_eq!(zero, VDF::estimate_hashrate(1_000_000, 1));
+    }
+    
+    #[test]
+    fn test_aggregate_proof_over_segment() {
+        let vdf = VDF::with_default_modulus(1_000);
+        let inputs: Vec<Vec<u8>> = (0..10u64)
+            .map(|height| format!("axiom_block_{}", height).into_bytes())
+            .collect();
+        let inputs: Vec<&[u8]> = inputs.iter().map(|i| i.as_slice()).collect();
+        let proofs: Vec<VDFProof> = inputs
+            .iter()
+            .map(|input| vdf.compute(input).expect("Compute failed"))
+            .collect();
+
+        let aggregate = vdf.aggregate_proofs(&inputs, &proofs).expect("Aggregation failed");
+        assert!(vdf.verify_aggregate(&aggregate, &inputs, 1_000));
+        assert!(!vdf.verify_aggregate(&aggregate, &inputs, 2_000));
+
+        // Swapping two constituents breaks the aggregate
+        let mut swapped = aggregate.clone();
+        swapped.outputs.swap(3, 4);
+        assert!(!vdf.verify_aggregate(&swapped, &inputs, 1_000));
+
+        // And a swapped proof is refused at aggregation time
+        let mut bad_proofs = proofs.clone();
+        bad_proofs.swap(3, 4);
+        assert!(vdf.aggregate_proofs(&inputs, &bad_proofs).is_err());
+    }
+    
     #[test]
     #[ignore] // Slow test - run manually
     fn test_vdf_calibration() {
@@ -304,4 +663,130 @@ mod tests {
         println!("Calibrated time_param: {}", time_param);
         assert!(time_param > 0);
     }
+
+    #[test]
+    fn test_proof_and_header_equality() {
+        use std::collections::HashSet;
+
+        let vdf = VDF::with_default_modulus(1_000);
+        let proof = vdf.compute(b"block_a").expect("VDF computation failed");
+        let same = vdf.compute(b"block_a").expect("VDF computation failed");
+        let other = vdf.compute(b"block_b").expect("VDF computation failed");
+
+        assert_eq!(proof, same);
+        assert_ne!(proof, other);
+        let proofs: HashSet<VDFProof> = [proof.clone(), same, other.clone()].into_iter().collect();
+        assert_eq!(proofs.len(), 2);
+
+        let header = VDFBlockHeader {
+            prev_block_hash: [7u8; 32],
+            timestamp: 1_700_000_000,
+            vdf_input: b"block_a".to_vec(),
+            vdf_proof: proof,
+            iterations: 1_000,
+        };
+        let mut different_proof = header.clone();
+        different_proof.vdf_proof = other;
+        let mut different_parent = header.clone();
+        different_parent.prev_block_hash[0] ^= 1;
+
+        let headers: HashSet<VDFBlockHeader> =
+            [header.clone(), header.clone(), different_proof.clone(), different_parent.clone()]
+                .into_iter()
+                .collect();
+        assert_eq!(headers.len(), 3);
+        assert_ne!(header, different_proof);
+        assert_ne!(header, different_parent);
+    }
+
+    #[test]
+    fn test_verification_strictness() {
+        // A real proof computed at a test-sized T, then tampered with
+        let small = VDF::with_default_modulus(1_000);
+        let mut header = VDFBlockHeader::mine([3u8; 32], 1_700_000_000, &small).expect("Block mining failed");
+        header.vdf_proof.output += BigUint::one();
+
+        // Claimed at the manifest minimum, it passes every structural check
+        let vdf = VDF::with_default_modulus(SovereignInvariants::MINIMUM_VDF_ITERATIONS);
+        header.iterations = vdf.time_param;
+        assert_eq!(VerificationStrictness::default(), VerificationStrictness::Full);
+        assert!(!header.verify(&vdf).unwrap(), "Full recomputes and catches the tampering");
+        assert!(header.verify_with(&vdf, VerificationStrictness::Structural).unwrap());
+
+        // Structural still enforces the iteration minimum
+        header.iterations = small.time_param;
+        assert!(!header.verify_with(&small, VerificationStrictness::Structural).unwrap());
+        assert!(header.verify_with(&small, VerificationStrictness::Skip).unwrap());
+
+        // Even Skip refuses a malformed proof
+        header.vdf_proof.proof = BigUint::zero();
+        assert!(!header.verify_with(&small, VerificationStrictness::Skip).unwrap());
+    }
+
+    #[test]
+    fn test_header_validation() {
+        let proof = VDFProof {
+            output: BigUint::from(3u32),
+            proof: BigUint::from(5u32),
+        };
+        let header = VDFBlockHeader {
+            prev_block_hash: [1u8; 32],
+            timestamp: GENESIS_TIMESTAMP + 600,
+            vdf_input: vec![9u8; 32],
+            vdf_proof: proof,
+            iterations: SovereignInvariants::MINIMUM_VDF_ITERATIONS,
+        };
+        let now = GENESIS_TIMESTAMP + 1_200;
+        header.validate(now).expect("well-formed header");
+        assert_eq!(header.iterations(), SovereignInvariants::MINIMUM_VDF_ITERATIONS);
+        assert_eq!(header.prev_block_hash(), &[1u8; 32]);
+
+        let mut zero_iterations = header.clone();
+        zero_iterations.iterations = 0;
+        assert!(matches!(zero_iterations.validate(now), Err(AxiomError::InvalidBlock(_))));
+
+        let mut empty_proof = header.clone();
+        empty_proof.vdf_proof.proof = BigUint::zero();
+        assert!(empty_proof.validate(now).is_err());
+
+        let mut empty_input = header.clone();
+        empty_input.vdf_input.clear();
+        assert!(empty_input.validate(now).is_err());
+
+        let mut before_genesis = header.clone();
+        before_genesis.timestamp = GENESIS_TIMESTAMP - 1;
+        assert!(before_genesis.validate(now).is_err());
+
+        let mut far_future = header.clone();
+        far_future.timestamp = u64::MAX / 2;
+        assert!(far_future.validate(now).is_err());
+
+        // The bound follows the caller's clock, not the host's
+        let mut ahead = header.clone();
+        ahead.timestamp = now + MAX_HEADER_FUTURE_DRIFT_SECS;
+        ahead.validate(now).expect("within drift of the supplied clock");
+        assert!(ahead.validate(now - 1).is_err());
+        assert!(header.validate(u64::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_legacy_header_migration() {
+        let vdf = VDF::with_default_modulus(1_000);
+        let mined = VDFBlockHeader::mine([4u8; 32], 1_700_000_000, &vdf).expect("Block mining failed");
+
+        // As decoded from the encoding without `iterations`
+        let mut legacy = mined.clone();
+        legacy.iterations = 0;
+        assert!(legacy.is_legacy());
+        assert!(!legacy.verify(&vdf).unwrap());
+
+        assert!(legacy.fill_legacy_iterations(&vdf));
+        assert_eq!(legacy, mined);
+        assert!(legacy.verify(&vdf).unwrap());
+
+        // Already-migrated headers keep their claim
+        let other = VDF::with_default_modulus(2_000);
+        assert!(!legacy.fill_legacy_iterations(&other));
+        assert_eq!(legacy.iterations(), 1_000);
+    }
 }
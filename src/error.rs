@@ -110,6 +110,12 @@ pub enum AxiomError {
         depth: u64,
     },
     
+    #[error("Chain reorganization too deep: {depth} blocks (max: {max})")]
+    ReorgTooDeep {
+        depth: u64,
+        max: u64,
+    },
+    
     #[error("Fork detected at height {height}")]
     ForkDetected {
         height: u64,
@@ -214,6 +220,17 @@ pub enum AxiomError {
     #[error("AI model error: {0}")]
     AIModelError(String),
     
+    #[error("AI optimizer warming up: {blocks_remaining} blocks remaining")]
+    WarmingUp {
+        blocks_remaining: u64,
+    },
+
+    #[error("Consensus proposal generation already in progress")]
+    ProposalInProgress,
+
+    #[error("Consensus optimization paused by operator")]
+    OptimizationPaused,
+    
     #[error("Oracle stake insufficient: have {have} AXM, need {need} AXM")]
     InsufficientStake {
         have: u64,
@@ -291,6 +308,9 @@ pub enum AxiomError {
     #[error("System resource exhausted: {0}")]
     ResourceExhausted(String),
     
+    #[error("Operation cancelled")]
+    Cancelled,
+    
     #[error("Emergency shutdown initiated: {reason}")]
     EmergencyShutdown {
         reason: String,
@@ -353,7 +373,8 @@ impl AxiomError {
             AxiomError::DatabaseError(_) |
             AxiomError::NetworkError(_) |
             AxiomError::ConsensusFailed(_) |
-            AxiomError::ChainReorgRequired { .. } => ErrorSeverity::Major,
+            AxiomError::ChainReorgRequired { .. } |
+            AxiomError::ReorgTooDeep { .. } => ErrorSeverity::Major,
             
             // Minor errors - can be handled
             _ => ErrorSeverity::Minor,
@@ -3,4 +3,4 @@
 
 pub mod safety_manifest;
 
-pub use safety_manifest::SovereignInvariants;
+pub use safety_manifest::{BlockTimeClass, ConsensusParameters, EraInfo, NetworkProfile, SovereignInvariants};
@@ -3,6 +3,75 @@
 // Foundational invariants that protect the protocol
 
 use crate::error::AxiomError;
+use serde::{Deserialize, Serialize};
+
+/// Network profile selecting the block-time target used by consensus math.
+/// Mainnet is pinned to the manifest's `TARGET_BLOCK_TIME_SECS` and cannot
+/// be overridden; only regtest accepts a custom target for local development.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NetworkProfile {
+    #[default]
+    Mainnet,
+    /// Public test network, same timing as mainnet
+    Testnet,
+    /// Local development network with a developer-chosen block time
+    Regtest { target_block_time_secs: u64 },
+}
+
+impl NetworkProfile {
+    /// Default regtest target: 10-second blocks
+    pub const REGTEST_DEFAULT_BLOCK_TIME_SECS: u64 = 10;
+
+    /// Regtest profile with the default 10-second target
+    pub fn regtest() -> Self {
+        NetworkProfile::Regtest {
+            target_block_time_secs: Self::REGTEST_DEFAULT_BLOCK_TIME_SECS,
+        }
+    }
+
+    /// Target block time in seconds (never zero)
+    pub fn target_block_time(&self) -> u64 {
+        match self {
+            NetworkProfile::Mainnet | NetworkProfile::Testnet => {
+                SovereignInvariants::TARGET_BLOCK_TIME_SECS
+            }
+            NetworkProfile::Regtest { target_block_time_secs } => (*target_block_time_secs).max(1),
+        }
+    }
+}
+
+/// One halving era of the emission schedule (heights are inclusive)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EraInfo {
+    pub era: u64,
+    /// Block reward throughout the era, in base units
+    pub reward: u64,
+    pub start_height: u64,
+    pub end_height: u64,
+    /// Total supply once every block of the era has been mined
+    pub cumulative_supply_at_end: u64,
+}
+
+/// The consensus parameters the AI is allowed to tune
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsensusParameters {
+    pub difficulty: u64,
+    pub vdf_iterations: u64,
+    pub min_gas: u64,
+}
+
+/// Graded block-time deviation from the target, for monitoring. Only
+/// `Violation` fails `verify_block_time`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum BlockTimeClass {
+    /// Within `ON_TARGET_BLOCK_TIME_DEVIATION_SECS` of the target
+    OnTarget,
+    Acceptable,
+    /// Still valid, but beyond `NEAR_BOUNDARY_BLOCK_TIME_DEVIATION_SECS`
+    NearBoundary,
+    /// Beyond `MAXIMUM_BLOCK_TIME_DEVIATION_SECS`
+    Violation,
+}
 
 /// Sovereign Invariants - Core protocol rules that are HARDCODED and UNMUTABLE
 /// All AI decisions must comply with these rules
@@ -31,6 +100,17 @@ impl SovereignInvariants {
     
     /// Maximum block time deviation: ±300 seconds (±5 minutes)
     pub const MAXIMUM_BLOCK_TIME_DEVIATION_SECS: u64 = 300;
+
+    /// Absolute minimum block interval: 5 seconds, whatever the network's
+    /// target; anything shorter is a manipulated timestamp
+    pub const ABSOLUTE_MIN_BLOCK_TIME_SECS: u64 = 5;
+
+    /// Block time deviation still classed as on target: ±60 seconds
+    pub const ON_TARGET_BLOCK_TIME_DEVIATION_SECS: u64 = 60;
+
+    /// Block time deviation classed as near the boundary: beyond ±240 seconds
+    /// (80% of the maximum)
+    pub const NEAR_BOUNDARY_BLOCK_TIME_DEVIATION_SECS: u64 = 240;
     
     // ==================== AI GOVERNANCE BOUNDS ====================
     /// Maximum difficulty swing: ±5%
@@ -54,6 +134,17 @@ impl SovereignInvariants {
     
     /// Minimum transaction fee: 1000 (0.00001 AXM)
     pub const MIN_TRANSACTION_FEE: u64 = 1000;
+
+    /// Suggested coinbase rounding tolerance when fees are split with
+    /// integer division: 100 base units (0.000001 AXM)
+    pub const FEE_ROUNDING_TOLERANCE: u64 = 100;
+
+    /// Maximum quarantine: 1,440 blocks (30 days at 30-min blocks)
+    pub const MAX_QUARANTINE_BLOCKS: u64 = 1_440;
+
+    /// Maximum chain reorganization depth: 6 blocks (3 hours at 30-min
+    /// blocks); anything deeper is treated as an attack
+    pub const MAX_REORG_DEPTH: u64 = 6;
     
     // ==================== GENESIS CONFIGURATION ====================
     /// Genesis validator count (4 active nodes)
@@ -86,14 +177,27 @@ impl SovereignInvariants {
     }
 
     // ==================== BLOCK REWARD VERIFICATION ====================
+    /// First halving era whose block reward is zero
+    ///
+    /// The reward is `INITIAL_BLOCK_REWARD >> era`, so it reaches zero once
+    /// the era exceeds the bit length of the initial reward. 50 AXM is
+    /// 5,000,000,000 base units (33 bits), so era 32 still pays 1 unit and
+    /// era 33 (height 40,920,000) is the first to pay nothing.
+    pub const fn first_zero_reward_era() -> u64 {
+        (u64::BITS - Self::INITIAL_BLOCK_REWARD.leading_zeros()) as u64
+    }
+
     /// Calculate expected reward for given block height
     pub fn calculate_expected_reward(height: u64) -> u64 {
         let era = height / Self::HALVING_INTERVAL;
-        let halvings = era.min(63); // Max 63 halvings before reward → 0
-        Self::INITIAL_BLOCK_REWARD >> halvings
+        if era >= Self::first_zero_reward_era() {
+            return 0;
+        }
+        Self::INITIAL_BLOCK_REWARD >> era
     }
 
-    /// Verify block reward matches protocol rule
+    /// Verify block reward matches protocol rule. From
+    /// `first_zero_reward_era()` onwards the only valid reward is zero.
     pub fn verify_block_reward(height: u64, reward: u64) -> Result<(), AxiomError> {
         let expected = Self::calculate_expected_reward(height);
         if reward != expected {
@@ -105,16 +209,52 @@ impl SovereignInvariants {
         Ok(())
     }
 
+    /// Verify a coinbase output pays exactly the block subsidy plus the fees
+    /// collected in the block. Fees are recycled rather than minted, so only
+    /// the subsidy counts towards the supply cap.
+    pub fn verify_coinbase_output(
+        height: u64,
+        total_output: u64,
+        collected_fees: u64,
+    ) -> Result<(), AxiomError> {
+        Self::verify_coinbase_output_with_tolerance(height, total_output, collected_fees, 0)
+    }
+
+    /// Like `verify_coinbase_output`, but allow the output to differ from
+    /// subsidy plus fees by up to `reward_tolerance` base units to absorb
+    /// rounding in fee distribution. The tolerance never exceeds the fees
+    /// collected, so a pure-subsidy coinbase must still be exact.
+    pub fn verify_coinbase_output_with_tolerance(
+        height: u64,
+        total_output: u64,
+        collected_fees: u64,
+        reward_tolerance: u64,
+    ) -> Result<(), AxiomError> {
+        let subsidy = Self::calculate_expected_reward(height);
+        let expected = subsidy.saturating_add(collected_fees);
+        if expected > Self::MAX_TOTAL_SUPPLY {
+            return Err(AxiomError::SupplyCapViolation {
+                current: expected,
+                max: Self::MAX_TOTAL_SUPPLY,
+            });
+        }
+
+        if total_output.abs_diff(expected) > reward_tolerance.min(collected_fees) {
+            return Err(AxiomError::InvalidBlockReward {
+                expected,
+                actual: total_output,
+            });
+        }
+
+        // Supply minted through this block (heights 0..=height)
+        Self::verify_supply_integrity(Self::calculate_supply_at_height(height.saturating_add(1)))
+    }
+
     // ==================== BLOCK TIME VERIFICATION ====================
     /// Verify block time is within acceptable deviation from target
     pub fn verify_block_time(block_time: u64) -> Result<(), AxiomError> {
-        let deviation = if block_time > Self::TARGET_BLOCK_TIME_SECS {
-            block_time - Self::TARGET_BLOCK_TIME_SECS
-        } else {
-            Self::TARGET_BLOCK_TIME_SECS - block_time
-        };
-
-        if deviation > Self::MAXIMUM_BLOCK_TIME_DEVIATION_SECS {
+        Self::verify_minimum_block_interval(block_time)?;
+        if Self::classify_block_time(block_time) == BlockTimeClass::Violation {
             return Err(AxiomError::AIProposalRejected {
                 reason: format!(
                     "Block time violation: {} secs (target: {} ±{} secs)",
@@ -127,6 +267,34 @@ impl SovereignInvariants {
         Ok(())
     }
 
+    /// Verify a block time is at least `ABSOLUTE_MIN_BLOCK_TIME_SECS`.
+    /// Independent of the target band, so it holds on every network profile.
+    pub fn verify_minimum_block_interval(block_time: u64) -> Result<(), AxiomError> {
+        if block_time < Self::ABSOLUTE_MIN_BLOCK_TIME_SECS {
+            return Err(AxiomError::InvalidBlock(format!(
+                "Block time {} secs below absolute minimum {} secs",
+                block_time,
+                Self::ABSOLUTE_MIN_BLOCK_TIME_SECS
+            )));
+        }
+        Ok(())
+    }
+
+    /// Grade a block time by its deviation from target. Boundaries are
+    /// inclusive on the better side, so exactly ±300 s is `NearBoundary`.
+    pub fn classify_block_time(block_time: u64) -> BlockTimeClass {
+        let deviation = block_time.abs_diff(Self::TARGET_BLOCK_TIME_SECS);
+        if deviation > Self::MAXIMUM_BLOCK_TIME_DEVIATION_SECS {
+            BlockTimeClass::Violation
+        } else if deviation > Self::NEAR_BOUNDARY_BLOCK_TIME_DEVIATION_SECS {
+            BlockTimeClass::NearBoundary
+        } else if deviation > Self::ON_TARGET_BLOCK_TIME_DEVIATION_SECS {
+            BlockTimeClass::Acceptable
+        } else {
+            BlockTimeClass::OnTarget
+        }
+    }
+
     // ==================== AI PROPOSAL VERIFICATION ====================
     /// Verify AI difficulty proposal stays within bounds
     pub fn verify_ai_difficulty_proposal(
@@ -215,6 +383,28 @@ impl SovereignInvariants {
         Ok(())
     }
 
+    /// Verify every AI-tunable parameter at once, collecting all violations
+    /// instead of stopping at the first (difficulty, VDF, gas order)
+    pub fn verify_ai_proposal_all(
+        current: &ConsensusParameters,
+        proposed: &ConsensusParameters,
+    ) -> Result<(), Vec<AxiomError>> {
+        let violations: Vec<AxiomError> = [
+            Self::verify_ai_difficulty_proposal(current.difficulty, proposed.difficulty),
+            Self::verify_ai_vdf_proposal(current.vdf_iterations, proposed.vdf_iterations),
+            Self::verify_ai_gas_proposal(current.min_gas, proposed.min_gas),
+        ]
+        .into_iter()
+        .filter_map(Result::err)
+        .collect();
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
     /// Verify minimum transaction fee requirement
     pub fn verify_transaction_fee(fee: u64) -> Result<(), AxiomError> {
         if fee < Self::MIN_TRANSACTION_FEE {
@@ -239,26 +429,173 @@ impl SovereignInvariants {
         Ok(())
     }
 
+    /// Verify a quarantine doesn't exceed the maximum duration
+    pub fn verify_quarantine_duration(blocks: u64) -> Result<(), AxiomError> {
+        if blocks > Self::MAX_QUARANTINE_BLOCKS {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!(
+                    "Quarantine too long: {} blocks (max: {})",
+                    blocks, Self::MAX_QUARANTINE_BLOCKS
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Verify a chain reorganization doesn't roll back more than
+    /// `MAX_REORG_DEPTH` blocks
+    pub fn verify_reorg_depth(depth: u64) -> Result<(), AxiomError> {
+        if depth > Self::MAX_REORG_DEPTH {
+            return Err(AxiomError::ReorgTooDeep {
+                depth,
+                max: Self::MAX_REORG_DEPTH,
+            });
+        }
+        Ok(())
+    }
+
     /// Get total supply after N blocks
+    ///
+    /// Iteration stops at `first_zero_reward_era()`, so arbitrarily large
+    /// heights (up to `u64::MAX`) terminate and saturate at the converged
+    /// emission total.
     pub fn calculate_supply_at_height(height: u64) -> u64 {
         let mut total = 0u64;
-        let mut era = 0u64;
 
-        while era * Self::HALVING_INTERVAL < height {
-            let blocks_in_era = if (era + 1) * Self::HALVING_INTERVAL <= height {
-                Self::HALVING_INTERVAL
-            } else {
-                height - (era * Self::HALVING_INTERVAL)
-            };
+        for era in 0..Self::first_zero_reward_era() {
+            let era_start = era * Self::HALVING_INTERVAL;
+            if era_start >= height {
+                break;
+            }
 
-            let reward = Self::INITIAL_BLOCK_REWARD >> era.min(63);
+            let reward = Self::INITIAL_BLOCK_REWARD >> era;
+            let blocks_in_era = (height - era_start).min(Self::HALVING_INTERVAL);
             total = total.saturating_add(blocks_in_era.saturating_mul(reward));
-
-            era += 1;
         }
 
         total.min(Self::MAX_TOTAL_SUPPLY)
     }
+
+    /// Emission schedule for every era that pays a non-zero reward, i.e.
+    /// up to (excluding) `first_zero_reward_era()`
+    pub fn emission_schedule() -> Vec<EraInfo> {
+        (0..Self::first_zero_reward_era())
+            .map(|era| {
+                let start_height = era * Self::HALVING_INTERVAL;
+                let end_height = start_height + Self::HALVING_INTERVAL - 1;
+                EraInfo {
+                    era,
+                    reward: Self::calculate_expected_reward(start_height),
+                    start_height,
+                    end_height,
+                    // Supply "at height N" counts blocks 0..N
+                    cumulative_supply_at_end: Self::calculate_supply_at_height(end_height + 1),
+                }
+            })
+            .collect()
+    }
+
+    // ==================== SELF TEST ====================
+    /// Check the manifest's constants are consistent with each other, so a
+    /// bad edit is caught at boot. Returns every violation found.
+    pub fn self_test() -> Result<(), Vec<String>> {
+        let mut violations = Vec::new();
+        let mut check = |ok: bool, violation: String| {
+            if !ok {
+                violations.push(violation);
+            }
+        };
+
+        // Supply
+        check(Self::INITIAL_BLOCK_REWARD != 0, "INITIAL_BLOCK_REWARD is zero".to_string());
+        check(Self::HALVING_INTERVAL != 0, "HALVING_INTERVAL is zero".to_string());
+        check(
+            Self::GENESIS_PREMINE <= Self::MAX_TOTAL_SUPPLY,
+            format!("GENESIS_PREMINE {} exceeds MAX_TOTAL_SUPPLY {}", Self::GENESIS_PREMINE, Self::MAX_TOTAL_SUPPLY),
+        );
+        if Self::HALVING_INTERVAL != 0 {
+            let emitted = Self::emission_schedule()
+                .last()
+                .map_or(0, |era| era.cumulative_supply_at_end);
+            check(
+                Self::GENESIS_PREMINE.saturating_add(emitted) <= Self::MAX_TOTAL_SUPPLY,
+                format!("Total emission {} exceeds MAX_TOTAL_SUPPLY {}", emitted, Self::MAX_TOTAL_SUPPLY),
+            );
+        }
+
+        // Timing
+        check(Self::MINIMUM_VDF_ITERATIONS != 0, "MINIMUM_VDF_ITERATIONS is zero".to_string());
+        check(
+            Self::MAXIMUM_BLOCK_TIME_DEVIATION_SECS < Self::TARGET_BLOCK_TIME_SECS,
+            format!(
+                "MAXIMUM_BLOCK_TIME_DEVIATION_SECS {} not below TARGET_BLOCK_TIME_SECS {}",
+                Self::MAXIMUM_BLOCK_TIME_DEVIATION_SECS,
+                Self::TARGET_BLOCK_TIME_SECS
+            ),
+        );
+        check(
+            Self::ABSOLUTE_MIN_BLOCK_TIME_SECS != 0
+                && Self::ABSOLUTE_MIN_BLOCK_TIME_SECS <= NetworkProfile::regtest().target_block_time(),
+            format!(
+                "ABSOLUTE_MIN_BLOCK_TIME_SECS {} not in 1..={} (regtest target)",
+                Self::ABSOLUTE_MIN_BLOCK_TIME_SECS,
+                NetworkProfile::regtest().target_block_time()
+            ),
+        );
+        check(
+            Self::ON_TARGET_BLOCK_TIME_DEVIATION_SECS < Self::NEAR_BOUNDARY_BLOCK_TIME_DEVIATION_SECS
+                && Self::NEAR_BOUNDARY_BLOCK_TIME_DEVIATION_SECS < Self::MAXIMUM_BLOCK_TIME_DEVIATION_SECS,
+            "Block-time classes not ordered ON_TARGET < NEAR_BOUNDARY < MAXIMUM".to_string(),
+        );
+
+        // AI governance
+        for (name, percent) in [
+            ("MAX_AI_DIFFICULTY_SWING_PERCENT", Self::MAX_AI_DIFFICULTY_SWING_PERCENT),
+            ("MAX_AI_GAS_SWING_PERCENT", Self::MAX_AI_GAS_SWING_PERCENT),
+            ("MAX_AI_VDF_SWING_PERCENT", Self::MAX_AI_VDF_SWING_PERCENT),
+        ] {
+            check(
+                percent.is_finite() && percent > 0.0 && percent < 100.0,
+                format!("{} {} outside (0, 100)", name, percent),
+            );
+        }
+        check(
+            Self::UPGRADE_VOTING_PERIOD_BLOCKS != 0,
+            "UPGRADE_VOTING_PERIOD_BLOCKS is zero".to_string(),
+        );
+
+        // Security and genesis
+        check(Self::MAX_BLOCK_SIZE_BYTES != 0, "MAX_BLOCK_SIZE_BYTES is zero".to_string());
+        check(Self::MAX_QUARANTINE_BLOCKS != 0, "MAX_QUARANTINE_BLOCKS is zero".to_string());
+        check(Self::MAX_REORG_DEPTH != 0, "MAX_REORG_DEPTH is zero".to_string());
+        check(
+            Self::GENESIS_BFT_THRESHOLD != 0 && Self::GENESIS_BFT_THRESHOLD <= Self::GENESIS_VALIDATORS,
+            format!(
+                "GENESIS_BFT_THRESHOLD {} not in 1..={} validators",
+                Self::GENESIS_BFT_THRESHOLD,
+                Self::GENESIS_VALIDATORS
+            ),
+        );
+        check(
+            Self::GENESIS_BFT_THRESHOLD * 3 > Self::GENESIS_VALIDATORS * 2,
+            format!(
+                "GENESIS_BFT_THRESHOLD {} is not a two-thirds supermajority of {}",
+                Self::GENESIS_BFT_THRESHOLD,
+                Self::GENESIS_VALIDATORS
+            ),
+        );
+        check(
+            Self::BLAKE3_OUTPUT_BITS_LEGACY < Self::BLAKE3_OUTPUT_BITS_HYBRID
+                && Self::BLAKE3_OUTPUT_BITS_HYBRID < Self::BLAKE3_OUTPUT_BITS_POSTQC,
+            "BLAKE3 output sizes not increasing LEGACY < HYBRID < POSTQC".to_string(),
+        );
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
 }
 
 // ==================== TESTS ====================
@@ -336,6 +673,56 @@ mod tests {
         assert!(SovereignInvariants::verify_block_time(2_101).is_err());
     }
 
+    #[test]
+    fn test_absolute_minimum_block_time() {
+        for block_time in [0, 1] {
+            assert!(matches!(
+                SovereignInvariants::verify_block_time(block_time),
+                Err(AxiomError::InvalidBlock(_))
+            ));
+            assert!(SovereignInvariants::verify_minimum_block_interval(block_time).is_err());
+        }
+
+        // The floor alone accepts anything a regtest network would produce
+        let min = SovereignInvariants::ABSOLUTE_MIN_BLOCK_TIME_SECS;
+        assert!(SovereignInvariants::verify_minimum_block_interval(min - 1).is_err());
+        assert!(SovereignInvariants::verify_minimum_block_interval(min).is_ok());
+        assert!(SovereignInvariants::verify_minimum_block_interval(NetworkProfile::regtest().target_block_time()).is_ok());
+    }
+
+    #[test]
+    fn test_block_time_classification() {
+        use BlockTimeClass::*;
+        let cases = [
+            (1_800, OnTarget),
+            (1_740, OnTarget),
+            (1_861, Acceptable),
+            (2_040, Acceptable),
+            (1_559, NearBoundary),
+            (2_099, NearBoundary),
+            (1_500, NearBoundary),
+            (2_101, Violation),
+            (0, Violation),
+        ];
+        for (block_time, class) in cases {
+            assert_eq!(SovereignInvariants::classify_block_time(block_time), class, "{}", block_time);
+            // Pass/fail is unchanged: only violations fail
+            assert_eq!(
+                SovereignInvariants::verify_block_time(block_time).is_err(),
+                class == Violation
+            );
+        }
+    }
+
+    #[test]
+    fn test_network_profile_targets() {
+        assert_eq!(NetworkProfile::default(), NetworkProfile::Mainnet);
+        assert_eq!(NetworkProfile::Mainnet.target_block_time(), SovereignInvariants::TARGET_BLOCK_TIME_SECS);
+        assert_eq!(NetworkProfile::Testnet.target_block_time(), SovereignInvariants::TARGET_BLOCK_TIME_SECS);
+        assert_eq!(NetworkProfile::regtest().target_block_time(), 10);
+        assert_eq!(NetworkProfile::Regtest { target_block_time_secs: 0 }.target_block_time(), 1);
+    }
+
     #[test]
     fn test_supply_calculation() {
         // At block 0: exactly 50 AXM
@@ -349,4 +736,195 @@ mod tests {
         assert!(supply_at_halving > 0);
         assert!(supply_at_halving <= SovereignInvariants::MAX_TOTAL_SUPPLY);
     }
+
+    #[test]
+    fn test_supply_sweep_all_eras() {
+        let interval = SovereignInvariants::HALVING_INTERVAL;
+        let mut previous = 0u64;
+        let mut expected_total = 0u64;
+
+        for era in 0..=63u64 {
+            let reward = SovereignInvariants::INITIAL_BLOCK_REWARD >> era;
+            let start = era * interval;
+
+            // Probe the start, the middle and the last block of each era
+            for height in [start, start + 1, start + interval / 2, start + interval] {
+                let supply = SovereignInvariants::calculate_supply_at_height(height);
+                assert!(supply >= previous, "supply decreased at height {}", height);
+                assert!(supply <= SovereignInvariants::MAX_TOTAL_SUPPLY);
+                previous = supply;
+            }
+
+            // Each full era adds exactly interval × (reward >> era)
+            expected_total += interval * reward;
+            assert_eq!(
+                SovereignInvariants::calculate_supply_at_height(start + interval),
+                expected_total,
+                "era {} does not match the geometric series",
+                era
+            );
+        }
+
+        // Integer halving truncates each term, so the series converges just
+        // below the 124M cap (2 × 62M emitted in era 0)
+        assert!(expected_total < SovereignInvariants::MAX_TOTAL_SUPPLY);
+        assert!(SovereignInvariants::MAX_TOTAL_SUPPLY - expected_total < 2 * interval * 64);
+
+        // Heights beyond the final era saturate at the converged total
+        assert_eq!(SovereignInvariants::calculate_supply_at_height(64 * interval), expected_total);
+        assert_eq!(SovereignInvariants::calculate_supply_at_height(u64::MAX), expected_total);
+    }
+
+    #[test]
+    fn test_verify_ai_proposal_all_collects_violations() {
+        let current = ConsensusParameters {
+            difficulty: 1000,
+            vdf_iterations: 1_000_000,
+            min_gas: 1000,
+        };
+
+        assert!(SovereignInvariants::verify_ai_proposal_all(&current, &current).is_ok());
+
+        // Difficulty +20% and gas +50% both exceed their bounds; VDF is fine
+        let proposed = ConsensusParameters {
+            difficulty: 1200,
+            vdf_iterations: 1_000_000,
+            min_gas: 1500,
+        };
+        let errors = SovereignInvariants::verify_ai_proposal_all(&current, &proposed).unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].to_string().contains("Difficulty"));
+        assert!(errors[1].to_string().contains("Gas"));
+    }
+
+    #[test]
+    fn test_coinbase_output_includes_fees() {
+        let fees = 12_345;
+        let subsidy = SovereignInvariants::INITIAL_BLOCK_REWARD;
+
+        assert!(SovereignInvariants::verify_coinbase_output(100, subsidy + fees, fees).is_ok());
+        // Pure subsidy alone is short when fees were collected
+        assert!(SovereignInvariants::verify_coinbase_output(100, subsidy, fees).is_err());
+
+        // Over-claimed fees
+        assert!(matches!(
+            SovereignInvariants::verify_coinbase_output(100, subsidy + fees + 1, fees),
+            Err(AxiomError::InvalidBlockReward { .. })
+        ));
+
+        // Era boundary: last block of era 0 pays 50 AXM, first of era 1 pays 25
+        let boundary = SovereignInvariants::HALVING_INTERVAL;
+        assert!(SovereignInvariants::verify_coinbase_output(boundary - 1, subsidy + fees, fees).is_ok());
+        assert!(SovereignInvariants::verify_coinbase_output(boundary, subsidy + fees, fees).is_err());
+        assert!(SovereignInvariants::verify_coinbase_output(boundary, subsidy / 2 + fees, fees).is_ok());
+
+        // Absurd fee claims can't overflow past the cap
+        assert!(matches!(
+            SovereignInvariants::verify_coinbase_output(100, u64::MAX, u64::MAX),
+            Err(AxiomError::SupplyCapViolation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_coinbase_reward_tolerance() {
+        let fees = 12_345;
+        let subsidy = SovereignInvariants::INITIAL_BLOCK_REWARD;
+        let tolerance = SovereignInvariants::FEE_ROUNDING_TOLERANCE;
+        let verify = |output| {
+            SovereignInvariants::verify_coinbase_output_with_tolerance(100, output, fees, tolerance)
+        };
+
+        // Exact
+        assert!(verify(subsidy + fees).is_ok());
+        // Rounding either way within tolerance
+        assert!(verify(subsidy + fees + tolerance).is_ok());
+        assert!(verify(subsidy + fees - tolerance).is_ok());
+        // Beyond tolerance
+        assert!(matches!(
+            verify(subsidy + fees + tolerance + 1),
+            Err(AxiomError::InvalidBlockReward { .. })
+        ));
+        assert!(verify(subsidy + fees - tolerance - 1).is_err());
+
+        // No fees: the subsidy must be exact whatever the tolerance
+        assert!(SovereignInvariants::verify_coinbase_output_with_tolerance(100, subsidy + 1, 0, tolerance).is_err());
+        assert!(SovereignInvariants::verify_coinbase_output_with_tolerance(100, subsidy, 0, tolerance).is_ok());
+    }
+
+    #[test]
+    fn test_first_zero_reward_era() {
+        let interval = SovereignInvariants::HALVING_INTERVAL;
+
+        // Scan for the first era whose reward is zero
+        let zero_era = (0..64u64)
+            .find(|era| SovereignInvariants::calculate_expected_reward(era * interval) == 0)
+            .unwrap();
+        assert_eq!(zero_era, 33);
+        assert_eq!(SovereignInvariants::first_zero_reward_era(), zero_era);
+        assert_eq!(SovereignInvariants::calculate_expected_reward(zero_era * interval - 1), 1);
+
+        // The zero-reward tail accepts only a zero reward, all the way out
+        for height in [zero_era * interval, 64 * interval, u64::MAX] {
+            assert_eq!(SovereignInvariants::calculate_expected_reward(height), 0);
+            assert!(SovereignInvariants::verify_block_reward(height, 0).is_ok());
+            assert!(SovereignInvariants::verify_block_reward(height, 1).is_err());
+        }
+
+        // Supply stops growing once rewards hit zero
+        assert_eq!(
+            SovereignInvariants::calculate_supply_at_height(zero_era * interval),
+            SovereignInvariants::calculate_supply_at_height(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_verify_quarantine_duration() {
+        let max = SovereignInvariants::MAX_QUARANTINE_BLOCKS;
+        assert!(SovereignInvariants::verify_quarantine_duration(0).is_ok());
+        assert!(SovereignInvariants::verify_quarantine_duration(max).is_ok());
+        assert!(SovereignInvariants::verify_quarantine_duration(max + 1).is_err());
+    }
+
+    #[test]
+    fn test_verify_reorg_depth() {
+        let max = SovereignInvariants::MAX_REORG_DEPTH;
+        assert!(SovereignInvariants::verify_reorg_depth(0).is_ok());
+        assert!(SovereignInvariants::verify_reorg_depth(max).is_ok());
+        assert!(matches!(
+            SovereignInvariants::verify_reorg_depth(max + 1),
+            Err(AxiomError::ReorgTooDeep { depth, max: limit }) if depth == max + 1 && limit == max
+        ));
+        assert!(SovereignInvariants::verify_reorg_depth(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_emission_schedule() {
+        let schedule = SovereignInvariants::emission_schedule();
+        assert_eq!(schedule.len() as u64, SovereignInvariants::first_zero_reward_era());
+
+        let mut previous_end = None;
+        for info in &schedule {
+            assert_eq!(info.start_height, previous_end.map_or(0, |end: u64| end + 1));
+            assert_eq!(info.reward, SovereignInvariants::calculate_expected_reward(info.end_height));
+            assert!(info.reward > 0);
+            previous_end = Some(info.end_height);
+        }
+        assert_eq!(schedule[0].reward, SovereignInvariants::INITIAL_BLOCK_REWARD);
+        assert_eq!(
+            schedule[1].cumulative_supply_at_end - schedule[0].cumulative_supply_at_end,
+            SovereignInvariants::HALVING_INTERVAL * schedule[1].reward
+        );
+
+        // The floored halvings fall short of the cap by at most one base unit
+        // per era per block
+        let last = schedule.last().unwrap();
+        let shortfall = SovereignInvariants::MAX_TOTAL_SUPPLY - last.cumulative_supply_at_end;
+        assert!(shortfall <= SovereignInvariants::HALVING_INTERVAL * schedule.len() as u64, "{}", shortfall);
+        assert_eq!(last.cumulative_supply_at_end, SovereignInvariants::calculate_supply_at_height(u64::MAX));
+    }
+
+    #[test]
+    fn test_manifest_self_test_passes() {
+        assert_eq!(SovereignInvariants::self_test(), Ok(()));
+    }
 }
@@ -2,21 +2,790 @@
 // Guardian-AI Bridge - Integrates AI decisions with Governor Safety Manifest
 // CRITICAL: All AI decisions require Guardian verification
 
-use crate::guardian::SovereignInvariants;
+use crate::guardian::{ConsensusParameters, NetworkProfile, SovereignInvariants};
+use crate::consensus::VDF;
 use crate::ai_core::{
-    MultiLayerSecurityEngine, ThreatAssessment, SecurityAction, RiskLevel, TransactionRiskProfile,
+    MultiLayerSecurityEngine, ThreatAssessment, ThreatAssessor, SecurityAction, RiskLevel,
+    TransactionClass, TransactionRiskProfile,
 };
 use crate::error::AxiomError;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+/// Maximum number of audit entries kept in memory (oldest are dropped first)
+const AUDIT_LOG_CAPACITY: usize = 10_000;
+
+/// Audit entries copied per read-lock acquisition when walking the
+/// in-memory trail, so exports don't hold off appends for their duration
+const AUDIT_EXPORT_CHUNK: usize = 256;
+
+/// Maximum number of undrained events kept in memory
+const EVENT_QUEUE_CAPACITY: usize = 1_024;
+
+/// Number of proposals whose lifecycle is tracked before finished ones are pruned
+const PROPOSAL_TRACKING_CAPACITY: usize = 1_000;
+
+/// Typical serialized transaction size, used to turn the block size limit
+/// into a transaction capacity
+const AVERAGE_TRANSACTION_SIZE_BYTES: usize = 1_000;
+
+/// Allowed difference between a block's `block_time` and the delta between
+/// its timestamp and its predecessor's (clock rounding at the producer)
+const BLOCK_TIMESTAMP_TOLERANCE_SECS: u64 = 5;
+
+/// Blocks of history a consensus optimization is computed over
+const OPTIMIZATION_WINDOW: usize = 144;
+
+/// Recent health scores the network-health trend is fitted over
+const HEALTH_TREND_WINDOW: usize = 144;
+
+/// Recent blocks whose average transaction fee feeds the fee percentiles
+const FEE_PERCENTILE_WINDOW: usize = 144;
+
+/// Default hashrate samples below which hashrate stability is neutral;
+/// fewer can't give a meaningful coefficient of variation
+const DEFAULT_MIN_HASHRATE_SAMPLES: usize = 30;
+
+/// Most recent back-filled optimization outcomes the confidence calibration
+/// looks at
+const CALIBRATION_WINDOW: usize = 10;
+
+/// Attempts at the breaker deactivation hook before giving up
+const BREAKER_HOOK_ATTEMPTS: u32 = 3;
+
+/// Base backoff (seconds) between deactivation hook attempts; doubles per
+/// attempt, plus up to the same again in random jitter
+const BREAKER_HOOK_BACKOFF_SECS: u64 = 1;
+
+/// Default bound on the manual-review queue
+const DEFAULT_REVIEW_QUEUE_CAPACITY: usize = 1_000;
+
+/// Threat-score points between a decision's score and the nearest action
+/// threshold at which the guardian is fully confident in it
+const FULL_CONFIDENCE_MARGIN: f64 = 20.0;
+
+/// Power-of-two latency buckets: bucket `i` holds calls of at most `2^i` µs
+const LATENCY_BUCKETS: usize = 32;
+
+/// Current `ConsensusOptimizationProposal` schema. Version 1 predates
+/// `urgency` and the version marker itself; versions 1 and 2 carried a bare
+/// `guardian_pre_approved` flag instead of `pre_approval`.
+pub const PROPOSAL_SCHEMA_VERSION: u16 = 3;
 
 /// Guardian-enforced AI governance
 pub struct AIGuardianBridge {
-    security_engine: Arc<MultiLayerSecurityEngine>,
+    security_engine: Arc<dyn ThreatAssessor>,
+    engine_failure_policy: EngineFailurePolicy,
+    fee_ratio_band: Option<FeeRatioBand>,
+    review_timeout_resolution: ReviewTimeoutResolution,
+    review_queue_capacity: usize,
+    review_queue_overflow: ReviewQueueOverflow,
+    review_timeout_breaker: Option<ReviewTimeoutBreaker>,
+    voting_policy: VotingPolicy,
+    auto_pilot_scope: AutoPilotScope,
+    change_percent_precision: Option<u32>,
+    coinbase_reward_tolerance: u64,
+    escalation_fallbacks: BTreeMap<RiskLevel, EscalationFallback>,
+    hashrate_drop_rule: Option<HashrateDropRule>,
+    breaker_recovery: Option<BreakerRecovery>,
     guardian_state: Arc<RwLock<GuardianState>>,
     consensus_ai: Arc<RwLock<ConsensusAIController>>,
     emergency_circuit_breaker: Arc<RwLock<CircuitBreaker>>,
+    threat_window: Arc<RwLock<ThreatWindow>>,
+    audit_sink: Arc<dyn AuditSink>,
+    threat_adjuster: Option<Arc<dyn ThreatAdjuster>>,
+    events: Arc<RwLock<VecDeque<GuardianEvent>>>,
+    proposal_statuses: Arc<RwLock<HashMap<String, TrackedProposal>>>,
+    vote_tallies: Arc<Mutex<HashMap<String, VoteTally>>>,
+    // Only for retry jitter, so it never perturbs anything consensus-facing
+    retry_rng: Arc<Mutex<StdRng>>,
+    // A failed deactivation hook waiting for its next attempt
+    pending_hook_retry: Arc<Mutex<Option<HookRetry>>>,
+    // Proposals assembled so far; disambiguates identical proposals
+    proposal_sequence: Arc<AtomicU64>,
+    // Held for the whole of `generate_consensus_optimization`
+    generation_guard: Arc<Mutex<()>>,
+    auto_optimize_interval: Option<u64>,
+    block_feed: Arc<Mutex<BlockFeed>>,
+    safe_defaults: ConsensusParameters,
+    clock: Arc<dyn Clock>,
+    decision_latency: Arc<LatencyHistogram>,
+    decision_counters: Arc<DecisionCounters>,
+    veto_monitor: Option<Arc<Mutex<VetoRateMonitor>>>,
+    assessment_cache: Option<Arc<Mutex<AssessmentCache>>>,
+    breaker_deactivation_guard: Option<BreakerCallback>,
+    breaker_deactivation_hook: Option<BreakerCallback>,
+    // What an emergency lockdown overrode, restored on release
+    lockdown: Arc<Mutex<Option<Lockdown>>>,
+    lockdown_signal: Arc<AtomicBool>,
+}
+
+/// Callback given the circuit breaker's state around deactivation; see
+/// `with_breaker_deactivation_guard` and `with_breaker_deactivation_hook`
+pub type BreakerCallback = Arc<dyn Fn(&CircuitBreaker) -> Result<(), AxiomError> + Send + Sync>;
+
+/// Source of wall-clock time (unix seconds) for audit entries, events and
+/// proposals; swap in a fixed clock for replay and tests
+pub trait Clock: Send + Sync {
+    fn now(&self) -> u64;
+}
+
+/// The system wall clock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        unix_timestamp()
+    }
+}
+
+/// Tunable bridge configuration
+#[derive(Debug, Clone, Default)]
+pub struct BridgeConfig {
+    /// Seed for the bridge's RNG. `None` seeds from OS entropy.
+    ///
+    /// Randomness is only used for the deactivation hook's retry jitter; consensus-affecting paths (parameter calculation, bounds
+    /// checks, decisions, proposal identifiers) must stay deterministic.
+    /// Fixing the seed makes the remaining randomness reproducible for
+    /// replay and tests.
+    pub rng_seed: Option<u64>,
+
+    /// Network profile whose block-time target drives the consensus math
+    pub network_profile: NetworkProfile,
+
+    /// Start in shadow mode (see [`AIGuardianBridge::set_shadow_mode`])
+    pub shadow_mode: bool,
+
+    /// Low-pass filter coefficient N for the PID derivative terms.
+    /// `None` keeps the raw, unfiltered derivative.
+    pub pid_derivative_filter: Option<f64>,
+
+    /// What to do when the security engine fails to produce an assessment
+    pub engine_failure_policy: EngineFailurePolicy,
+
+    /// Pending transactions that fill one block, i.e. 100% congestion.
+    /// `None` derives it from `MAX_BLOCK_SIZE_BYTES` and the average
+    /// transaction size.
+    pub mempool_capacity: Option<usize>,
+
+    /// Rolling-threat circuit breaker rule; `None` trips the breaker only on
+    /// chain-halt recommendations from the engine
+    pub rolling_threat_breaker: Option<RollingThreatBreaker>,
+
+    /// Automatic recovery of a tripped breaker; `None` leaves it tripped
+    /// until an operator deactivates it
+    pub breaker_recovery: Option<BreakerRecovery>,
+
+    /// Expected fee-to-amount ratio for user transactions; ones outside the
+    /// band are flagged in the decision but not rejected. `None` disables it.
+    pub fee_ratio_band: Option<FeeRatioBand>,
+
+    /// Blocks the optimizer must ingest after startup before it produces
+    /// proposals (0 disables warm-up)
+    pub warm_up_blocks: u64,
+
+    /// How manual-review items are resolved when nobody reviews them in time
+    pub review_timeout_resolution: ReviewTimeoutResolution,
+
+    /// Most transactions held for manual review at once; `None` uses 1,000
+    pub review_queue_capacity: Option<usize>,
+
+    /// What happens to a new manual-review item when the queue is full
+    pub review_queue_overflow: ReviewQueueOverflow,
+
+    /// Per-risk-level handling of engine escalations while no operator has
+    /// taken manual control (lights-out operation); unlisted levels go to
+    /// manual review
+    pub escalation_fallbacks: BTreeMap<RiskLevel, EscalationFallback>,
+
+    /// Hashrate-cliff detection on ingested block metrics; `None` disables it
+    pub hashrate_drop_rule: Option<HashrateDropRule>,
+
+    /// Alarm on a high recent veto rate; `None` disables it
+    pub veto_rate_alarm: Option<VetoRateAlarm>,
+
+    /// Transactions whose threat assessment is kept for reuse when they
+    /// re-enter the mempool (e.g. after a reorg); `None` disables the cache
+    pub assessment_cache_capacity: Option<usize>,
+
+    /// Breaker on consecutive manual-review timeouts; `None` disables it
+    pub review_timeout_breaker: Option<ReviewTimeoutBreaker>,
+
+    /// Blocks between proposals generated automatically by `on_new_block`;
+    /// `None` only ingests pushed blocks
+    pub auto_optimize_interval: Option<u64>,
+
+    /// Known-good parameters restored by `reset_to_safe_defaults`; `None`
+    /// uses the controller's genesis parameters
+    pub safe_defaults: Option<ConsensusParameters>,
+
+    /// Consensus AI controller tuning; construction fails with the
+    /// `ControllerTuning::validate` error if it is invalid
+    pub controller_tuning: ControllerTuning,
+
+    /// When generated proposals need a governance vote
+    pub voting_policy: VotingPolicy,
+
+    /// What auto-pilot mode is allowed to act on
+    pub auto_pilot_scope: AutoPilotScope,
+
+    /// Decimal places the change percents of generated proposals are
+    /// rounded to; `None` stores them unrounded
+    pub change_percent_precision: Option<u32>,
+
+    /// Base units a coinbase may differ from subsidy plus collected fees, to
+    /// absorb rounding in fee distribution (0 requires an exact match)
+    pub coinbase_reward_tolerance: u64,
+}
+
+/// Governance thresholds for AI-generated proposals
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VotingPolicy {
+    /// A proposal requiring a vote can only be applied at or above this AI
+    /// confidence, unless a vote approved it (default 0.8)
+    pub min_apply_confidence: f64,
+    /// Generated proposals below this AI confidence require a vote
+    /// (default 0.4; a full 144-block window alone scores at most ~0.57)
+    pub governance_confidence: f64,
+    /// Generated proposals changing any parameter by more than this many
+    /// percent require a vote (default 5.0)
+    pub large_change_percent: f64,
+    /// Registered validators and their vote weights; once any are
+    /// registered, only these can vote. Empty by default: every ballot then
+    /// counts with the weight given to `cast_vote` (1 if none), so the
+    /// default threshold is the 3-of-4 count rule.
+    #[serde(default)]
+    pub validators: BTreeMap<String, u64>,
+    /// Approve weight a vote needs to pass (default `GENESIS_BFT_THRESHOLD`,
+    /// i.e. 3-of-4 with `GENESIS_VALIDATORS` validators of weight 1)
+    #[serde(default = "default_vote_approval_weight")]
+    pub vote_approval_weight: u64,
+}
+
+impl Default for VotingPolicy {
+    fn default() -> Self {
+        Self {
+            min_apply_confidence: 0.8,
+            governance_confidence: 0.4,
+            large_change_percent: 5.0,
+            validators: BTreeMap::new(),
+            vote_approval_weight: default_vote_approval_weight(),
+        }
+    }
+}
+
+fn default_vote_approval_weight() -> u64 {
+    SovereignInvariants::GENESIS_BFT_THRESHOLD as u64
+}
+
+/// Ballots cast on one proposal: validator → (approve, weight)
+#[derive(Debug, Clone, Default)]
+struct VoteTally {
+    ballots: BTreeMap<String, (bool, u64)>,
+}
+
+impl VoteTally {
+    fn weight(&self, approve: bool) -> u64 {
+        self.ballots
+            .values()
+            .filter(|(approved, _)| *approved == approve)
+            .map(|(_, weight)| weight)
+            .sum()
+    }
+}
+
+impl VotingPolicy {
+    /// Combined weight of the registered validators, or of
+    /// `GENESIS_VALIDATORS` equal-weight validators when none are registered
+    pub fn vote_weight_total(&self) -> u64 {
+        if self.validators.is_empty() {
+            return SovereignInvariants::GENESIS_VALIDATORS as u64;
+        }
+        self.validators.values().sum()
+    }
+
+    /// Whether a generated proposal moving `current` to `proposed` with
+    /// `ai_confidence` must go to a vote
+    fn requires_voting(&self, ai_confidence: f64, current: ConsensusParameters, proposed: ConsensusParameters) -> bool {
+        let largest_change = [
+            (current.difficulty, proposed.difficulty),
+            (current.vdf_iterations, proposed.vdf_iterations),
+            (current.min_gas, proposed.min_gas),
+        ]
+        .into_iter()
+        .map(|(old, new)| AIGuardianBridge::calculate_change_percent(old, new).abs())
+        .fold(0.0, f64::max);
+
+        // A change exactly at the threshold (e.g. a full 5% swing) can come
+        // out a hair above it in floating point; that isn't "larger"
+        ai_confidence < self.governance_confidence || largest_change > self.large_change_percent + 1e-9
+    }
+}
+
+/// Which decisions auto-pilot mode takes without an operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoPilotScope {
+    /// Auto-reject catastrophic transactions instead of escalating them to
+    /// manual review (default on)
+    pub validation: bool,
+    /// Apply proposals auto-generated by `on_new_block` that need no vote
+    /// and meet `VotingPolicy::min_apply_confidence` (default off)
+    pub optimization: bool,
+}
+
+impl Default for AutoPilotScope {
+    fn default() -> Self {
+        Self {
+            validation: true,
+            optimization: false,
+        }
+    }
+}
+
+/// Tunable constants of the consensus AI controller
+///
+/// The max-change fractions bound each adjustment (and the matching PID
+/// output) per proposal and may not exceed the manifest swing bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ControllerTuning {
+    /// Max relative difficulty change per proposal (default 0.05); only
+    /// bounds increases when `difficulty_max_decrease` is set
+    pub difficulty_max_change: f64,
+    /// Max relative difficulty decrease per proposal, letting difficulty
+    /// fall more slowly than it rises (default `None` = symmetric)
+    pub difficulty_max_decrease: Option<f64>,
+    /// Max relative VDF iteration change per proposal (default 0.02)
+    pub vdf_max_change: f64,
+    /// Max relative minimum-gas change per proposal (default 0.10)
+    pub gas_max_change: f64,
+    /// Difficulty never adjusts below this (default 100)
+    pub min_difficulty: u64,
+    /// Average block time within this many seconds of the target is treated
+    /// as normal variance and leaves difficulty unchanged (default 30; keep
+    /// it well below short regtest targets)
+    pub block_time_dead_band_secs: u64,
+    /// Mempool size the gas adjustment aims for, as a fraction of one
+    /// block's capacity (default 0.5)
+    pub gas_target_utilization: f64,
+    /// Largest share of the block-time correction VDF may take on; the rest
+    /// is always left to difficulty, which reacts faster (default 0.5)
+    pub vdf_budget_share: f64,
+    /// Blocks of history kept; confidence reaches full data quality here
+    /// (default 1000)
+    pub history_capacity: usize,
+    /// Blocks of history below which confidence is a flat 0.5 (default 144)
+    pub min_confidence_blocks: usize,
+    /// Hashrate samples below which hashrate stability is a neutral 0.5
+    /// (default 30)
+    #[serde(default = "default_min_hashrate_samples")]
+    pub min_hashrate_samples: usize,
+    /// Largest share of confidence taken away when recent applied proposals
+    /// promised more improvement than they delivered (default 0.5; 0
+    /// disables calibration)
+    #[serde(default = "default_calibration_penalty")]
+    pub calibration_penalty: f64,
+}
+
+impl Default for ControllerTuning {
+    fn default() -> Self {
+        Self {
+            difficulty_max_change: 0.05,
+            difficulty_max_decrease: None,
+            vdf_max_change: 0.02,
+            gas_max_change: 0.10,
+            min_difficulty: 100,
+            block_time_dead_band_secs: 30,
+            gas_target_utilization: 0.5,
+            vdf_budget_share: 0.5,
+            history_capacity: 1000,
+            min_confidence_blocks: 144,
+            min_hashrate_samples: DEFAULT_MIN_HASHRATE_SAMPLES,
+            calibration_penalty: default_calibration_penalty(),
+        }
+    }
+}
+
+fn default_min_hashrate_samples() -> usize {
+    DEFAULT_MIN_HASHRATE_SAMPLES
+}
+
+fn default_calibration_penalty() -> f64 {
+    0.5
+}
+
+impl ControllerTuning {
+    /// Check the fractions are in range and the max-change fractions stay
+    /// within the manifest swing bounds
+    pub fn validate(&self) -> Result<(), AxiomError> {
+        let swings = [
+            ("difficulty", self.difficulty_max_change, SovereignInvariants::MAX_AI_DIFFICULTY_SWING_PERCENT),
+            ("difficulty decrease", self.difficulty_down_step(), SovereignInvariants::MAX_AI_DIFFICULTY_SWING_PERCENT),
+            ("VDF", self.vdf_max_change, SovereignInvariants::MAX_AI_VDF_SWING_PERCENT),
+            ("gas", self.gas_max_change, SovereignInvariants::MAX_AI_GAS_SWING_PERCENT),
+        ];
+        for (name, fraction, manifest_percent) in swings {
+            if !(fraction > 0.0 && fraction * 100.0 <= manifest_percent as f64) {
+                return Err(AxiomError::AIProposalRejected {
+                    reason: format!(
+                        "Tuned {} max change {} outside (0, {}%] manifest swing bound",
+                        name, fraction, manifest_percent
+                    ),
+                });
+            }
+        }
+        if self.block_time_dead_band_secs >= SovereignInvariants::MAXIMUM_BLOCK_TIME_DEVIATION_SECS {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!(
+                    "Tuned block-time dead band {}s must be below the {}s manifest deviation",
+                    self.block_time_dead_band_secs,
+                    SovereignInvariants::MAXIMUM_BLOCK_TIME_DEVIATION_SECS
+                ),
+            });
+        }
+        if !(self.gas_target_utilization > 0.0 && self.gas_target_utilization <= 1.0) {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!("Tuned gas target utilization {} outside (0, 1]", self.gas_target_utilization),
+            });
+        }
+        if !(0.0..=1.0).contains(&self.vdf_budget_share) {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!("Tuned VDF budget share {} outside [0, 1]", self.vdf_budget_share),
+            });
+        }
+        if self.history_capacity == 0 || self.min_confidence_blocks > self.history_capacity {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!(
+                    "Tuned history capacity {} must be non-zero and cover {} confidence blocks",
+                    self.history_capacity, self.min_confidence_blocks
+                ),
+            });
+        }
+        if !(0.0..=1.0).contains(&self.calibration_penalty) {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!("Tuned calibration penalty {} outside [0, 1]", self.calibration_penalty),
+            });
+        }
+        if self.min_hashrate_samples < 2 || self.min_hashrate_samples > self.history_capacity {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!(
+                    "Tuned minimum hashrate samples {} outside [2, {}]",
+                    self.min_hashrate_samples, self.history_capacity
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Max relative difficulty decrease per proposal
+    pub fn difficulty_down_step(&self) -> f64 {
+        self.difficulty_max_decrease.unwrap_or(self.difficulty_max_change)
+    }
+}
+
+/// Proportional, integral and derivative gains of one PID loop
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PIDGains {
+    pub kp: f64,
+    pub ki: f64,
+    pub kd: f64,
+}
+
+/// Gains for the difficulty, gas and VDF loops, switched as a unit with
+/// `ConsensusAIController::activate_preset`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PIDPreset {
+    pub difficulty: PIDGains,
+    pub gas: PIDGains,
+    pub vdf: PIDGains,
+}
+
+impl PIDPreset {
+    /// The controller's standard gains
+    pub const DEFAULT: Self = Self {
+        difficulty: PIDGains { kp: 0.5, ki: 0.1, kd: 0.05 },
+        gas: PIDGains { kp: 0.3, ki: 0.05, kd: 0.02 },
+        vdf: PIDGains { kp: 0.2, ki: 0.03, kd: 0.01 },
+    };
+
+    /// Half the standard gains, for volatile periods
+    pub const CONSERVATIVE: Self = Self::DEFAULT.scaled(0.5);
+
+    /// One and a half times the standard gains, for stable periods
+    pub const AGGRESSIVE: Self = Self::DEFAULT.scaled(1.5);
+
+    const fn scaled(self, factor: f64) -> Self {
+        const fn scale(gains: PIDGains, factor: f64) -> PIDGains {
+            PIDGains {
+                kp: gains.kp * factor,
+                ki: gains.ki * factor,
+                kd: gains.kd * factor,
+            }
+        }
+        Self {
+            difficulty: scale(self.difficulty, factor),
+            gas: scale(self.gas, factor),
+            vdf: scale(self.vdf, factor),
+        }
+    }
+}
+
+/// Operator sign-off for `AIGuardianBridge::reset_to_safe_defaults` and
+/// the emergency lockdown
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResetAuthorization {
+    pub operator: String,
+    pub reason: String,
+}
+
+/// A deactivation hook attempt that failed and is due again at `retry_at`
+#[derive(Debug, Clone)]
+struct HookRetry {
+    cleared: CircuitBreaker,
+    attempts: u32,
+    retry_at: u64,
+}
+
+/// State an emergency lockdown replaced, put back by `release_lockdown`
+#[derive(Debug, Clone)]
+struct Lockdown {
+    auto_pilot_mode: bool,
+    optimization_paused: bool,
+    breaker: CircuitBreaker,
+}
+
+/// Blocks pushed through `on_new_block`
+#[derive(Debug, Default)]
+struct BlockFeed {
+    recent: VecDeque<BlockMetrics>,
+    blocks_since_optimization: u64,
+}
+
+/// Raise `GuardianEvent::VetoRateHigh` when the veto rate over the last
+/// `window` decisions reaches `threshold`, and `VetoRateCleared` once it
+/// falls back below
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VetoRateAlarm {
+    pub window: usize,
+    /// Percentage, on the same 0-100 scale as `GuardianStats::veto_rate`
+    pub threshold: f64,
+    /// Minimum blocks between two alarms
+    pub cooldown_blocks: u64,
+}
+
+/// Alert when the short-term hashrate EWMA falls more than `max_drop_fraction`
+/// below the long-term baseline EWMA, e.g. an attacker or large pool leaving
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HashrateDropRule {
+    /// Span, in blocks, of the short-term EWMA
+    pub short_window: usize,
+    /// Span, in blocks, of the baseline EWMA; no alert fires before this
+    /// many blocks have been observed
+    pub long_window: usize,
+    pub max_drop_fraction: f64,
+    /// Also trip a low-severity circuit breaker, which pauses consensus
+    /// optimization but not transaction validation
+    pub trip_breaker: bool,
+}
+
+/// Severity of an active circuit breaker
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum BreakerSeverity {
+    /// Pauses consensus optimization only
+    Low,
+    /// Also rejects every transaction validation
+    High,
+}
+
+/// Lifecycle of the circuit breaker
+///
+/// ```text
+/// Armed ──trip──> Tripped ──auto-recovery block──> Recovering ──probe phase passes──> Closed
+///                   ^  ^                               │                                │
+///                   │  └────── renewed threat ─────────┘                                │
+///                   └──────────────────────────── trip ─────────────────────────────────┘
+/// ```
+/// Without a `BreakerRecovery` rule a tripped breaker stays tripped until
+/// `deactivate_circuit_breaker`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum BreakerState {
+    /// Never tripped; everything is admitted
+    #[default]
+    Armed,
+    Tripped,
+    /// Half-open: a high-severity breaker admits only a fraction of
+    /// transactions as probes, and any trip sends it back to `Tripped`
+    Recovering,
+    /// Recovered automatically or cleared by an operator
+    Closed,
+}
+
+/// Automatic recovery of a tripped breaker through a half-open probe phase,
+/// starting at the breaker's auto-recovery block
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BreakerRecovery {
+    /// Blocks the `Recovering` phase lasts before the breaker closes
+    pub probe_blocks: u64,
+    /// Share of transactions admitted while `Recovering` (0-1)
+    pub admit_fraction: f64,
+}
+
+/// Why the circuit breaker was activated, for programmatic handling; the
+/// human-readable detail travels alongside it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BreakerReason {
+    /// Tripped by an operator
+    Manual,
+    /// The security engine recommended halting the chain
+    AiDetected,
+    /// `RollingThreatBreaker` mean-threat threshold reached
+    RollingThreat,
+    /// `HashrateDropRule` cliff detected
+    HashrateDrop,
+    /// Supply invariant breach reported by the chain
+    SupplyBreach,
+    /// `ReviewTimeoutBreaker` run of unanswered manual reviews
+    ReviewTimeouts,
+    /// Operator emergency lockdown; cleared only by `release_lockdown`
+    EmergencyLockdown,
+}
+
+/// Outcome applied to a manual-review item that timed out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ReviewTimeoutResolution {
+    #[default]
+    Reject,
+    AcceptMonitored,
+}
+
+impl ReviewTimeoutResolution {
+    fn action(&self) -> GuardianAction {
+        match self {
+            ReviewTimeoutResolution::Reject => GuardianAction::Reject,
+            ReviewTimeoutResolution::AcceptMonitored => GuardianAction::AcceptMonitored,
+        }
+    }
+}
+
+/// Handling of a new manual-review item while the review queue is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ReviewQueueOverflow {
+    /// Refuse the new item with a plain `Reject`; the sender may resubmit
+    /// once the queue drains
+    #[default]
+    RejectNewest,
+    /// Evict the oldest pending review as rejected (audited like a timeout)
+    /// and queue the new item
+    RejectOldest,
+    /// Auto-reject the new item, as auto-pilot would
+    AutoReject,
+}
+
+/// What an `EscalateToGuardian` recommendation becomes when no reviewer is
+/// attached (manual override off)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EscalationFallback {
+    #[default]
+    RequireManualReview,
+    AutoReject,
+    AcceptMonitored,
+    /// Clamped to the manifest's `MAX_QUARANTINE_BLOCKS`
+    Quarantine { duration_blocks: u64 },
+}
+
+impl EscalationFallback {
+    fn action(&self, threat_level: RiskLevel) -> GuardianAction {
+        match self {
+            EscalationFallback::RequireManualReview => GuardianAction::RequireManualReview { threat_level },
+            EscalationFallback::AutoReject => GuardianAction::AutoReject,
+            EscalationFallback::AcceptMonitored => GuardianAction::AcceptMonitored,
+            EscalationFallback::Quarantine { duration_blocks } => GuardianAction::Quarantine {
+                duration_blocks: (*duration_blocks).min(SovereignInvariants::MAX_QUARANTINE_BLOCKS),
+            },
+        }
+    }
+}
+
+/// A transaction waiting for a human decision
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingReview {
+    pub tx_hash: String,
+    pub threat_level: RiskLevel,
+    pub enqueued_block: u64,
+    pub enqueued_at: u64,
+}
+
+/// Inclusive band for `fee / amount`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FeeRatioBand {
+    pub min_ratio: f64,
+    pub max_ratio: f64,
+}
+
+impl FeeRatioBand {
+    /// Classify a transaction's fee against its amount. A zero amount with a
+    /// non-zero fee counts as an overpay.
+    pub fn check(&self, amount: u64, fee: u64) -> Option<FeeAnomaly> {
+        let ratio = if amount == 0 {
+            if fee == 0 {
+                return None;
+            }
+            f64::INFINITY
+        } else {
+            fee as f64 / amount as f64
+        };
+
+        if ratio > self.max_ratio {
+            Some(FeeAnomaly::Overpay { ratio })
+        } else if ratio < self.min_ratio {
+            Some(FeeAnomaly::Underpay { ratio })
+        } else {
+            None
+        }
+    }
+}
+
+/// Fee-to-amount relationship outside the configured sanity band
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FeeAnomaly {
+    /// Fee is disproportionately large for the amount moved (e.g. dust paying a huge fee)
+    Overpay { ratio: f64 },
+    /// Fee is suspiciously small for the amount moved
+    Underpay { ratio: f64 },
+}
+
+/// Trip the circuit breaker when the mean threat score of the last `window`
+/// assessed user transactions reaches `mean_threat_threshold`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RollingThreatBreaker {
+    pub window: usize,
+    /// Threshold on the engine's 0-100 threat score scale
+    pub mean_threat_threshold: f64,
+}
+
+/// Trip a low-severity circuit breaker when `max_timeouts` manual reviews in
+/// a row time out within `window_blocks`: nobody is answering, so the node
+/// is running unattended. A review completed by an operator breaks the run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewTimeoutBreaker {
+    pub max_timeouts: usize,
+    pub window_blocks: u64,
+}
+
+/// Behaviour when the security engine errors during transaction validation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EngineFailurePolicy {
+    /// Fall back to manifest-only checks (fee, supply) and accept with
+    /// monitoring, flagging the decision as degraded
+    #[default]
+    FailOpen,
+    /// Reject the transaction while the engine is unavailable
+    FailClosed,
 }
 
 #[derive(Debug, Clone)]
@@ -24,9 +793,150 @@ struct GuardianState {
     ai_enabled: bool,
     auto_pilot_mode: bool,
     manual_override_active: bool,
-    total_ai_decisions: u64,
-    guardian_vetoes: u64,
+    shadow_mode: bool,
+    optimization_paused: bool,
     last_veto_reason: Option<String>,
+    pending_reviews: VecDeque<PendingReview>,
+    review_queue_overflows: u64,
+    // Blocks of the current run of review timeouts, oldest first
+    review_timeout_run: VecDeque<u64>,
+}
+
+/// Per-decision counters, kept out of `GuardianState` so concurrent
+/// validations don't serialize on its write lock just to count
+#[derive(Debug, Default)]
+struct DecisionCounters {
+    total_ai_decisions: AtomicU64,
+    guardian_vetoes: AtomicU64,
+    shadow_vetoes: AtomicU64,
+    // Indexed by `VetoCategory as usize`
+    veto_categories: [AtomicU64; VetoCategory::ALL.len()],
+}
+
+/// Threat assessments keyed by transaction and the consensus-parameter epoch
+/// (application nonce) they were made under, oldest evicted first
+#[derive(Debug, Clone)]
+struct AssessmentCache {
+    capacity: usize,
+    entries: HashMap<([u8; 32], u64), ThreatAssessment>,
+    order: VecDeque<([u8; 32], u64)>,
+}
+
+impl AssessmentCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// BLAKE3 digest of every assessed field of `profile`. The caller's
+    /// `hash` is not trusted to identify the contents it claims to.
+    fn key(profile: &TransactionRiskProfile) -> [u8; 32] {
+        let mut hasher = blake3::Hasher::new();
+        for text in [&profile.hash, &profile.sender, &profile.recipient] {
+            hasher.update(&(text.len() as u64).to_le_bytes());
+            hasher.update(text.as_bytes());
+        }
+        for value in [
+            profile.timestamp,
+            profile.amount,
+            profile.gas_price,
+            profile.zk_proof_size as u64,
+            profile.sender_history_count,
+            profile.recipient_history_count,
+            profile.time_since_last_sender_tx,
+            profile.time_since_last_recipient_tx,
+            profile.contract_bytecode_size as u64,
+            profile.vdf_verification_time_ms,
+        ] {
+            hasher.update(&value.to_le_bytes());
+        }
+        hasher.update(&profile.sender_reputation_score.to_le_bytes());
+        hasher.update(&[profile.is_contract_deployment as u8]);
+        *hasher.finalize().as_bytes()
+    }
+
+    fn get(&self, digest: &[u8; 32], epoch: u64) -> Option<ThreatAssessment> {
+        self.entries.get(&(*digest, epoch)).cloned()
+    }
+
+    fn insert(&mut self, digest: [u8; 32], epoch: u64, assessment: ThreatAssessment) {
+        let key = (digest, epoch);
+        if self.entries.insert(key, assessment).is_some() {
+            return;
+        }
+        self.order.push_back(key);
+        while self.order.len() > self.capacity {
+            if let Some(evicted) = self.order.pop_front() {
+                self.entries.remove(&evicted);
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+}
+
+/// Recent veto outcomes for `VetoRateAlarm`
+#[derive(Debug, Clone)]
+struct VetoRateMonitor {
+    rule: VetoRateAlarm,
+    outcomes: VecDeque<bool>,
+    alarmed: bool,
+    last_alarm_block: Option<u64>,
+}
+
+impl VetoRateMonitor {
+    fn new(rule: VetoRateAlarm) -> Self {
+        Self {
+            rule,
+            outcomes: VecDeque::new(),
+            alarmed: false,
+            last_alarm_block: None,
+        }
+    }
+
+    /// Record one decision and return the alarm transition it caused, if any
+    fn observe(&mut self, vetoed: bool, current_block: u64, ts: u64) -> Option<GuardianEvent> {
+        let window = self.rule.window.max(1);
+        if self.outcomes.len() >= window {
+            self.outcomes.pop_front();
+        }
+        self.outcomes.push_back(vetoed);
+        if self.outcomes.len() < window {
+            return None;
+        }
+
+        let rate = self.outcomes.iter().filter(|&&vetoed| vetoed).count() as f64 / window as f64 * 100.0;
+        if rate >= self.rule.threshold {
+            let cooling_down = self
+                .last_alarm_block
+                .is_some_and(|last| current_block.saturating_sub(last) < self.rule.cooldown_blocks);
+            if self.alarmed || cooling_down {
+                return None;
+            }
+            self.alarmed = true;
+            self.last_alarm_block = Some(current_block);
+            Some(GuardianEvent::VetoRateHigh {
+                rate,
+                block_height: current_block,
+                ts,
+            })
+        } else if self.alarmed {
+            self.alarmed = false;
+            Some(GuardianEvent::VetoRateCleared {
+                rate,
+                block_height: current_block,
+                ts,
+            })
+        } else {
+            None
+        }
+    }
 }
 
 /// AI-driven consensus optimizer with Guardian bounds
@@ -34,16 +944,51 @@ pub struct ConsensusAIController {
     current_difficulty: u64,
     current_vdf_iterations: u64,
     current_min_gas: u64,
+
+    // Block-time target source (mainnet pinned to the manifest)
+    profile: NetworkProfile,
+    tuning: ControllerTuning,
     
     // PID controllers for smooth adjustments
     difficulty_pid: PIDController,
     gas_pid: PIDController,
     vdf_pid: PIDController,
+
+    // Named gain presets registered at construction, and the active one
+    pid_presets: BTreeMap<String, PIDPreset>,
+    active_preset: String,
     
-    // Historical data (last 1000 blocks)
+    // Historical data (last `tuning.history_capacity` blocks)
     block_time_history: Vec<u64>,
     hashrate_history: Vec<f64>,
-    mempool_history: Vec<usize>,
+    // Running moments of the two histories above, kept in step with them
+    block_time_moments: RunningMoments,
+    hashrate_moments: RunningMoments,
+    mempool_history: Vec<MempoolSample>,
+    // Average fee per transaction of each ingested non-empty block
+    fee_history: Vec<u64>,
+    // Network health score after each ingested block
+    health_history: Vec<f64>,
+    mempool_capacity: usize,
+
+    // Highest block height ingested so far (replays are ignored)
+    last_ingested_height: Option<u64>,
+    // The block at `last_ingested_height`, which the next one must follow;
+    // not part of snapshots, so the first block after an import is unchecked
+    last_ingested_block: Option<BlockMetrics>,
+
+    // Startup warm-up: blocks required vs. blocks ingested since the first update
+    warm_up_blocks: u64,
+    blocks_ingested: u64,
+
+    // Successful proposal applications so far; guards against replays
+    application_nonce: u64,
+    // Height of the last applied proposal; `None` until the first one
+    last_applied_height: Option<u64>,
+
+    // Hashrate-cliff detector and alerts not yet collected by the bridge
+    hashrate_drop: Option<HashrateDropTracker>,
+    hashrate_alerts: Vec<HashrateDrop>,
     
     // AI learning state
     optimization_history: Vec<OptimizationRecord>,
@@ -56,32 +1001,311 @@ struct PIDController {
     kd: f64,
     integral: f64,
     previous_error: f64,
+    // Unclamped output of the last update, for back-calculation
+    last_raw_output: f64,
+    // First-order derivative filter coefficient (None = raw derivative)
+    derivative_filter: Option<f64>,
+    filtered_derivative: f64,
     output_min: f64,
     output_max: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct OptimizationRecord {
-    timestamp: u64,
-    block_height: u64,
-    parameter: String,
-    old_value: u64,
-    new_value: u64,
-    predicted_improvement: f64,
-    actual_improvement: f64,
-    guardian_approved: bool,
+/// One observation of the mempool. With a byte total, occupancy is measured
+/// against `MAX_BLOCK_SIZE_BYTES`; without, the count is measured against
+/// the controller's `mempool_capacity`.
+#[derive(Debug, Clone, Copy)]
+struct MempoolSample {
+    transactions: usize,
+    bytes: Option<usize>,
+}
+
+/// Running mean and sum of squared deviations (Welford) over a sliding
+/// window, so the stability metrics don't rescan the history on every read
+#[derive(Debug, Clone, Default)]
+struct RunningMoments {
+    count: usize,
+    mean: f64,
+    m2: f64,
+}
+
+impl RunningMoments {
+    fn push(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    /// Remove a sample that was previously pushed (the evicted end of the
+    /// ring buffer)
+    fn evict(&mut self, x: f64) {
+        if self.count <= 1 {
+            *self = Self::default();
+            return;
+        }
+        let previous_mean = self.mean;
+        self.count -= 1;
+        self.mean -= (x - previous_mean) / self.count as f64;
+        self.m2 = (self.m2 - (x - previous_mean) * (x - self.mean)).max(0.0);
+    }
+
+    /// Population variance; zero until there are samples
+    fn variance(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.m2 / self.count as f64
+        }
+    }
+}
+
+/// Circuit-breaker trip conditions evaluated over successive assessments
+#[derive(Debug, Clone)]
+struct ThreatWindow {
+    rule: Option<RollingThreatBreaker>,
+    scores: VecDeque<f64>,
+}
+
+impl ThreatWindow {
+    fn new(rule: Option<RollingThreatBreaker>) -> Self {
+        Self {
+            rule,
+            scores: VecDeque::new(),
+        }
+    }
+
+    /// Record an assessment and return why the breaker should trip, if it
+    /// should. The rolling window restarts after a trip.
+    fn observe(&mut self, assessment: &ThreatAssessment) -> Option<(BreakerReason, String)> {
+        if let SecurityAction::HaltChain { emergency_level } = assessment.recommended_action {
+            if emergency_level >= 9 {
+                self.scores.clear();
+                return Some((BreakerReason::AiDetected, "AI detected critical chain-level threat".to_string()));
+            }
+        }
+
+        let rule = self.rule?;
+        let window = rule.window.max(1);
+        if self.scores.len() >= window {
+            self.scores.pop_front();
+        }
+        self.scores.push_back(assessment.threat_score);
+        if self.scores.len() < window {
+            return None;
+        }
+
+        let mean = self.scores.iter().sum::<f64>() / window as f64;
+        if mean < rule.mean_threat_threshold {
+            return None;
+        }
+
+        self.scores.clear();
+        Some((
+            BreakerReason::RollingThreat,
+            format!(
+                "Rolling threat mean {:.1} over {} transactions reached threshold {:.1}",
+                mean, window, rule.mean_threat_threshold
+            ),
+        ))
+    }
+}
+
+/// A detected hashrate cliff
+#[derive(Debug, Clone, Copy)]
+struct HashrateDrop {
+    block_height: u64,
+    short_term_hashrate: f64,
+    baseline_hashrate: f64,
+    drop_fraction: f64,
+}
+
+/// Short- vs long-term hashrate EWMAs for `HashrateDropRule`
+#[derive(Debug, Clone)]
+struct HashrateDropTracker {
+    rule: HashrateDropRule,
+    short_ewma: f64,
+    long_ewma: f64,
+    samples: usize,
+    alerting: bool,
+}
+
+impl HashrateDropTracker {
+    fn new(rule: HashrateDropRule) -> Self {
+        Self {
+            rule,
+            short_ewma: 0.0,
+            long_ewma: 0.0,
+            samples: 0,
+            alerting: false,
+        }
+    }
+
+    /// Fold in one block's hashrate estimate. Alerts once when the drop
+    /// crosses the threshold and re-arms after it recovers.
+    fn observe(&mut self, block_height: u64, hashrate: f64) -> Option<HashrateDrop> {
+        if !hashrate.is_finite() || hashrate < 0.0 {
+            return None;
+        }
+
+        if self.samples == 0 {
+            self.short_ewma = hashrate;
+            self.long_ewma = hashrate;
+        } else {
+            let short_alpha = 2.0 / (self.rule.short_window.max(1) as f64 + 1.0);
+            let long_alpha = 2.0 / (self.rule.long_window.max(1) as f64 + 1.0);
+            self.short_ewma += short_alpha * (hashrate - self.short_ewma);
+            self.long_ewma += long_alpha * (hashrate - self.long_ewma);
+        }
+        self.samples += 1;
+
+        if self.samples < self.rule.long_window || self.long_ewma <= 0.0 {
+            return None;
+        }
+
+        let drop_fraction = 1.0 - self.short_ewma / self.long_ewma;
+        if drop_fraction <= self.rule.max_drop_fraction {
+            self.alerting = false;
+            return None;
+        }
+        if self.alerting {
+            return None;
+        }
+
+        self.alerting = true;
+        Some(HashrateDrop {
+            block_height,
+            short_term_hashrate: self.short_ewma,
+            baseline_hashrate: self.long_ewma,
+            drop_fraction,
+        })
+    }
+}
+
+/// A telemetry value, or why it can't be computed yet
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Telemetry<T> {
+    Ready(T),
+    /// Fewer samples than the metric needs
+    InsufficientData { have: usize, need: usize },
+}
+
+impl<T> Telemetry<T> {
+    /// `Ready(compute())` when `have >= need`
+    fn require(have: usize, need: usize, compute: impl FnOnce() -> T) -> Self {
+        if have < need {
+            Telemetry::InsufficientData { have, need }
+        } else {
+            Telemetry::Ready(compute())
+        }
+    }
+
+    /// The value, if ready
+    pub fn ready(self) -> Option<T> {
+        match self {
+            Telemetry::Ready(value) => Some(value),
+            Telemetry::InsufficientData { .. } => None,
+        }
+    }
+
+    /// The value, or `default` when there isn't enough data yet
+    pub fn unwrap_or(self, default: T) -> T {
+        self.ready().unwrap_or(default)
+    }
+}
+
+/// Block-time error allocated to each consensus lever
+#[derive(Debug, Clone, Copy)]
+struct CorrectionBudget {
+    difficulty_error: f64,
+    vdf_error: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct OptimizationRecord {
+    proposal_id: String,
+    timestamp: u64,
+    block_height: u64,
+    parameter: String,
+    old_value: u64,
+    new_value: u64,
+    predicted_improvement: f64,
+    /// Back-filled by `record_optimization_outcome`
+    actual_improvement: Option<f64>,
+    guardian_approved: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct CircuitBreaker {
-    is_active: bool,
+    state: BreakerState,
     activation_block: Option<u64>,
     reason: Option<String>,
+    category: Option<BreakerReason>,
+    severity: Option<BreakerSeverity>,
     auto_recovery_block: Option<u64>,
+    // Probe phase: end block, transactions seen and admitted so far
+    recovery_end_block: Option<u64>,
+    probes_seen: u64,
+    probes_admitted: u64,
+}
+
+impl CircuitBreaker {
+    fn inactive() -> Self {
+        Self {
+            state: BreakerState::Armed,
+            activation_block: None,
+            reason: None,
+            category: None,
+            severity: None,
+            auto_recovery_block: None,
+            recovery_end_block: None,
+            probes_seen: 0,
+            probes_admitted: 0,
+        }
+    }
+
+    fn closed() -> Self {
+        Self {
+            state: BreakerState::Closed,
+            ..Self::inactive()
+        }
+    }
+
+    /// Tripped or still recovering
+    pub fn is_active(&self) -> bool {
+        matches!(self.state, BreakerState::Tripped | BreakerState::Recovering)
+    }
+
+    pub fn state(&self) -> BreakerState {
+        self.state
+    }
+
+    pub fn activation_block(&self) -> Option<u64> {
+        self.activation_block
+    }
+
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    pub fn category(&self) -> Option<BreakerReason> {
+        self.category
+    }
+
+    pub fn severity(&self) -> Option<BreakerSeverity> {
+        self.severity
+    }
+
+    pub fn auto_recovery_block(&self) -> Option<u64> {
+        self.auto_recovery_block
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConsensusOptimizationProposal {
+    /// Serialization schema; see `deserialize_compat` for older versions
+    #[serde(default = "current_schema_version")]
+    pub schema_version: u16,
+
     pub proposal_id: String,
     pub block_height: u64,
     pub timestamp: u64,
@@ -89,6 +1313,9 @@ pub struct ConsensusOptimizationProposal {
     // Difficulty adjustment
     pub current_difficulty: u64,
     pub proposed_difficulty: u64,
+    /// Percent change from current to proposed; positive means an increase.
+    /// Rounded when `BridgeConfig::change_percent_precision` is set, as are
+    /// the other two change percents.
     pub difficulty_change_percent: f64,
     
     // VDF adjustment
@@ -110,567 +1337,7475 @@ pub struct ConsensusOptimizationProposal {
     // Confidence and status
     pub ai_confidence: f64,
     pub expected_improvement: f64,
-    pub guardian_pre_approved: bool,
+    pub pre_approval: PreApproval,
     pub requires_voting: bool,
+
+    /// Controller application nonce the proposal was generated against; it
+    /// can only be applied while the controller is still at this nonce
+    #[serde(default)]
+    pub application_nonce: u64,
+
+    /// How urgently the network needs this change, in `[0.0, 1.0]`
+    #[serde(default)]
+    pub urgency: f64,
 }
 
-#[derive(Debug, Clone)]
-pub struct BlockMetrics {
-    pub height: u64,
-    pub timestamp: u64,
-    pub block_time: u64,
-    pub difficulty: u64,
-    pub vdf_iterations: u64,
-    pub transaction_count: usize,
-    pub total_fees: u64,
-    pub hashrate_estimate: f64,
+/// Difference between two proposals, `self` minus `other` (see
+/// [`ConsensusOptimizationProposal::diff`])
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ProposalDelta {
+    pub block_height: i128,
+    pub difficulty: ParameterDelta,
+    pub vdf_iterations: ParameterDelta,
+    pub min_gas: ParameterDelta,
+    pub ai_confidence: f64,
+    pub network_health_score: f64,
 }
 
-impl AIGuardianBridge {
-    pub fn new(security_engine: Arc<MultiLayerSecurityEngine>) -> Self {
+/// Difference in one proposed parameter
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ParameterDelta {
+    /// Difference between the proposed values
+    pub proposed: i128,
+    /// Difference between the proposed change percentages, in points
+    pub change_percent: f64,
+}
+
+impl ParameterDelta {
+    fn between(proposed: u64, other_proposed: u64, change_percent: f64, other_change_percent: f64) -> Self {
         Self {
-            security_engine,
-            guardian_state: Arc::new(RwLock::new(GuardianState {
-                ai_enabled: true,
-                auto_pilot_mode: false,
-                manual_override_active: false,
-                total_ai_decisions: 0,
-                guardian_vetoes: 0,
-                last_veto_reason: None,
-            })),
-            consensus_ai: Arc::new(RwLock::new(ConsensusAIController::new())),
-            emergency_circuit_breaker: Arc::new(RwLock::new(CircuitBreaker {
-                is_active: false,
-                activation_block: None,
-                reason: None,
-                auto_recovery_block: None,
-            })),
+            proposed: proposed as i128 - other_proposed as i128,
+            change_percent: change_percent - other_change_percent,
         }
     }
+}
 
-    /// Validate transaction with AI + Guardian oversight
-    pub fn validate_transaction_with_guardian(
-        &self,
-        profile: TransactionRiskProfile,
-        current_block: u64,
-    ) -> Result<GuardianDecision, AxiomError> {
-        // Check circuit breaker
-        let breaker = self.emergency_circuit_breaker.read();
-        if breaker.is_active {
-            return Err(AxiomError::AIProposalRejected {
-                reason: format!(
-                    "Emergency circuit breaker active: {}",
-                    breaker.reason.as_ref().unwrap_or(&"Unknown".to_string())
-                ),
-            });
+impl ConsensusOptimizationProposal {
+    /// Round the three change percents to `decimals` places (half away from
+    /// zero). A recomputation from the current and proposed values agrees
+    /// with the stored figure to within `change_percent_tolerance(decimals)`.
+    pub fn round_change_percents(&mut self, decimals: u32) {
+        let scale = 10f64.powi(decimals as i32);
+        for percent in [
+            &mut self.difficulty_change_percent,
+            &mut self.vdf_change_percent,
+            &mut self.gas_change_percent,
+        ] {
+            *percent = (*percent * scale).round() / scale;
         }
-        drop(breaker);
+    }
 
-        // Get AI threat assessment
-        let threat_assessment = self.security_engine.assess_transaction_threat(&profile, current_block)?;
+    /// Largest difference rounding to `decimals` places can introduce
+    pub fn change_percent_tolerance(decimals: u32) -> f64 {
+        0.5 * 10f64.powi(-(decimals as i32)) + 1e-9
+    }
 
-        // Guardian verification of AI decision
-        let guardian_decision = self.guardian_verify_ai_decision(&threat_assessment, &profile, current_block)?;
+    /// Whether the proposal's urgency meets `threshold` and may be fast-tracked
+    pub fn is_urgent(&self, threshold: f64) -> bool {
+        self.urgency >= threshold
+    }
 
-        // Update state
-        let mut state = self.guardian_state.write();
-        state.total_ai_decisions += 1;
+    /// How this proposal differs from `other`, e.g. a fresh proposal
+    /// against a previously rejected one. Positive values mean higher here.
+    pub fn diff(&self, other: &ConsensusOptimizationProposal) -> ProposalDelta {
+        ProposalDelta {
+            block_height: self.block_height as i128 - other.block_height as i128,
+            difficulty: ParameterDelta::between(
+                self.proposed_difficulty,
+                other.proposed_difficulty,
+                self.difficulty_change_percent,
+                other.difficulty_change_percent,
+            ),
+            vdf_iterations: ParameterDelta::between(
+                self.proposed_vdf,
+                other.proposed_vdf,
+                self.vdf_change_percent,
+                other.vdf_change_percent,
+            ),
+            min_gas: ParameterDelta::between(
+                self.proposed_min_gas,
+                other.proposed_min_gas,
+                self.gas_change_percent,
+                other.gas_change_percent,
+            ),
+            ai_confidence: self.ai_confidence - other.ai_confidence,
+            network_health_score: self.network_health_score - other.network_health_score,
+        }
+    }
 
-        if !guardian_decision.approved {
-            state.guardian_vetoes += 1;
-            state.last_veto_reason = Some(
-                guardian_decision.veto_reason.clone().unwrap_or_default(),
-            );
+    /// Deserialize a proposal written by any supported schema version.
+    ///
+    /// Blobs without a `schema_version` are treated as version 1; fields
+    /// added since are filled with neutral defaults and the result is
+    /// upgraded to `PROPOSAL_SCHEMA_VERSION`. A legacy pre-approval flag is
+    /// kept, but marked as having no recorded checks.
+    pub fn deserialize_compat(json: &str) -> Result<Self, AxiomError> {
+        let probe: SchemaProbe =
+            serde_json::from_str(json).map_err(|e| AxiomError::SerializationError(e.to_string()))?;
+
+        match probe.schema_version {
+            1 | 2 => serde_json::from_str::<LegacyProposal>(json)
+                .map(Self::from)
+                .map_err(|e| AxiomError::SerializationError(e.to_string())),
+            PROPOSAL_SCHEMA_VERSION => {
+                serde_json::from_str(json).map_err(|e| AxiomError::SerializationError(e.to_string()))
+            }
+            other => Err(AxiomError::SerializationError(format!(
+                "Unsupported proposal schema version {} (current {})",
+                other, PROPOSAL_SCHEMA_VERSION
+            ))),
         }
+    }
+}
+
+fn current_schema_version() -> u16 {
+    PROPOSAL_SCHEMA_VERSION
+}
+
+/// Reads only the version marker; absent means the pre-versioning schema
+#[derive(Deserialize)]
+struct SchemaProbe {
+    #[serde(default = "legacy_schema_version")]
+    schema_version: u16,
+}
+
+fn legacy_schema_version() -> u16 {
+    1
+}
+
+/// `ConsensusOptimizationProposal` as serialized by schema versions 1 and 2
+#[derive(Deserialize)]
+struct LegacyProposal {
+    proposal_id: String,
+    block_height: u64,
+    timestamp: u64,
+    current_difficulty: u64,
+    proposed_difficulty: u64,
+    difficulty_change_percent: f64,
+    current_vdf: u64,
+    proposed_vdf: u64,
+    vdf_change_percent: f64,
+    current_min_gas: u64,
+    proposed_min_gas: u64,
+    gas_change_percent: f64,
+    avg_block_time_last_144: f64,
+    hashrate_trend: f64,
+    mempool_congestion: f64,
+    network_health_score: f64,
+    ai_confidence: f64,
+    expected_improvement: f64,
+    guardian_pre_approved: bool,
+    requires_voting: bool,
+    #[serde(default)]
+    urgency: f64,
+}
+
+impl From<LegacyProposal> for ConsensusOptimizationProposal {
+    fn from(v1: LegacyProposal) -> Self {
+        // The legacy flag is only trusted if the manifest checks agree
+        let mut pre_approval = PreApproval::evaluate(
+            ConsensusParameters {
+                difficulty: v1.current_difficulty,
+                vdf_iterations: v1.current_vdf,
+                min_gas: v1.current_min_gas,
+            },
+            ConsensusParameters {
+                difficulty: v1.proposed_difficulty,
+                vdf_iterations: v1.proposed_vdf,
+                min_gas: v1.proposed_min_gas,
+            },
+            v1.block_height,
+        );
+        pre_approval.bounds_ok &= v1.guardian_pre_approved;
+        pre_approval.notes.push(format!(
+            "migrated from a legacy schema (guardian_pre_approved: {}); checks re-run on migration",
+            v1.guardian_pre_approved
+        ));
 
-        Ok(guardian_decision)
+        Self {
+            schema_version: PROPOSAL_SCHEMA_VERSION,
+            proposal_id: v1.proposal_id,
+            block_height: v1.block_height,
+            timestamp: v1.timestamp,
+            current_difficulty: v1.current_difficulty,
+            proposed_difficulty: v1.proposed_difficulty,
+            difficulty_change_percent: v1.difficulty_change_percent,
+            current_vdf: v1.current_vdf,
+            proposed_vdf: v1.proposed_vdf,
+            vdf_change_percent: v1.vdf_change_percent,
+            current_min_gas: v1.current_min_gas,
+            proposed_min_gas: v1.proposed_min_gas,
+            gas_change_percent: v1.gas_change_percent,
+            avg_block_time_last_144: v1.avg_block_time_last_144,
+            hashrate_trend: v1.hashrate_trend,
+            mempool_congestion: v1.mempool_congestion,
+            network_health_score: v1.network_health_score,
+            ai_confidence: v1.ai_confidence,
+            expected_improvement: v1.expected_improvement,
+            pre_approval,
+            requires_voting: v1.requires_voting,
+            application_nonce: 0,
+            // v1 blobs lack urgency and default to 0.0 (never fast-tracked); v2 urgency is kept
+            urgency: v1.urgency,
+        }
     }
+}
 
-    /// Guardian verification layer - CANNOT BE BYPASSED
-    fn guardian_verify_ai_decision(
-        &self,
-        ai_assessment: &ThreatAssessment,
-        profile: &TransactionRiskProfile,
-        _current_block: u64,
-    ) -> Result<GuardianDecision, AxiomError> {
-        // Rule 1: Verify transaction doesn't exceed supply
-        SovereignInvariants::verify_supply_integrity(profile.amount)?;
+/// Record of the Guardian's manifest checks on a proposal
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PreApproval {
+    /// Height at which the checks ran
+    pub checked_at_block: u64,
+    /// Whether every proposed change is within the manifest bounds
+    pub bounds_ok: bool,
+    /// One line per check, with the violation if it failed
+    pub notes: Vec<String>,
+}
 
-        // Rule 2: Verify minimum fee
-        if profile.gas_price < SovereignInvariants::MIN_TRANSACTION_FEE {
-            return Ok(GuardianDecision {
-                approved: false,
-                veto_reason: Some(format!(
-                    "Transaction fee {} below minimum {}",
-                    profile.gas_price,
-                    SovereignInvariants::MIN_TRANSACTION_FEE
-                )),
-                action: GuardianAction::Reject,
-                threat_assessment: ai_assessment.clone(),
-            });
+impl PreApproval {
+    /// Run the difficulty, VDF and gas bound checks of `proposed` against
+    /// `current` and record each result
+    pub fn evaluate(current: ConsensusParameters, proposed: ConsensusParameters, checked_at_block: u64) -> Self {
+        let checks = [
+            (
+                "difficulty",
+                SovereignInvariants::verify_ai_difficulty_proposal(current.difficulty, proposed.difficulty),
+            ),
+            (
+                "vdf",
+                SovereignInvariants::verify_ai_vdf_proposal(current.vdf_iterations, proposed.vdf_iterations),
+            ),
+            ("gas", SovereignInvariants::verify_ai_gas_proposal(current.min_gas, proposed.min_gas)),
+        ];
+
+        let bounds_ok = checks.iter().all(|(_, result)| result.is_ok());
+        let notes = checks
+            .iter()
+            .map(|(check, result)| match result {
+                Ok(()) => format!("{}: within manifest bounds", check),
+                Err(e) => format!("{}: {}", check, e),
+            })
+            .collect();
+
+        Self {
+            checked_at_block,
+            bounds_ok,
+            notes,
         }
+    }
+}
 
-        // Rule 3: Check if AI wants to escalate to Guardian
-        if ai_assessment.guardian_override_required {
-            log::warn!("🛡️  Guardian override required - AI threat score: {:.2}", ai_assessment.threat_score);
+/// Lifecycle of a consensus optimization proposal
+///
+/// ```text
+/// Generated ─┬─> PendingVote ─┬─> Approved ──> Applied
+///            ├────────────────┘       │
+///            └─> Rejected / Stale <───┘ (from any non-terminal state)
+/// ```
+/// `Generated` proposals that don't require voting may be approved or
+/// applied directly; the others must pass through `PendingVote`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProposalStatus {
+    Generated,
+    PendingVote,
+    Approved,
+    Applied,
+    Rejected,
+    Stale,
+}
 
-            let state = self.guardian_state.read();
-            if state.auto_pilot_mode && matches!(ai_assessment.risk_level, RiskLevel::Catastrophic) {
-                return Ok(GuardianDecision {
-                    approved: false,
-                    veto_reason: Some(format!(
-                        "Auto-pilot rejection: Catastrophic threat (score: {:.2})",
-                        ai_assessment.threat_score
-                    )),
-                    action: GuardianAction::AutoReject,
-                    threat_assessment: ai_assessment.clone(),
-                });
-            }
+impl ProposalStatus {
+    /// Applied, Rejected and Stale proposals can never change again
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, ProposalStatus::Applied | ProposalStatus::Rejected | ProposalStatus::Stale)
+    }
+
+    /// Whether a proposal with the given `requires_voting` may move from
+    /// this status to `next`
+    pub fn can_transition_to(&self, next: ProposalStatus, requires_voting: bool) -> bool {
+        use ProposalStatus::*;
+        match (self, next) {
+            (Generated, PendingVote) => true,
+            (Generated, Approved) | (Generated, Applied) => !requires_voting,
+            (PendingVote, Approved) => true,
+            (Approved, Applied) => true,
+            (from, Rejected) | (from, Stale) => !from.is_terminal(),
+            _ => false,
         }
+    }
+}
 
-        // Rule 4: Apply AI's recommended action with Guardian bounds
-        let action = match &ai_assessment.recommended_action {
-            SecurityAction::Accept => GuardianAction::Accept,
-            SecurityAction::AcceptWithMonitoring => GuardianAction::AcceptMonitored,
-            SecurityAction::Quarantine { duration_blocks } => {
-                let max_duration = 1440;
-                let safe_duration = (*duration_blocks).min(max_duration);
-                GuardianAction::Quarantine {
-                    duration_blocks: safe_duration,
-                }
-            }
-            SecurityAction::Reject { reason: _ } => GuardianAction::Reject,
-            SecurityAction::EscalateToGuardian { threat_level } => {
-                GuardianAction::RequireManualReview {
-                    threat_level: *threat_level,
-                }
+/// A proposal generated by this bridge, as the lifecycle tracker holds it
+#[derive(Debug, Clone, Copy)]
+struct TrackedProposal {
+    status: ProposalStatus,
+    // Fixed at generation; the caller's copy of the proposal isn't trusted
+    requires_voting: bool,
+    ai_confidence: f64,
+    expected_improvement: f64,
+}
+
+/// Live parameters immediately after a proposal was applied, returned by
+/// [`AIGuardianBridge::apply_consensus_optimization`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppliedParameters {
+    pub parameters: ConsensusParameters,
+    /// Nonce the next proposal must carry (the applied proposal's + 1)
+    pub application_nonce: u64,
+}
+
+/// Accumulated state of one PID loop; gains and limits come from the
+/// importing controller's tuning
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PIDState {
+    pub integral: f64,
+    pub previous_error: f64,
+    pub last_raw_output: f64,
+    pub filtered_derivative: f64,
+}
+
+/// Portable copy of a controller's live state, produced by
+/// [`ConsensusAIController::export_state`]. `checksum` is an unkeyed BLAKE3
+/// digest over every other field and is verified on import.
+///
+/// The checksum only detects accidental corruption (truncated files, bit
+/// rot); anyone who can edit a snapshot can recompute it. It does not
+/// authenticate the snapshot. The bounds checks on import keep a tampered
+/// snapshot within the manifest, but callers loading snapshots from an
+/// untrusted location must authenticate them separately.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ControllerSnapshot {
+    pub parameters: ConsensusParameters,
+    pub difficulty_pid: PIDState,
+    pub gas_pid: PIDState,
+    pub vdf_pid: PIDState,
+    pub application_nonce: u64,
+    pub last_ingested_height: Option<u64>,
+    pub warm_up_blocks: u64,
+    pub blocks_ingested: u64,
+    pub checksum: String,
+}
+
+impl ControllerSnapshot {
+    /// Hex BLAKE3 digest over the snapshot contents, excluding `checksum`.
+    /// Corruption detection only, see [`ControllerSnapshot`].
+    pub fn compute_checksum(&self) -> String {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&self.parameters.difficulty.to_le_bytes());
+        hasher.update(&self.parameters.vdf_iterations.to_le_bytes());
+        hasher.update(&self.parameters.min_gas.to_le_bytes());
+        for pid in [&self.difficulty_pid, &self.gas_pid, &self.vdf_pid] {
+            hasher.update(&pid.integral.to_le_bytes());
+            hasher.update(&pid.previous_error.to_le_bytes());
+            hasher.update(&pid.last_raw_output.to_le_bytes());
+            hasher.update(&pid.filtered_derivative.to_le_bytes());
+        }
+        hasher.update(&self.application_nonce.to_le_bytes());
+        match self.last_ingested_height {
+            Some(height) => {
+                hasher.update(&[1]);
+                hasher.update(&height.to_le_bytes());
             }
-            SecurityAction::HaltChain { emergency_level } => {
-                if *emergency_level >= 9 {
-                    self.activate_circuit_breaker(
-                        0,
-                        "AI detected critical chain-level threat".to_string(),
-                    )?;
-                    GuardianAction::ChainHalt
-                } else {
-                    GuardianAction::RequireManualReview {
-                        threat_level: RiskLevel::Critical,
-                    }
-                }
+            None => {
+                hasher.update(&[0]);
             }
-        };
+        }
+        hasher.update(&self.warm_up_blocks.to_le_bytes());
+        hasher.update(&self.blocks_ingested.to_le_bytes());
+        hasher.finalize().to_hex().to_string()
+    }
 
-        Ok(GuardianDecision {
-            approved: !matches!(
-                action,
-                GuardianAction::Reject | GuardianAction::AutoReject | GuardianAction::ChainHalt
-            ),
-            veto_reason: None,
-            action,
-            threat_assessment: ai_assessment.clone(),
+    /// Reject snapshots whose checksum does not match or whose contents
+    /// could not have come from a healthy controller
+    fn verify(&self, min_difficulty: u64) -> Result<(), AxiomError> {
+        if self.checksum != self.compute_checksum() {
+            return Err(AxiomError::StateCorruption(
+                "Controller snapshot checksum mismatch".to_string(),
+            ));
+        }
+        if self.parameters.vdf_iterations < SovereignInvariants::MINIMUM_VDF_ITERATIONS {
+            return Err(AxiomError::StateCorruption(format!(
+                "Snapshot VDF iterations {} below minimum {}",
+                self.parameters.vdf_iterations,
+                SovereignInvariants::MINIMUM_VDF_ITERATIONS
+            )));
+        }
+        if self.parameters.difficulty < min_difficulty {
+            return Err(AxiomError::StateCorruption(format!(
+                "Snapshot difficulty {} below minimum {}",
+                self.parameters.difficulty, min_difficulty
+            )));
+        }
+        for (name, pid) in [("difficulty", &self.difficulty_pid), ("gas", &self.gas_pid), ("vdf", &self.vdf_pid)] {
+            let finite = pid.integral.is_finite()
+                && pid.previous_error.is_finite()
+                && pid.last_raw_output.is_finite()
+                && pid.filtered_derivative.is_finite();
+            if !finite {
+                return Err(AxiomError::StateCorruption(format!(
+                    "Snapshot {} PID state is not finite",
+                    name
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How urgently a wallet wants its transaction included
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FeePriority {
+    Low,
+    Medium,
+    High,
+}
+
+impl FeePriority {
+    /// Multiplier applied to the live minimum gas price. Higher priorities
+    /// carry a small base premium and grow faster with mempool congestion
+    /// (`0.0` = empty, `1.0` = saturated).
+    fn fee_multiplier(&self, congestion: f64) -> f64 {
+        let congestion = congestion.clamp(0.0, 1.0);
+        match self {
+            FeePriority::Low => 1.0 + 0.5 * congestion,
+            FeePriority::Medium => 1.1 + 1.5 * congestion,
+            FeePriority::High => 1.25 + 3.0 * congestion,
+        }
+    }
+}
+
+/// Fee distribution for wallets: `p90` targets the next block, `p50` the
+/// next few blocks and `p10` eventual inclusion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeePercentiles {
+    pub p10: u64,
+    pub p50: u64,
+    pub p90: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct BlockMetrics {
+    pub height: u64,
+    pub timestamp: u64,
+    pub block_time: u64,
+    pub difficulty: u64,
+    pub vdf_iterations: u64,
+    pub transaction_count: usize,
+    pub total_fees: u64,
+    /// Network hashrate (H/s); producers fill it with
+    /// `canonical_hashrate_estimate`
+    pub hashrate_estimate: f64,
+}
+
+impl BlockMetrics {
+    /// Canonical hashrate estimate for this block; producers should use it to
+    /// fill `hashrate_estimate` so every node feeds the controller identically
+    pub fn canonical_hashrate_estimate(&self) -> f64 {
+        VDF::estimate_hashrate(self.difficulty, self.block_time)
+    }
+
+    /// Check that `next` can directly follow this block: its timestamp is
+    /// strictly later and its `block_time` matches the timestamp delta within
+    /// `BLOCK_TIMESTAMP_TOLERANCE_SECS`
+    pub fn validate_successor(&self, next: &BlockMetrics) -> Result<(), AxiomError> {
+        if next.timestamp <= self.timestamp {
+            return Err(AxiomError::InvalidBlock(format!(
+                "Block metrics timestamp not increasing: height {} at {} follows height {} at {}",
+                next.height, next.timestamp, self.height, self.timestamp
+            )));
+        }
+
+        let delta = next.timestamp - self.timestamp;
+        if delta.abs_diff(next.block_time) > BLOCK_TIMESTAMP_TOLERANCE_SECS {
+            return Err(AxiomError::InvalidBlock(format!(
+                "Block metrics at height {} report block_time {}s but follow the previous block by {}s",
+                next.height, next.block_time, delta
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validate every adjacent pair of `blocks` with `validate_successor`
+    pub fn validate_series(blocks: &[BlockMetrics]) -> Result<(), AxiomError> {
+        blocks
+            .windows(2)
+            .try_for_each(|pair| pair[0].validate_successor(&pair[1]))
+    }
+}
+
+/// Lock-free histogram of `validate_transaction_with_guardian` latencies.
+/// Percentiles resolve to a bucket's upper bound, i.e. within a factor of 2.
+#[derive(Debug, Default)]
+struct LatencyHistogram {
+    buckets: [AtomicU64; LATENCY_BUCKETS],
+}
+
+impl LatencyHistogram {
+    fn record(&self, elapsed: std::time::Duration) {
+        let micros = u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX);
+        let bucket = (u64::BITS - micros.saturating_sub(1).leading_zeros()) as usize;
+        self.buckets[bucket.min(LATENCY_BUCKETS - 1)].fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn summary(&self) -> LatencySummary {
+        let counts: Vec<u64> = self.buckets.iter().map(|bucket| bucket.load(Ordering::Relaxed)).collect();
+        let samples = counts.iter().sum::<u64>();
+        let percentile = |fraction: f64| -> u64 {
+            if samples == 0 {
+                return 0;
+            }
+            let target = ((samples as f64 * fraction).ceil() as u64).max(1);
+            let mut cumulative = 0;
+            for (bucket, count) in counts.iter().enumerate() {
+                cumulative += count;
+                if cumulative >= target {
+                    return 1 << bucket;
+                }
+            }
+            1 << (LATENCY_BUCKETS - 1)
+        };
+
+        LatencySummary {
+            samples,
+            p50_micros: percentile(0.50),
+            p99_micros: percentile(0.99),
+        }
+    }
+}
+
+impl AIGuardianBridge {
+    /// Bridge with the default configuration.
+    ///
+    /// # Panics
+    ///
+    /// If the engine's thresholds contradict the manifest
+    /// (`SecurityConfig::validate_against_manifest`); use `try_new` to
+    /// handle that as an error.
+    pub fn new(security_engine: Arc<MultiLayerSecurityEngine>) -> Self {
+        match Self::try_new(security_engine) {
+            Ok(bridge) => bridge,
+            Err(e) => panic!("Security engine configuration contradicts the manifest: {}", e),
+        }
+    }
+
+    /// Build a bridge after checking the engine's thresholds against the
+    /// manifest (`SecurityConfig::validate_against_manifest`)
+    pub fn try_new(security_engine: Arc<MultiLayerSecurityEngine>) -> Result<Self, AxiomError> {
+        Self::try_new_with_config(security_engine, BridgeConfig::default())
+    }
+
+    pub fn try_new_with_config(
+        security_engine: Arc<MultiLayerSecurityEngine>,
+        config: BridgeConfig,
+    ) -> Result<Self, AxiomError> {
+        Self::new_with_assessor(security_engine, config)
+    }
+
+    /// Build a bridge around any threat assessor (e.g. an alternative
+    /// engine), checked as by `AIGuardianBridgeBuilder::build`
+    pub fn new_with_assessor(
+        security_engine: Arc<dyn ThreatAssessor>,
+        config: BridgeConfig,
+    ) -> Result<Self, AxiomError> {
+        Self::builder(security_engine).config(config).build()
+    }
+
+    /// Start building a bridge around `security_engine`; every other
+    /// dependency defaults as in `new`
+    pub fn builder(security_engine: Arc<dyn ThreatAssessor>) -> AIGuardianBridgeBuilder {
+        AIGuardianBridgeBuilder::new(security_engine)
+    }
+
+    /// Route the audit trail to `sink` (e.g. a file or database) instead of
+    /// the default in-memory ring buffer
+    pub fn with_audit_sink(mut self, sink: Arc<dyn AuditSink>) -> Self {
+        self.audit_sink = sink;
+        self
+    }
+
+    /// Blend operator rules into every AI assessment before the guardian
+    /// maps it to an action
+    pub fn with_threat_adjuster(mut self, adjuster: Arc<dyn ThreatAdjuster>) -> Self {
+        self.threat_adjuster = Some(adjuster);
+        self
+    }
+
+    /// Precondition for `deactivate_circuit_breaker`, given the active
+    /// breaker; an error keeps it active. Runs under the breaker lock, so it
+    /// must not call back into the bridge's breaker methods.
+    pub fn with_breaker_deactivation_guard(
+        mut self,
+        guard: impl Fn(&CircuitBreaker) -> Result<(), AxiomError> + Send + Sync + 'static,
+    ) -> Self {
+        self.breaker_deactivation_guard = Some(Arc::new(guard));
+        self
+    }
+
+    /// Recovery logic run after the breaker is cleared, given its state just
+    /// before (e.g. to re-enable dependent subsystems). A failing hook is
+    /// retried with jittered backoff by `poll_breaker_deactivation_hook`.
+    pub fn with_breaker_deactivation_hook(
+        mut self,
+        hook: impl Fn(&CircuitBreaker) -> Result<(), AxiomError> + Send + Sync + 'static,
+    ) -> Self {
+        self.breaker_deactivation_hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Unique proposal identifier derived from the height, the parameter
+    /// epoch, the proposed values and the bridge's proposal sequence, so
+    /// replaying the same inputs reproduces the same identifiers while
+    /// proposals at the same height stay distinguishable in the tracker
+    fn next_proposal_id(
+        &self,
+        prefix: &str,
+        current_block: u64,
+        nonce: u64,
+        proposed: &ConsensusParameters,
+    ) -> String {
+        let sequence = self.proposal_sequence.fetch_add(1, Ordering::SeqCst);
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(prefix.as_bytes());
+        for value in [
+            current_block,
+            nonce,
+            proposed.difficulty,
+            proposed.vdf_iterations,
+            proposed.min_gas,
+            sequence,
+        ] {
+            hasher.update(&value.to_le_bytes());
+        }
+        let digest = hasher.finalize();
+        let suffix = u32::from_le_bytes(digest.as_bytes()[..4].try_into().expect("4-byte slice"));
+        format!("{}_{}_{:08x}", prefix, current_block, suffix)
+    }
+
+    /// Enable or disable AI participation (audited)
+    pub fn set_ai_enabled(&self, enabled: bool) {
+        self.set_guardian_flag(GuardianFlag::AiEnabled, enabled);
+    }
+
+    /// Enable or disable auto-pilot mode (audited)
+    ///
+    /// What auto-pilot acts on is set by `BridgeConfig::auto_pilot_scope`.
+    /// Auto-pilot cannot be enabled while the manual override is engaged;
+    /// the request is logged and ignored.
+    pub fn set_auto_pilot_mode(&self, enabled: bool) {
+        // Held across the write so a lockdown can't slip in between
+        let lockdown = self.lockdown.lock();
+        if enabled && lockdown.is_some() {
+            drop(lockdown);
+            log::warn!("🛡️  Auto-pilot not enabled: emergency lockdown is active");
+            return;
+        }
+        // Checked and set under one lock so the override can't slip in between
+        let old = {
+            let mut state = self.guardian_state.write();
+            if enabled && state.manual_override_active {
+                drop(state);
+                log::warn!("🛡️  Auto-pilot not enabled: manual override is active");
+                return;
+            }
+            std::mem::replace(&mut state.auto_pilot_mode, enabled)
+        };
+        drop(lockdown);
+        self.record_flag_change(GuardianFlag::AutoPilotMode, old, enabled);
+    }
+
+    /// Engage or release the manual override (audited)
+    ///
+    /// Engaging the override disables auto-pilot, since the two are mutually
+    /// exclusive. Releasing it does not re-enable auto-pilot.
+    pub fn set_manual_override(&self, active: bool) {
+        let (auto_pilot_disabled, old) = {
+            let mut state = self.guardian_state.write();
+            let auto_pilot_disabled = active && std::mem::replace(&mut state.auto_pilot_mode, false);
+            (auto_pilot_disabled, std::mem::replace(&mut state.manual_override_active, active))
+        };
+        if auto_pilot_disabled {
+            log::warn!("🛡️  Manual override engaged: disabling auto-pilot");
+            self.record_flag_change(GuardianFlag::AutoPilotMode, true, false);
+        }
+        self.record_flag_change(GuardianFlag::ManualOverride, old, active);
+    }
+
+    /// Enable or disable shadow mode (audited)
+    ///
+    /// In shadow mode the AI + Guardian decision is still computed, counted in
+    /// the stats and written to the audit trail, but every AI-derived veto
+    /// is returned as `AcceptMonitored`. Manifest vetoes (supply integrity,
+    /// minimum fee) and the circuit breaker are still enforced.
+    pub fn set_shadow_mode(&self, enabled: bool) {
+        self.set_guardian_flag(GuardianFlag::ShadowMode, enabled);
+    }
+
+    /// Stop generating and applying consensus optimizations, e.g. during
+    /// maintenance (audited). Transaction validation is unaffected and
+    /// blocks pushed meanwhile are still ingested.
+    pub fn pause_optimization(&self) {
+        self.set_guardian_flag(GuardianFlag::OptimizationPaused, true);
+    }
+
+    /// Undo `pause_optimization` (audited). Ignored during an emergency
+    /// lockdown.
+    pub fn resume_optimization(&self) {
+        let lockdown = self.lockdown.lock();
+        if lockdown.is_some() {
+            drop(lockdown);
+            log::warn!("🛡️  Optimization not resumed: emergency lockdown is active");
+            return;
+        }
+        let old = std::mem::replace(&mut self.guardian_state.write().optimization_paused, false);
+        drop(lockdown);
+        self.record_flag_change(GuardianFlag::OptimizationPaused, old, false);
+    }
+
+    fn ensure_optimization_running(&self) -> Result<(), AxiomError> {
+        if self.guardian_state.read().optimization_paused {
+            return Err(AxiomError::OptimizationPaused);
+        }
+        Ok(())
+    }
+
+    /// Flip a security-critical flag, recording the transition for forensics.
+    /// Setting a flag to its current value is a no-op and is not recorded.
+    fn set_guardian_flag(&self, field: GuardianFlag, new: bool) {
+        let old = {
+            let mut state = self.guardian_state.write();
+            let slot = match field {
+                GuardianFlag::AiEnabled => &mut state.ai_enabled,
+                GuardianFlag::AutoPilotMode => &mut state.auto_pilot_mode,
+                GuardianFlag::ManualOverride => &mut state.manual_override_active,
+                GuardianFlag::ShadowMode => &mut state.shadow_mode,
+                GuardianFlag::OptimizationPaused => &mut state.optimization_paused,
+            };
+            std::mem::replace(slot, new)
+        };
+        self.record_flag_change(field, old, new);
+    }
+
+    /// Audit and emit a flag transition made under the state lock. An
+    /// unchanged flag is not recorded.
+    fn record_flag_change(&self, field: GuardianFlag, old: bool, new: bool) {
+        if old == new {
+            return;
+        }
+
+        let ts = self.clock.now();
+        log::warn!("🛡️  Guardian flag {:?} changed: {} → {}", field, old, new);
+
+        self.record_audit(AuditEntry {
+            timestamp: ts,
+            record: AuditRecord::StateChanged { field, old, new },
+        });
+        self.emit_event(GuardianEvent::StateChanged { field, old, new, ts });
+    }
+
+    /// Append to the audit sink. A failing sink is logged rather than
+    /// failing the guardian operation being audited.
+    fn record_audit(&self, entry: AuditEntry) {
+        if let Err(e) = self.audit_sink.append(&entry) {
+            log::error!("📝 Audit sink rejected entry {:?}: {}", entry.record, e);
+        }
+    }
+
+    fn record_decision(&self, profile: &TransactionRiskProfile, current_block: u64, decision: &GuardianDecision) {
+        self.record_audit(AuditEntry {
+            timestamp: self.clock.now(),
+            record: AuditRecord::Decision {
+                tx_hash: profile.hash.clone(),
+                block_height: current_block,
+                approved: decision.approved,
+                action: decision.action.clone(),
+                veto_reason: decision.veto_reason.clone(),
+                degraded: decision.degraded,
+                veto_category: decision.veto_category,
+                threat_score: decision.threat_assessment.threat_score,
+                amount: profile.amount,
+            },
+        });
+    }
+
+    fn record_proposal_status(&self, proposal_id: &str, from: Option<ProposalStatus>, to: ProposalStatus) {
+        self.record_audit(AuditEntry {
+            timestamp: self.clock.now(),
+            record: AuditRecord::ProposalStatusChanged {
+                proposal_id: proposal_id.to_string(),
+                from,
+                to,
+            },
+        });
+    }
+
+    fn emit_event(&self, event: GuardianEvent) {
+        let mut events = self.events.write();
+        if events.len() >= EVENT_QUEUE_CAPACITY {
+            events.pop_front();
+        }
+        events.push_back(event);
+    }
+
+    /// Get a copy of the audit trail still readable from the sink (oldest first)
+    pub fn audit_log(&self) -> Vec<AuditEntry> {
+        self.audit_sink.recent()
+    }
+
+    /// Stream every readable `Decision` audit entry as CSV with columns
+    /// `timestamp,action,approved,veto_category,threat_score,amount`,
+    /// oldest first
+    pub fn export_decisions_csv(&self, mut writer: impl std::io::Write) -> Result<(), AxiomError> {
+        writeln!(writer, "timestamp,action,approved,veto_category,threat_score,amount")
+            .map_err(|e| AxiomError::IoError(e.to_string()))?;
+        self.for_each_decision(&mut |row| {
+            writeln!(
+                writer,
+                "{},{},{},{},{},{}",
+                row.timestamp,
+                row.action,
+                row.approved,
+                row.veto_category.map(|category| category.as_str()).unwrap_or(""),
+                row.threat_score,
+                row.amount
+            )
+            .map_err(|e| AxiomError::IoError(e.to_string()))
         })
     }
 
-    /// Generate consensus optimization proposal
-    pub fn generate_consensus_optimization(
+    /// Like `export_decisions_csv`, but one JSON object per line
+    pub fn export_decisions_jsonl(&self, mut writer: impl std::io::Write) -> Result<(), AxiomError> {
+        self.for_each_decision(&mut |row| {
+            serde_json::to_writer(&mut writer, &row).map_err(|e| AxiomError::SerializationError(e.to_string()))?;
+            writeln!(writer).map_err(|e| AxiomError::IoError(e.to_string()))
+        })
+    }
+
+    fn for_each_decision(
         &self,
+        visit: &mut dyn FnMut(DecisionRow) -> Result<(), AxiomError>,
+    ) -> Result<(), AxiomError> {
+        self.audit_sink.for_each_recent(&mut |entry| match &entry.record {
+            AuditRecord::Decision {
+                action,
+                approved,
+                veto_category,
+                threat_score,
+                amount,
+                ..
+            } => visit(DecisionRow {
+                timestamp: entry.timestamp,
+                action: action.name(),
+                approved: *approved,
+                veto_category: *veto_category,
+                threat_score: *threat_score,
+                amount: *amount,
+            }),
+            _ => Ok(()),
+        })
+    }
+
+    /// Take all pending events (oldest first), leaving the queue empty
+    pub fn drain_events(&self) -> Vec<GuardianEvent> {
+        self.events.write().drain(..).collect()
+    }
+
+    /// Validate transaction with AI + Guardian oversight
+    ///
+    /// The transaction is treated as a user transaction; use
+    /// `validate_block_transaction` for one at a known position in a block.
+    /// Every call's latency is recorded; see `GuardianStats::decision_latency`.
+    /// With the `tracing` feature each call runs in a span recording the
+    /// outcome.
+    pub fn validate_transaction_with_guardian(
+        &self,
+        profile: TransactionRiskProfile,
         current_block: u64,
-        recent_blocks: &[BlockMetrics],
-    ) -> Result<ConsensusOptimizationProposal, AxiomError> {
-        if recent_blocks.len() < 144 {
+    ) -> Result<GuardianDecision, AxiomError> {
+        self.validate_classified(profile, TransactionClass::User, current_block, 0)
+    }
+
+    /// Validate the transaction at `index` in the block at `current_block`.
+    /// The class comes from the position (see
+    /// `TransactionClass::at_block_position`): the coinbase skips the
+    /// security engine but must pay the block subsidy plus
+    /// `collected_fees`, the fees of the block's other transactions (within
+    /// `BridgeConfig::coinbase_reward_tolerance`).
+    pub fn validate_block_transaction(
+        &self,
+        profile: TransactionRiskProfile,
+        index: usize,
+        current_block: u64,
+        collected_fees: u64,
+    ) -> Result<GuardianDecision, AxiomError> {
+        self.validate_classified(profile, TransactionClass::at_block_position(index), current_block, collected_fees)
+    }
+
+    fn validate_classified(
+        &self,
+        profile: TransactionRiskProfile,
+        class: TransactionClass,
+        current_block: u64,
+        collected_fees: u64,
+    ) -> Result<GuardianDecision, AxiomError> {
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "validate_transaction_with_guardian",
+            tx_hash = %profile.hash,
+            block_height = current_block,
+            approved = tracing::field::Empty,
+            action = tracing::field::Empty,
+            threat_score = tracing::field::Empty,
+            error = tracing::field::Empty,
+        );
+        #[cfg(feature = "tracing")]
+        let _entered = span.enter();
+
+        let started = std::time::Instant::now();
+        let result = self.decide_transaction(profile, class, current_block, collected_fees);
+        self.decision_latency.record(started.elapsed());
+
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(decision) => {
+                span.record("approved", decision.approved);
+                span.record("action", decision.action.name());
+                span.record("threat_score", decision.threat_assessment.threat_score);
+            }
+            Err(e) => {
+                span.record("error", tracing::field::display(e));
+            }
+        }
+        result
+    }
+
+    /// The engine's assessment of `profile`, reused from the assessment
+    /// cache when identical contents were already assessed under the live
+    /// consensus parameters. Engine errors are never cached. The flag is
+    /// true for a cache hit.
+    fn assess_transaction(
+        &self,
+        profile: &TransactionRiskProfile,
+        current_block: u64,
+    ) -> Result<(ThreatAssessment, bool), AxiomError> {
+        let Some(cache) = &self.assessment_cache else {
+            let assessment = self.security_engine.assess_transaction_threat(profile, current_block)?;
+            return Ok((assessment, false));
+        };
+
+        let digest = AssessmentCache::key(profile);
+        let epoch = self.consensus_ai.read().application_nonce;
+        if let Some(assessment) = cache.lock().get(&digest, epoch) {
+            log::debug!("♻️ Reusing assessment of {} (parameter epoch {})", profile.hash, epoch);
+            return Ok((assessment, true));
+        }
+
+        let assessment = self.security_engine.assess_transaction_threat(profile, current_block)?;
+        cache.lock().insert(digest, epoch, assessment.clone());
+        Ok((assessment, false))
+    }
+
+    fn decide_transaction(
+        &self,
+        profile: TransactionRiskProfile,
+        class: TransactionClass,
+        current_block: u64,
+        collected_fees: u64,
+    ) -> Result<GuardianDecision, AxiomError> {
+        // Check circuit breaker (low severity only pauses optimization)
+        self.advance_breaker(current_block);
+        let breaker = self.emergency_circuit_breaker.read();
+        let state = breaker.state;
+        if breaker.severity == Some(BreakerSeverity::High) && state == BreakerState::Tripped {
             return Err(AxiomError::AIProposalRejected {
-                reason: "Insufficient block history for optimization".to_string(),
+                reason: format!(
+                    "Emergency circuit breaker active: {}",
+                    breaker.reason.as_ref().unwrap_or(&"Unknown".to_string())
+                ),
+            });
+        }
+        let probing = breaker.severity == Some(BreakerSeverity::High) && state == BreakerState::Recovering;
+        drop(breaker);
+        if probing && !self.admit_probe() {
+            return Err(AxiomError::AIProposalRejected {
+                reason: "Emergency circuit breaker recovering: transaction held back from the probe phase".to_string(),
+            });
+        }
+
+        // The coinbase only needs the structural checks
+        if class == TransactionClass::Coinbase {
+            let decision = self.validate_coinbase(&profile, current_block, collected_fees)?;
+            self.record_decision(&profile, current_block, &decision);
+            return Ok(decision);
+        }
+
+        // Get AI threat assessment, then Guardian verification of AI decision
+        let mut guardian_decision = match self.assess_transaction(&profile, current_block) {
+            Ok((threat_assessment, cached)) => {
+                // A re-entering transaction was already counted in the window
+                let trip = if cached {
+                    None
+                } else {
+                    self.threat_window.write().observe(&threat_assessment)
+                };
+                if let Some((category, reason)) = trip {
+                    // A shadow decision must not halt the chain for real
+                    if self.guardian_state.read().shadow_mode {
+                        log::warn!("👥 Shadow mode: circuit breaker would trip: {}", reason);
+                    } else {
+                        self.activate_breaker(current_block, category, reason, BreakerSeverity::High);
+                    }
+                }
+                self.guardian_verify_ai_decision(&threat_assessment, &profile, current_block)?
+            }
+            Err(e) => self.degraded_decision(&profile, e)?,
+        };
+
+        // Flag (never reject) fee/amount ratios outside the sanity band
+        guardian_decision.fee_anomaly = self
+            .fee_ratio_band
+            .and_then(|band| band.check(profile.amount, profile.gas_price));
+        if let Some(anomaly) = &guardian_decision.fee_anomaly {
+            log::warn!("💸 Fee anomaly on {}: {:?}", profile.hash, anomaly);
+        }
+
+        // Queue manual reviews, applying the overflow policy when full
+        let shadow_mode = self.guardian_state.read().shadow_mode;
+        if let GuardianAction::RequireManualReview { threat_level } = guardian_decision.action {
+            if !shadow_mode {
+                self.enqueue_review(&profile, threat_level, current_block, &mut guardian_decision);
+            }
+        }
+
+        // Update state. Counters are atomic; the state lock is only written
+        // when there is a veto reason to store.
+        let vetoed = !guardian_decision.approved;
+        // Manifest vetoes are never shadowed
+        let enforced = !shadow_mode || guardian_decision.veto_category.is_some_and(|category| category.is_manifest());
+
+        // Total before vetoes, so a concurrent reader never sees vetoes > total
+        self.decision_counters.total_ai_decisions.fetch_add(1, Ordering::SeqCst);
+        if vetoed {
+            self.decision_counters.guardian_vetoes.fetch_add(1, Ordering::SeqCst);
+            if let Some(category) = guardian_decision.veto_category {
+                self.decision_counters.veto_categories[category as usize].fetch_add(1, Ordering::SeqCst);
+            }
+            if !enforced {
+                self.decision_counters.shadow_vetoes.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+        if vetoed {
+            self.guardian_state.write().last_veto_reason = Some(guardian_decision.veto_reason.clone().unwrap_or_default());
+        }
+        let veto_alarm = self
+            .veto_monitor
+            .as_ref()
+            .and_then(|monitor| monitor.lock().observe(vetoed, current_block, self.clock.now()));
+        self.emit_veto_alarm(veto_alarm);
+
+        if enforced {
+            self.record_decision(&profile, current_block, &guardian_decision);
+            return Ok(guardian_decision);
+        }
+
+        // Shadow mode: record what would have happened, then let it through
+
+        log::info!(
+            "👥 Shadow decision for {}: {:?} (not enforced)",
+            profile.hash, guardian_decision.action
+        );
+        self.record_audit(AuditEntry {
+            timestamp: self.clock.now(),
+            record: AuditRecord::ShadowDecision {
+                tx_hash: profile.hash.clone(),
+                block_height: current_block,
+                would_approve: guardian_decision.approved,
+                would_action: guardian_decision.action.clone(),
+                veto_reason: guardian_decision.veto_reason.clone(),
+            },
+        });
+
+        Ok(GuardianDecision {
+            approved: true,
+            veto_reason: None,
+            action: GuardianAction::AcceptMonitored,
+            threat_assessment: guardian_decision.threat_assessment,
+            degraded: guardian_decision.degraded,
+            fee_anomaly: guardian_decision.fee_anomaly,
+            monitoring_rationale: Some(match (guardian_decision.veto_reason, guardian_decision.monitoring_rationale) {
+                (Some(reason), _) => format!("Shadow mode: would have been {:?} ({})", guardian_decision.action, reason),
+                (None, Some(rationale)) => rationale,
+                (None, None) => format!("Shadow mode: would have been {:?}", guardian_decision.action),
+            }),
+            veto_category: None,
+            decision_confidence: guardian_decision.decision_confidence,
+        })
+    }
+
+    /// Decision used when the security engine failed, per the configured
+    /// `EngineFailurePolicy`. Sovereign invariants are enforced either way.
+    fn degraded_decision(
+        &self,
+        profile: &TransactionRiskProfile,
+        engine_error: AxiomError,
+    ) -> Result<GuardianDecision, AxiomError> {
+        log::error!("⚠️  Security engine failed ({:?} policy): {}", self.engine_failure_policy, engine_error);
+        SovereignInvariants::verify_supply_integrity(profile.amount)?;
+
+        let threat_assessment = unassessed(format!("Security engine unavailable: {}", engine_error));
+        let (veto_reason, veto_category) = match self.engine_failure_policy {
+            EngineFailurePolicy::FailClosed => (
+                Some(format!("Security engine unavailable (fail-closed policy): {}", engine_error)),
+                Some(VetoCategory::EngineUnavailable),
+            ),
+            EngineFailurePolicy::FailOpen => match minimum_fee_violation(profile) {
+                Some(reason) => (Some(reason), Some(VetoCategory::MinimumFee)),
+                None => (None, None),
+            },
+        };
+
+        let monitoring_rationale = veto_reason
+            .is_none()
+            .then(|| format!("Security engine unavailable (fail-open policy): {}", engine_error));
+        Ok(GuardianDecision {
+            approved: veto_reason.is_none(),
+            action: if veto_reason.is_none() {
+                GuardianAction::AcceptMonitored
+            } else {
+                GuardianAction::Reject
+            },
+            veto_reason,
+            threat_assessment,
+            degraded: true,
+            fee_anomaly: None,
+            monitoring_rationale,
+            veto_category,
+            decision_confidence: 0.0,
+        })
+    }
+
+    /// Hold a transaction for manual review. With the queue full, the
+    /// `ReviewQueueOverflow` policy either vetoes `decision` or evicts the
+    /// oldest pending review to make room.
+    fn enqueue_review(
+        &self,
+        profile: &TransactionRiskProfile,
+        threat_level: RiskLevel,
+        current_block: u64,
+        decision: &mut GuardianDecision,
+    ) {
+        let evicted = {
+            let mut state = self.guardian_state.write();
+            let mut evicted = None;
+            if state.pending_reviews.len() >= self.review_queue_capacity {
+                state.review_queue_overflows += 1;
+                let action = match self.review_queue_overflow {
+                    ReviewQueueOverflow::RejectOldest => {
+                        evicted = state.pending_reviews.pop_front();
+                        None
+                    }
+                    ReviewQueueOverflow::RejectNewest => Some(GuardianAction::Reject),
+                    ReviewQueueOverflow::AutoReject => Some(GuardianAction::AutoReject),
+                };
+                if let Some(action) = action {
+                    drop(state);
+                    log::warn!(
+                        "🛡️  Manual review queue full ({}): {} → {:?}",
+                        self.review_queue_capacity, profile.hash, action
+                    );
+                    decision.approved = false;
+                    decision.veto_reason = Some(format!(
+                        "Manual review queue full ({} pending)",
+                        self.review_queue_capacity
+                    ));
+                    decision.action = action;
+                    decision.veto_category = Some(VetoCategory::ReviewQueueFull);
+                    return;
+                }
+            }
+            state.pending_reviews.push_back(PendingReview {
+                tx_hash: profile.hash.clone(),
+                threat_level,
+                enqueued_block: current_block,
+                enqueued_at: self.clock.now(),
+            });
+            evicted
+        };
+
+        if let Some(review) = evicted {
+            log::warn!(
+                "🛡️  Manual review queue full ({}): evicted {} as rejected",
+                self.review_queue_capacity, review.tx_hash
+            );
+            self.record_audit(AuditEntry {
+                timestamp: self.clock.now(),
+                record: AuditRecord::ReviewExpired {
+                    tx_hash: review.tx_hash,
+                    enqueued_block: review.enqueued_block,
+                    expired_block: current_block,
+                    resolution: GuardianAction::Reject,
+                },
+            });
+        }
+    }
+
+    /// Mark a pending manual review as handled by an operator, returning it
+    pub fn complete_review(&self, tx_hash: &str) -> Option<PendingReview> {
+        let mut state = self.guardian_state.write();
+        let index = state.pending_reviews.iter().position(|review| review.tx_hash == tx_hash)?;
+        // An operator is answering: the run of timeouts is over
+        state.review_timeout_run.clear();
+        state.pending_reviews.remove(index)
+    }
+
+    /// Auto-resolve manual reviews that have waited `max_review_blocks` or
+    /// more, using the configured `ReviewTimeoutResolution`. Each timeout is
+    /// audited; the expired items are returned oldest first.
+    pub fn expire_stale_reviews(&self, current_block: u64, max_review_blocks: u64) -> Vec<PendingReview> {
+        let (expired, timeout_trip) = {
+            let mut state = self.guardian_state.write();
+            let (stale, fresh): (Vec<_>, Vec<_>) = state
+                .pending_reviews
+                .drain(..)
+                .partition(|review| current_block.saturating_sub(review.enqueued_block) >= max_review_blocks);
+            state.pending_reviews = fresh.into();
+
+            let trip = self.review_timeout_breaker.and_then(|rule| {
+                let run = &mut state.review_timeout_run;
+                run.extend(std::iter::repeat_n(current_block, stale.len()));
+                while run.front().is_some_and(|&block| current_block.saturating_sub(block) > rule.window_blocks) {
+                    run.pop_front();
+                }
+                if run.len() < rule.max_timeouts.max(1) {
+                    return None;
+                }
+                let count = run.len();
+                run.clear();
+                Some(format!(
+                    "{} consecutive manual reviews timed out within {} blocks",
+                    count, rule.window_blocks
+                ))
+            });
+            (stale, trip.map(|reason| (reason, state.shadow_mode)))
+        };
+
+        let resolution = self.review_timeout_resolution.action();
+        for review in &expired {
+            log::warn!(
+                "⏰ Manual review for {} timed out after {} blocks → {:?}",
+                review.tx_hash,
+                current_block.saturating_sub(review.enqueued_block),
+                resolution
+            );
+            self.record_audit(AuditEntry {
+                timestamp: self.clock.now(),
+                record: AuditRecord::ReviewExpired {
+                    tx_hash: review.tx_hash.clone(),
+                    enqueued_block: review.enqueued_block,
+                    expired_block: current_block,
+                    resolution: resolution.clone(),
+                },
             });
         }
 
-        let mut consensus = self.consensus_ai.write();
-        consensus.update_metrics(recent_blocks)?;
+        match timeout_trip {
+            Some((reason, true)) => log::warn!("👥 Shadow mode: circuit breaker would trip: {}", reason),
+            Some((reason, false)) => {
+                self.activate_breaker(current_block, BreakerReason::ReviewTimeouts, reason, BreakerSeverity::Low)
+            }
+            None => {}
+        }
+
+        expired
+    }
+
+    fn emit_veto_alarm(&self, alarm: Option<GuardianEvent>) {
+        if let Some(event) = alarm {
+            match &event {
+                GuardianEvent::VetoRateHigh { rate, .. } => log::warn!("🚨 Veto rate high: {:.1}%", rate),
+                _ => log::info!("✅ Veto rate back to normal"),
+            }
+            self.emit_event(event);
+        }
+    }
+
+    /// Structural manifest checks for the coinbase, which skips the security
+    /// engine: it must pay the subsidy for `current_block` plus the fees
+    /// collected in the block.
+    fn validate_coinbase(
+        &self,
+        profile: &TransactionRiskProfile,
+        current_block: u64,
+        collected_fees: u64,
+    ) -> Result<GuardianDecision, AxiomError> {
+        SovereignInvariants::verify_supply_integrity(profile.amount)?;
+        SovereignInvariants::verify_coinbase_output_with_tolerance(
+            current_block,
+            profile.amount,
+            collected_fees,
+            self.coinbase_reward_tolerance,
+        )?;
+
+        Ok(GuardianDecision {
+            approved: true,
+            veto_reason: None,
+            action: GuardianAction::Accept,
+            threat_assessment: unassessed("Coinbase transaction: security engine skipped".to_string()),
+            degraded: false,
+            fee_anomaly: None,
+            monitoring_rationale: None,
+            veto_category: None,
+            decision_confidence: 1.0,
+        })
+    }
+
+    /// Guardian verification layer - CANNOT BE BYPASSED
+    ///
+    /// When the AI requests a guardian override, manual override always takes
+    /// precedence over auto-pilot: the transaction is held for manual review
+    /// instead of being auto-rejected, even if both flags are set.
+    fn guardian_verify_ai_decision(
+        &self,
+        ai_assessment: &ThreatAssessment,
+        profile: &TransactionRiskProfile,
+        _current_block: u64,
+    ) -> Result<GuardianDecision, AxiomError> {
+        // Operator rules refine the AI's view before any rule looks at it
+        let mut adjusted = ai_assessment.clone();
+        if let Some(adjuster) = &self.threat_adjuster {
+            adjuster.adjust(profile, &mut adjusted);
+        }
+        let ai_assessment = &adjusted;
+
+        // Rule 1: Verify transaction doesn't exceed supply
+        SovereignInvariants::verify_supply_integrity(profile.amount)?;
+
+        // Rule 2: Verify minimum fee
+        if let Some(reason) = minimum_fee_violation(profile) {
+            return Ok(GuardianDecision {
+                approved: false,
+                veto_reason: Some(reason),
+                action: GuardianAction::Reject,
+                threat_assessment: ai_assessment.clone(),
+                degraded: false,
+                fee_anomaly: None,
+                monitoring_rationale: None,
+                veto_category: Some(VetoCategory::MinimumFee),
+                decision_confidence: 1.0,
+            });
+        }
+
+        let (manual_override, auto_pilot) = {
+            let state = self.guardian_state.read();
+            (state.manual_override_active, state.auto_pilot_mode)
+        };
+        Ok(self.map_ai_action(ai_assessment, manual_override, auto_pilot))
+    }
+
+    /// Rules 3 and 4 of `guardian_verify_ai_decision`: turn the engine's
+    /// recommendation into a Guardian action under the given operator modes
+    fn map_ai_action(&self, ai_assessment: &ThreatAssessment, manual_override: bool, auto_pilot: bool) -> GuardianDecision {
+        // Rule 3: Check if AI wants to escalate to Guardian
+        if ai_assessment.guardian_override_required {
+            log::warn!("🛡️  Guardian override required - AI threat score: {:.2}", ai_assessment.threat_score);
+
+            if manual_override {
+                // The reviewer gets the case, but a hard reject stays
+                // unapproved until they clear it
+                let hold = matches!(ai_assessment.recommended_action, SecurityAction::Reject { .. })
+                    || matches!(ai_assessment.risk_level, RiskLevel::Catastrophic);
+                return GuardianDecision {
+                    approved: !hold,
+                    veto_reason: hold.then(|| {
+                        format!(
+                            "Held for manual review: {:?} threat (score: {:.2})",
+                            ai_assessment.risk_level, ai_assessment.threat_score
+                        )
+                    }),
+                    action: GuardianAction::RequireManualReview {
+                        threat_level: ai_assessment.risk_level,
+                    },
+                    threat_assessment: ai_assessment.clone(),
+                    degraded: false,
+                    fee_anomaly: None,
+                    monitoring_rationale: None,
+                    veto_category: hold.then_some(VetoCategory::AiRejected),
+                    decision_confidence: self.decision_confidence(ai_assessment),
+                };
+            }
+            if auto_pilot
+                && self.auto_pilot_scope.validation
+                && matches!(ai_assessment.risk_level, RiskLevel::Catastrophic)
+            {
+                return GuardianDecision {
+                    approved: false,
+                    veto_reason: Some(format!(
+                        "Auto-pilot rejection: Catastrophic threat (score: {:.2})",
+                        ai_assessment.threat_score
+                    )),
+                    action: GuardianAction::AutoReject,
+                    threat_assessment: ai_assessment.clone(),
+                    degraded: false,
+                    fee_anomaly: None,
+                    monitoring_rationale: None,
+                    veto_category: Some(VetoCategory::AutoPilot),
+                    decision_confidence: self.decision_confidence(ai_assessment),
+                };
+            }
+        }
+
+        // Rule 4: Apply AI's recommended action with Guardian bounds
+        let mut monitoring_rationale = None;
+        let action = match &ai_assessment.recommended_action {
+            SecurityAction::Accept => GuardianAction::Accept,
+            SecurityAction::AcceptWithMonitoring => {
+                monitoring_rationale = Some(self.monitoring_rationale(ai_assessment));
+                GuardianAction::AcceptMonitored
+            }
+            SecurityAction::Quarantine { duration_blocks } => {
+                let safe_duration = (*duration_blocks).min(SovereignInvariants::MAX_QUARANTINE_BLOCKS);
+                GuardianAction::Quarantine {
+                    duration_blocks: safe_duration,
+                }
+            }
+            SecurityAction::Reject { reason: _ } => GuardianAction::Reject,
+            SecurityAction::EscalateToGuardian { threat_level } => {
+                // An attached reviewer (manual override) always gets the case
+                let fallback = if manual_override {
+                    EscalationFallback::RequireManualReview
+                } else {
+                    self.escalation_fallbacks.get(threat_level).copied().unwrap_or_default()
+                };
+                if fallback != EscalationFallback::RequireManualReview {
+                    log::warn!("🛡️  No reviewer attached: {:?} escalation handled as {:?}", threat_level, fallback);
+                }
+                if fallback == EscalationFallback::AcceptMonitored {
+                    monitoring_rationale = Some(format!(
+                        "{:?} escalation accepted under monitoring: no reviewer attached",
+                        threat_level
+                    ));
+                }
+                fallback.action(*threat_level)
+            }
+            SecurityAction::HaltChain { emergency_level } => {
+                // The breaker itself is tripped by the threat window
+                if *emergency_level >= 9 {
+                    GuardianAction::ChainHalt
+                } else {
+                    GuardianAction::RequireManualReview {
+                        threat_level: RiskLevel::Critical,
+                    }
+                }
+            }
+        };
+
+        let veto_category = match action {
+            GuardianAction::Reject => Some(VetoCategory::AiRejected),
+            GuardianAction::AutoReject => Some(VetoCategory::EscalationFallback),
+            GuardianAction::ChainHalt => Some(VetoCategory::ChainHalt),
+            _ => None,
+        };
+        GuardianDecision {
+            approved: veto_category.is_none(),
+            veto_reason: None,
+            action,
+            threat_assessment: ai_assessment.clone(),
+            degraded: false,
+            fee_anomaly: None,
+            monitoring_rationale,
+            veto_category,
+            decision_confidence: self.decision_confidence(ai_assessment),
+        }
+    }
+
+    /// The action the Guardian would take on an engine escalation at
+    /// `risk_level`, for previewing the level→action policy (escalation
+    /// fallbacks, auto-pilot rejection) without a real transaction.
+    /// `auto_pilot` stands in for the live mode; the live manual override
+    /// still applies.
+    pub fn preview_action_for(&self, threat_score: f64, risk_level: RiskLevel, auto_pilot: bool) -> GuardianAction {
+        let assessment = ThreatAssessment {
+            threat_score,
+            confidence: 1.0,
+            risk_level,
+            recommended_action: SecurityAction::EscalateToGuardian { threat_level: risk_level },
+            guardian_override_required: true,
+            ..unassessed("Synthetic assessment for policy preview".to_string())
+        };
+        let manual_override = self.guardian_state.read().manual_override_active;
+        self.map_ai_action(&assessment, manual_override, auto_pilot).action
+    }
+
+    /// Margin of the threat score from the engine's nearest action
+    /// threshold, scaled so `FULL_CONFIDENCE_MARGIN` points is certain.
+    /// Engines that publish no thresholds fall back to their own confidence.
+    fn decision_confidence(&self, assessment: &ThreatAssessment) -> f64 {
+        self.security_engine
+            .action_thresholds()
+            .iter()
+            .map(|threshold| (assessment.threat_score - threshold).abs())
+            .reduce(f64::min)
+            .map_or(assessment.confidence, |margin| margin / FULL_CONFIDENCE_MARGIN)
+            .clamp(0.0, 1.0)
+    }
+
+    /// Explain an engine-recommended `AcceptWithMonitoring`, e.g.
+    /// "threat_score 55.00 above monitoring threshold 40.00"
+    fn monitoring_rationale(&self, assessment: &ThreatAssessment) -> String {
+        let mut rationale = match self.security_engine.monitoring_threshold() {
+            Some(threshold) => format!(
+                "threat_score {:.2} above monitoring threshold {:.2}",
+                assessment.threat_score, threshold
+            ),
+            None => format!(
+                "threat_score {:.2} ({:?} risk) flagged for monitoring",
+                assessment.threat_score, assessment.risk_level
+            ),
+        };
+        if !assessment.identified_threats.is_empty() {
+            rationale.push_str(&format!("; threats: {:?}", assessment.identified_threats));
+        }
+        rationale
+    }
+
+    /// Generate consensus optimization proposal
+    ///
+    /// Only one generation runs at a time; a concurrent call fails fast with
+    /// `ProposalInProgress` instead of interleaving PID updates. With the
+    /// `tracing` feature each call runs in a span recording the confidence
+    /// and block-time deviation.
+    pub fn generate_consensus_optimization(
+        &self,
+        current_block: u64,
+        recent_blocks: &[BlockMetrics],
+    ) -> Result<ConsensusOptimizationProposal, AxiomError> {
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "generate_consensus_optimization",
+            block_height = current_block,
+            blocks = recent_blocks.len(),
+            proposal_id = tracing::field::Empty,
+            confidence = tracing::field::Empty,
+            deviation_secs = tracing::field::Empty,
+            requires_voting = tracing::field::Empty,
+            error = tracing::field::Empty,
+        );
+        #[cfg(feature = "tracing")]
+        let _entered = span.enter();
+
+        let result = self.generate_proposal(current_block, recent_blocks);
+
+        #[cfg(feature = "tracing")]
+        match &result {
+            Ok(proposal) => {
+                let target = self.consensus_ai.read().profile.target_block_time() as f64;
+                span.record("proposal_id", proposal.proposal_id.as_str());
+                span.record("confidence", proposal.ai_confidence);
+                span.record("deviation_secs", proposal.avg_block_time_last_144 - target);
+                span.record("requires_voting", proposal.requires_voting);
+            }
+            Err(e) => {
+                span.record("error", tracing::field::display(e));
+            }
+        }
+        result
+    }
+
+    fn generate_proposal(
+        &self,
+        current_block: u64,
+        recent_blocks: &[BlockMetrics],
+    ) -> Result<ConsensusOptimizationProposal, AxiomError> {
+        let _generation = self.generation_guard.try_lock().ok_or(AxiomError::ProposalInProgress)?;
+
+        // A short slice is refused before any of it is ingested
+        if recent_blocks.len() < OPTIMIZATION_WINDOW {
+            if let Some(blocks_remaining) = self.consensus_ai.read().warm_up_remaining() {
+                return Err(AxiomError::WarmingUp { blocks_remaining });
+            }
+            return Err(AxiomError::AIProposalRejected {
+                reason: "Insufficient block history for optimization".to_string(),
+            });
+        }
+
+        // Then ingest (validating the series), so warm-up and hashrate
+        // monitoring progress even while the breaker is active
+        self.ingest_blocks(current_block, recent_blocks)?;
+        self.ensure_optimization_running()?;
+
+        // Don't retune consensus while the breaker is active at any severity
+        let breaker = self.emergency_circuit_breaker.read();
+        if breaker.is_active() {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!(
+                    "Emergency circuit breaker active: {}",
+                    breaker.reason.as_deref().unwrap_or("Unknown")
+                ),
+            });
+        }
+        drop(breaker);
+
+        let mut consensus = self.consensus_ai.write();
+        if let Some(blocks_remaining) = consensus.warm_up_remaining() {
+            return Err(AxiomError::WarmingUp { blocks_remaining });
+        }
+
+        let proposed = consensus.compute_adjustments(recent_blocks)?;
+
+        // Calculate metrics
+        let avg_block_time = recent_blocks.iter().map(|b| b.block_time).sum::<u64>() as f64
+            / recent_blocks.len() as f64;
+
+        let ai_confidence = consensus.calculate_confidence()?;
+        let requires_voting = self
+            .voting_policy
+            .requires_voting(ai_confidence, consensus.parameters(), proposed);
+
+        let mut proposal = Self::assemble_proposal(
+            &consensus,
+            self.next_proposal_id("ai_consensus", current_block, consensus.application_nonce, &proposed),
+            current_block,
+            proposed,
+            avg_block_time,
+            ai_confidence,
+            requires_voting,
+        )?;
+        proposal.timestamp = self.clock.now();
+        if let Some(decimals) = self.change_percent_precision {
+            proposal.round_change_percents(decimals);
+        }
+        drop(consensus);
+
+        self.block_feed.lock().blocks_since_optimization = 0;
+        self.track_proposal(&proposal);
+        Ok(proposal)
+    }
+
+    /// Feed the controller one block at a time, as a node receives them
+    ///
+    /// Replayed heights are ignored. With `auto_optimize_interval` set, a
+    /// proposal is generated over the last `OPTIMIZATION_WINDOW` pushed
+    /// blocks once that many blocks have arrived since the last
+    /// optimization; it is returned and emitted as
+    /// `GuardianEvent::ProposalGenerated`. A generation that can't run yet
+    /// (warm-up, active breaker, one already in progress) is retried on the
+    /// next block.
+    pub fn on_new_block(&self, metrics: BlockMetrics) -> Result<Option<ConsensusOptimizationProposal>, AxiomError> {
+        let height = metrics.height;
+        if self.block_feed.lock().recent.back().is_some_and(|last| height <= last.height) {
+            log::debug!("Ignoring already-fed block at height {}", height);
+            return Ok(None);
+        }
+        // A block that fails validation never enters the feed
+        self.ingest_blocks(height, std::slice::from_ref(&metrics))?;
+
+        let window = {
+            let mut feed = self.block_feed.lock();
+            if feed.recent.back().is_some_and(|last| height <= last.height) {
+                return Ok(None);
+            }
+            if feed.recent.len() >= OPTIMIZATION_WINDOW {
+                feed.recent.pop_front();
+            }
+            feed.recent.push_back(metrics.clone());
+            feed.blocks_since_optimization += 1;
+
+            let due = self
+                .auto_optimize_interval
+                .is_some_and(|interval| feed.blocks_since_optimization >= interval.max(1));
+            (due && feed.recent.len() >= OPTIMIZATION_WINDOW).then(|| Vec::from(feed.recent.clone()))
+        };
+        self.advance_breaker(height);
+        self.poll_breaker_deactivation_hook();
+
+        let window = match window {
+            Some(window) => window,
+            None => return Ok(None),
+        };
+        match self.generate_consensus_optimization(height, &window) {
+            Ok(proposal) => {
+                log::info!("🤖 Auto-generated consensus proposal {} at block {}", proposal.proposal_id, height);
+                self.emit_event(GuardianEvent::ProposalGenerated {
+                    proposal: proposal.clone(),
+                    ts: self.clock.now(),
+                });
+                self.auto_apply(&proposal);
+                Ok(Some(proposal))
+            }
+            Err(e) => {
+                log::debug!("Auto-optimization deferred at block {}: {}", height, e);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Apply an auto-generated proposal when auto-pilot covers optimization
+    /// and the proposal needs no vote and is confident enough
+    fn auto_apply(&self, proposal: &ConsensusOptimizationProposal) {
+        if !self.auto_pilot_scope.optimization || !self.guardian_state.read().auto_pilot_mode {
+            return;
+        }
+        if proposal.requires_voting || proposal.ai_confidence < self.voting_policy.min_apply_confidence {
+            log::debug!(
+                "Auto-pilot left proposal {} for review (confidence {:.2}, voting: {})",
+                proposal.proposal_id,
+                proposal.ai_confidence,
+                proposal.requires_voting
+            );
+            return;
+        }
+        match self.apply_consensus_optimization(proposal) {
+            Ok(_) => log::info!("🤖 Auto-pilot applied proposal {}", proposal.proposal_id),
+            Err(e) => log::warn!("⚠️  Auto-pilot could not apply proposal {}: {}", proposal.proposal_id, e),
+        }
+    }
+
+    /// Ingest block metrics into the controller and act on any hashrate
+    /// alerts they raise
+    fn ingest_blocks(&self, current_block: u64, blocks: &[BlockMetrics]) -> Result<(), AxiomError> {
+        let hashrate_alerts = {
+            let mut consensus = self.consensus_ai.write();
+            consensus.update_metrics(blocks)?;
+            consensus.take_hashrate_alerts()
+        };
+        self.handle_hashrate_alerts(current_block, hashrate_alerts);
+        Ok(())
+    }
+
+    /// Package an operator-specified parameter set as a proposal.
+    /// The values are checked against the manifest bounds relative to the
+    /// live parameters and always require a governance vote.
+    pub fn manual_proposal(
+        &self,
+        difficulty: u64,
+        vdf: u64,
+        gas: u64,
+        current_block: u64,
+    ) -> Result<ConsensusOptimizationProposal, AxiomError> {
+        let consensus = self.consensus_ai.read();
+
+        SovereignInvariants::verify_ai_difficulty_proposal(consensus.current_difficulty, difficulty)?;
+        SovereignInvariants::verify_ai_vdf_proposal(consensus.current_vdf_iterations, vdf)?;
+        SovereignInvariants::verify_ai_gas_proposal(consensus.current_min_gas, gas)?;
+
+        let avg_block_time = consensus.average_block_time();
+        let proposed = ConsensusParameters {
+            difficulty,
+            vdf_iterations: vdf,
+            min_gas: gas,
+        };
+
+        // Operator-chosen values carry no model uncertainty
+        let mut proposal = Self::assemble_proposal(
+            &consensus,
+            self.next_proposal_id("manual_consensus", current_block, consensus.application_nonce, &proposed),
+            current_block,
+            proposed,
+            avg_block_time,
+            1.0,
+            true,
+        )?;
+        proposal.timestamp = self.clock.now();
+        if let Some(decimals) = self.change_percent_precision {
+            proposal.round_change_percents(decimals);
+        }
+
+        self.track_proposal(&proposal);
+        Ok(proposal)
+    }
+
+    /// Start tracking a freshly generated proposal in the `Generated` state
+    fn track_proposal(&self, proposal: &ConsensusOptimizationProposal) {
+        let mut statuses = self.proposal_statuses.write();
+        if statuses.len() >= PROPOSAL_TRACKING_CAPACITY {
+            statuses.retain(|_, tracked| !tracked.status.is_terminal());
+        }
+        statuses.insert(
+            proposal.proposal_id.clone(),
+            TrackedProposal {
+                status: ProposalStatus::Generated,
+                requires_voting: proposal.requires_voting,
+                ai_confidence: proposal.ai_confidence,
+                expected_improvement: proposal.expected_improvement,
+            },
+        );
+        drop(statuses);
+
+        self.record_proposal_status(&proposal.proposal_id, None, ProposalStatus::Generated);
+    }
+
+    /// Lifecycle status of a proposal generated by this bridge
+    pub fn proposal_status(&self, proposal_id: &str) -> Option<ProposalStatus> {
+        self.proposal_statuses.read().get(proposal_id).map(|tracked| tracked.status)
+    }
+
+    /// Move a tracked proposal to `next`, enforcing the legal lifecycle
+    ///
+    /// A proposal pending a vote is only approved or rejected by
+    /// `finalize_vote`; it can still be marked `Stale`.
+    pub fn transition_proposal(&self, proposal_id: &str, next: ProposalStatus) -> Result<(), AxiomError> {
+        self.move_proposal(proposal_id, next, false)
+    }
+
+    /// `transition_proposal`, with `by_vote` set only by `finalize_vote`
+    fn move_proposal(&self, proposal_id: &str, next: ProposalStatus, by_vote: bool) -> Result<(), AxiomError> {
+        let mut statuses = self.proposal_statuses.write();
+        let tracked = statuses.get_mut(proposal_id).ok_or_else(|| AxiomError::AIProposalRejected {
+            reason: format!("Unknown proposal {}", proposal_id),
+        })?;
+        let requires_voting = tracked.requires_voting;
+        let current = &mut tracked.status;
+
+        if !by_vote
+            && *current == ProposalStatus::PendingVote
+            && matches!(next, ProposalStatus::Approved | ProposalStatus::Rejected)
+        {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!("Proposal {} is pending a vote; only finalize_vote can decide it", proposal_id),
+            });
+        }
+
+        if !current.can_transition_to(next, requires_voting) {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!(
+                    "Illegal proposal transition for {}: {:?} → {:?}",
+                    proposal_id, current, next
+                ),
+            });
+        }
+
+        log::info!("📋 Proposal {}: {:?} → {:?}", proposal_id, current, next);
+        let from = std::mem::replace(current, next);
+        drop(statuses);
+        if next.is_terminal() {
+            self.vote_tallies.lock().remove(proposal_id);
+        }
+
+        self.record_proposal_status(proposal_id, Some(from), next);
+        Ok(())
+    }
+
+    /// Record `validator`'s vote on a proposal in `PendingVote`; each
+    /// validator votes once. With a validator set registered in
+    /// `VotingPolicy::validators`, only its members vote, at their
+    /// registered weight (a `weight` given must match it). Without one,
+    /// the ballot counts with `weight`, or 1 when `None`.
+    pub fn cast_vote(
+        &self,
+        proposal_id: &str,
+        validator: &str,
+        approve: bool,
+        weight: Option<u64>,
+    ) -> Result<(), AxiomError> {
+        let weight = if self.voting_policy.validators.is_empty() {
+            weight.unwrap_or(1)
+        } else {
+            match self.voting_policy.validators.get(validator) {
+                Some(&registered) if weight.unwrap_or(registered) == registered => registered,
+                Some(&registered) => {
+                    return Err(AxiomError::AIProposalRejected {
+                        reason: format!(
+                            "{} voted with weight {:?} but is registered with {}",
+                            validator, weight, registered
+                        ),
+                    })
+                }
+                None => {
+                    return Err(AxiomError::AIProposalRejected {
+                        reason: format!("{} is not a registered validator", validator),
+                    })
+                }
+            }
+        };
+        if weight == 0 {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!("{} has no vote weight", validator),
+            });
+        }
+        if self.proposal_status(proposal_id) != Some(ProposalStatus::PendingVote) {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!("Proposal {} is not pending a vote", proposal_id),
+            });
+        }
+
+        let mut tallies = self.vote_tallies.lock();
+        let tally = tallies.entry(proposal_id.to_string()).or_default();
+        if tally.ballots.contains_key(validator) {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!("{} already voted on {}", validator, proposal_id),
+            });
+        }
+        tally.ballots.insert(validator.to_string(), (approve, weight));
+        log::info!(
+            "🗳️ {} voted {} on {} (weight {})",
+            validator,
+            if approve { "approve" } else { "reject" },
+            proposal_id,
+            weight
+        );
+        Ok(())
+    }
+
+    /// Close the vote on a proposal: `Approved` when the approve weight
+    /// reaches `VotingPolicy::vote_approval_weight`, `Rejected` otherwise
+    pub fn finalize_vote(&self, proposal_id: &str) -> Result<ProposalStatus, AxiomError> {
+        if self.proposal_status(proposal_id) != Some(ProposalStatus::PendingVote) {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!("Proposal {} is not pending a vote", proposal_id),
+            });
+        }
+
+        let tally = self.vote_tallies.lock().remove(proposal_id).unwrap_or_default();
+        let approve_weight = tally.weight(true);
+        let outcome = if approve_weight >= self.voting_policy.vote_approval_weight.max(1) {
+            ProposalStatus::Approved
+        } else {
+            ProposalStatus::Rejected
+        };
+        log::info!(
+            "🗳️ Vote on {} closed: {} approve / {} reject weight of {} (needs {})",
+            proposal_id,
+            approve_weight,
+            tally.weight(false),
+            self.voting_policy.vote_weight_total(),
+            self.voting_policy.vote_approval_weight
+        );
+        self.move_proposal(proposal_id, outcome, true)?;
+        Ok(outcome)
+    }
+
+    /// Build a Guardian pre-approved proposal from already-verified parameters,
+    /// filling metrics from the controller's current telemetry. Callers stamp
+    /// `timestamp` from their own time source.
+    fn assemble_proposal(
+        consensus: &ConsensusAIController,
+        proposal_id: String,
+        current_block: u64,
+        proposed: ConsensusParameters,
+        avg_block_time: f64,
+        ai_confidence: f64,
+        requires_voting: bool,
+    ) -> Result<ConsensusOptimizationProposal, AxiomError> {
+        let hashrate_trend = consensus.calculate_hashrate_trend()?;
+        let mempool_congestion = consensus.calculate_mempool_congestion()?;
+        let network_health = consensus.calculate_network_health_score()?;
+        let urgency = consensus.calculate_urgency(avg_block_time, mempool_congestion, network_health);
+
+        Ok(ConsensusOptimizationProposal {
+            schema_version: PROPOSAL_SCHEMA_VERSION,
+            proposal_id,
+            block_height: current_block,
+            timestamp: 0,
+
+            current_difficulty: consensus.current_difficulty,
+            proposed_difficulty: proposed.difficulty,
+            difficulty_change_percent: Self::calculate_change_percent(
+                consensus.current_difficulty,
+                proposed.difficulty,
+            ),
+
+            current_vdf: consensus.current_vdf_iterations,
+            proposed_vdf: proposed.vdf_iterations,
+            vdf_change_percent: Self::calculate_change_percent(
+                consensus.current_vdf_iterations,
+                proposed.vdf_iterations,
+            ),
+
+            current_min_gas: consensus.current_min_gas,
+            proposed_min_gas: proposed.min_gas,
+            gas_change_percent: Self::calculate_change_percent(consensus.current_min_gas, proposed.min_gas),
+
+            avg_block_time_last_144: avg_block_time,
+            hashrate_trend,
+            mempool_congestion,
+            network_health_score: network_health,
+
+            ai_confidence,
+            expected_improvement: consensus.calculate_expected_improvement(proposed, ai_confidence)?,
+
+            pre_approval: PreApproval::evaluate(consensus.parameters(), proposed, current_block),
+            requires_voting,
+            application_nonce: consensus.application_nonce,
+            urgency,
+        })
+    }
+
+    /// Apply consensus optimization (Guardian-verified)
+    ///
+    /// The first application on a fresh controller has no prior baseline:
+    /// it expects nonce 0 and is measured against the genesis parameters.
+    /// Once applied, its parameters, height and nonce + 1 are the baseline
+    /// every later proposal is checked against.
+    ///
+    /// The manifest checks are re-run against the live parameters whatever
+    /// the proposal's `pre_approval` says. A proposal this bridge didn't
+    /// generate gets its confidence and voting requirement from this
+    /// controller rather than from its own fields.
+    ///
+    /// Returns the resulting live parameters and application nonce, read
+    /// under the same lock as the update, so a block producer can use them
+    /// without a separate query.
+    pub fn apply_consensus_optimization(
+        &self,
+        proposal: &ConsensusOptimizationProposal,
+    ) -> Result<AppliedParameters, AxiomError> {
+        self.ensure_optimization_running()?;
+
+        if !proposal.pre_approval.bounds_ok {
+            return Err(AxiomError::AIProposalRejected {
+                reason: "Proposal not pre-approved by Guardian".to_string(),
+            });
+        }
+
+        // The nonce check and the parameter update happen under one lock so
+        // a stale or replayed proposal can never slip in between
+        let mut consensus = self.consensus_ai.write();
+        if proposal.application_nonce != consensus.application_nonce {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!(
+                    "Proposal {} has application nonce {}, expected {} (replayed or out of order)",
+                    proposal.proposal_id, proposal.application_nonce, consensus.application_nonce
+                ),
+            });
+        }
+
+        // The payload's pre-approval is only a claim: re-run the manifest
+        // checks against the live parameters the proposal must start from
+        let live = consensus.parameters();
+        let claimed = ConsensusParameters {
+            difficulty: proposal.current_difficulty,
+            vdf_iterations: proposal.current_vdf,
+            min_gas: proposal.current_min_gas,
+        };
+        if claimed != live {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!(
+                    "Proposal {} starts from {:?} but the live parameters are {:?}",
+                    proposal.proposal_id, claimed, live
+                ),
+            });
+        }
+        let proposed = ConsensusParameters {
+            difficulty: proposal.proposed_difficulty,
+            vdf_iterations: proposal.proposed_vdf,
+            min_gas: proposal.proposed_min_gas,
+        };
+        let pre_approval = PreApproval::evaluate(live, proposed, proposal.block_height);
+        if !pre_approval.bounds_ok {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!(
+                    "Proposal {} fails the manifest checks: {}",
+                    proposal.proposal_id,
+                    pre_approval.notes.join("; ")
+                ),
+            });
+        }
+
+        // Tracked proposals must be applicable in their current lifecycle state;
+        // proposals received from elsewhere are untracked and start here, with
+        // confidence and voting requirement decided by this controller
+        let mut statuses = self.proposal_statuses.write();
+        let tracked = statuses.get(&proposal.proposal_id).copied();
+        let status = tracked.map(|tracked| tracked.status);
+        let (ai_confidence, requires_voting) = match tracked {
+            Some(tracked) => (tracked.ai_confidence, tracked.requires_voting),
+            None => {
+                let confidence = consensus.calculate_confidence().unwrap_or(0.0);
+                (confidence, self.voting_policy.requires_voting(confidence, live, proposed))
+            }
+        };
+
+        // A passed vote settles a low-confidence proposal; without one the
+        // confidence must stand on its own
+        if requires_voting
+            && status != Some(ProposalStatus::Approved)
+            && ai_confidence < self.voting_policy.min_apply_confidence
+        {
+            return Err(AxiomError::AIProposalRejected {
+                reason: "Proposal requires voting but confidence too low".to_string(),
+            });
+        }
+
+        if let Some(status) = status {
+            if !status.can_transition_to(ProposalStatus::Applied, requires_voting) {
+                return Err(AxiomError::AIProposalRejected {
+                    reason: format!(
+                        "Proposal {} cannot be applied from status {:?}",
+                        proposal.proposal_id, status
+                    ),
+                });
+            }
+        }
+        // Calibration is scored against the prediction made at generation;
+        // a proposal from elsewhere is re-predicted by this controller
+        let predicted_improvement = match tracked {
+            Some(tracked) => tracked.expected_improvement,
+            None => consensus.calculate_expected_improvement(proposed, ai_confidence).unwrap_or(0.0),
+        };
+        let from = statuses
+            .insert(
+                proposal.proposal_id.clone(),
+                TrackedProposal {
+                    status: ProposalStatus::Applied,
+                    requires_voting,
+                    ai_confidence,
+                    expected_improvement: predicted_improvement,
+                },
+            )
+            .map(|tracked| tracked.status);
+        drop(statuses);
+
+        consensus.current_difficulty = proposal.proposed_difficulty;
+        consensus.current_vdf_iterations = proposal.proposed_vdf;
+        consensus.current_min_gas = proposal.proposed_min_gas;
+        consensus.application_nonce += 1;
+        consensus.last_applied_height = Some(proposal.block_height);
+        if consensus.optimization_history.len() >= PROPOSAL_TRACKING_CAPACITY {
+            consensus.optimization_history.remove(0);
+        }
+        consensus.optimization_history.push(OptimizationRecord {
+            proposal_id: proposal.proposal_id.clone(),
+            timestamp: self.clock.now(),
+            block_height: proposal.block_height,
+            parameter: "difficulty".to_string(),
+            old_value: proposal.current_difficulty,
+            new_value: proposal.proposed_difficulty,
+            predicted_improvement,
+            actual_improvement: None,
+            guardian_approved: proposal.pre_approval.bounds_ok,
+        });
+        let applied = AppliedParameters {
+            parameters: consensus.parameters(),
+            application_nonce: consensus.application_nonce,
+        };
+        drop(consensus);
+
+        self.record_proposal_status(&proposal.proposal_id, from, ProposalStatus::Applied);
+
+        log::info!("🤖 Applied AI consensus optimization:");
+        log::info!("   Difficulty: {} → {} ({:+.2}%)", proposal.current_difficulty, proposal.proposed_difficulty, proposal.difficulty_change_percent);
+        log::info!("   VDF: {} → {} ({:+.2}%)", proposal.current_vdf, proposal.proposed_vdf, proposal.vdf_change_percent);
+        log::info!("   Min Gas: {} → {} ({:+.2}%)", proposal.current_min_gas, proposal.proposed_min_gas, proposal.gas_change_percent);
+
+        Ok(applied)
+    }
+
+    /// Back-fill the improvement an applied proposal actually delivered,
+    /// in the same units as its `expected_improvement`
+    ///
+    /// Outcomes feed the confidence calibration: when recent proposals
+    /// promised more than they delivered, later proposals score lower
+    /// confidence (see `ControllerTuning::calibration_penalty`).
+    pub fn record_optimization_outcome(&self, proposal_id: &str, actual_improvement: f64) -> Result<(), AxiomError> {
+        if !actual_improvement.is_finite() {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!("Non-finite improvement {} for proposal {}", actual_improvement, proposal_id),
+            });
+        }
+
+        let mut consensus = self.consensus_ai.write();
+        let record = consensus
+            .optimization_history
+            .iter_mut()
+            .rev()
+            .find(|record| record.proposal_id == proposal_id)
+            .ok_or_else(|| AxiomError::AIProposalRejected {
+                reason: format!("No applied proposal {}", proposal_id),
+            })?;
+        if record.actual_improvement.is_some() {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!("Outcome for proposal {} already recorded", proposal_id),
+            });
+        }
+        record.actual_improvement = Some(actual_improvement);
+
+        log::info!(
+            "🤖 Proposal {} ({} {} → {} at block {}) outcome: {:.2} predicted, {:.2} actual",
+            proposal_id,
+            record.parameter,
+            record.old_value,
+            record.new_value,
+            record.block_height,
+            record.predicted_improvement,
+            actual_improvement
+        );
+        Ok(())
+    }
+
+    fn calculate_change_percent(old: u64, new: u64) -> f64 {
+        if old == 0 {
+            return 0.0;
+        }
+        ((new as f64 - old as f64) / old as f64) * 100.0
+    }
+
+    /// Dry-run the circuit-breaker conditions over historical profiles
+    ///
+    /// Each profile is assessed by the security engine and fed through a
+    /// fresh copy of the breaker rules. Returns the index of every profile
+    /// that would trip the breaker along with the reason; the simulated
+    /// window restarts after each trip, as if an operator had reset it.
+    /// No live bridge state (breaker, window, stats, audit) is touched.
+    /// Profiles the engine fails to assess are skipped.
+    pub fn would_trip_breaker(
+        &self,
+        profiles: &[TransactionRiskProfile],
+        current_block: u64,
+    ) -> Vec<(usize, String)> {
+        let mut window = ThreatWindow::new(self.threat_window.read().rule);
+
+        profiles
+            .iter()
+            .enumerate()
+            .filter_map(|(index, profile)| {
+                let assessment = self.security_engine.assess_transaction_threat(profile, current_block).ok()?;
+                window.observe(&assessment).map(|(_, reason)| (index, reason))
+            })
+            .collect()
+    }
+
+    /// Activate emergency circuit breaker (operator trip)
+    pub fn activate_circuit_breaker(&self, current_block: u64, reason: String) -> Result<(), AxiomError> {
+        self.activate_circuit_breaker_for(current_block, BreakerReason::Manual, reason)
+    }
+
+    /// Activate emergency circuit breaker with an explicit category, e.g.
+    /// `SupplyBreach` from chain-level supply checks
+    pub fn activate_circuit_breaker_for(
+        &self,
+        current_block: u64,
+        category: BreakerReason,
+        reason: String,
+    ) -> Result<(), AxiomError> {
+        self.activate_breaker(current_block, category, reason, BreakerSeverity::High);
+        Ok(())
+    }
+
+    /// Activate the breaker at `severity`, or raise the severity of a
+    /// tripped breaker. A tripped breaker is never downgraded; a recovering
+    /// one trips afresh.
+    ///
+    /// During an emergency lockdown the trip is applied to the breaker the
+    /// lockdown holds back, so `release_lockdown` restores it.
+    fn activate_breaker(&self, current_block: u64, category: BreakerReason, reason: String, severity: BreakerSeverity) {
+        let mut lockdown = self.lockdown.lock();
+        let tripped = match lockdown.as_mut() {
+            Some(held) => {
+                let tripped = Self::trip_breaker(&mut held.breaker, current_block, category, &reason, severity);
+                if tripped {
+                    log::error!("🚨 Breaker trip recorded during emergency lockdown; it stands on release");
+                }
+                tripped
+            }
+            None => {
+                let mut breaker = self.emergency_circuit_breaker.write();
+                Self::trip_breaker(&mut breaker, current_block, category, &reason, severity)
+            }
+        };
+        drop(lockdown);
+        if !tripped {
+            return;
+        }
+
+        self.emit_event(GuardianEvent::CircuitBreakerActivated {
+            category,
+            reason,
+            severity,
+            block_height: current_block,
+            ts: self.clock.now(),
+        });
+    }
+
+    /// Trip or escalate `breaker`; false if it was already tripped at
+    /// `severity` or above
+    fn trip_breaker(
+        breaker: &mut CircuitBreaker,
+        current_block: u64,
+        category: BreakerReason,
+        reason: &str,
+        severity: BreakerSeverity,
+    ) -> bool {
+        // A re-trip during recovery keeps at least the original severity
+        let severity = match breaker.state {
+            BreakerState::Recovering => breaker.severity.map_or(severity, |previous| previous.max(severity)),
+            _ => severity,
+        };
+        if breaker.state != BreakerState::Tripped {
+            if breaker.state == BreakerState::Recovering {
+                log::error!("🚨 Circuit breaker re-tripped during recovery at block {}", current_block);
+            }
+            *breaker = CircuitBreaker::inactive();
+            breaker.state = BreakerState::Tripped;
+            breaker.activation_block = Some(current_block);
+            breaker.reason = Some(reason.to_string());
+            breaker.category = Some(category);
+            breaker.severity = Some(severity);
+            breaker.auto_recovery_block = Some(current_block + 144);
+
+            log::error!("🚨 EMERGENCY CIRCUIT BREAKER ACTIVATED at block {} ({:?}, {:?})", current_block, category, severity);
+            log::error!("   Reason: {}", reason);
+            log::error!("   Auto-recovery: block {}", current_block + 144);
+        } else if breaker.severity < Some(severity) {
+            breaker.severity = Some(severity);
+            breaker.reason = Some(reason.to_string());
+            breaker.category = Some(category);
+
+            log::error!("🚨 Circuit breaker escalated to {:?} at block {}: {}", severity, current_block, reason);
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Move a tripped breaker into its probe phase once the auto-recovery
+    /// block is reached, and close it once the probe phase passes without
+    /// a renewed trip. Does nothing without a `BreakerRecovery` rule.
+    fn advance_breaker(&self, current_block: u64) {
+        let Some(recovery) = self.breaker_recovery else {
+            return;
+        };
+        if !self.emergency_circuit_breaker.read().is_active() {
+            return;
+        }
+
+        let mut breaker = self.emergency_circuit_breaker.write();
+        match breaker.state {
+            BreakerState::Tripped if breaker.auto_recovery_block.is_some_and(|block| current_block >= block) => {
+                let end = current_block + recovery.probe_blocks;
+                breaker.state = BreakerState::Recovering;
+                breaker.recovery_end_block = Some(end);
+                breaker.probes_seen = 0;
+                breaker.probes_admitted = 0;
+                log::warn!(
+                    "🔶 Circuit breaker recovering at block {}: admitting {:.0}% of transactions until block {}",
+                    current_block,
+                    recovery.admit_fraction * 100.0,
+                    end
+                );
+            }
+            BreakerState::Recovering if breaker.recovery_end_block.is_some_and(|block| current_block >= block) => {
+                log::info!("✅ Circuit breaker closed at block {} after recovery", current_block);
+                *breaker = CircuitBreaker::closed();
+            }
+            _ => {}
+        }
+    }
+
+    /// Count a transaction seen while the breaker is recovering and decide
+    /// whether it goes through as a probe; admissions track
+    /// `admit_fraction` of the transactions seen, starting with the first
+    fn admit_probe(&self) -> bool {
+        let fraction = self
+            .breaker_recovery
+            .map_or(0.0, |recovery| recovery.admit_fraction.clamp(0.0, 1.0));
+        let mut breaker = self.emergency_circuit_breaker.write();
+        match breaker.state {
+            BreakerState::Recovering => {}
+            BreakerState::Tripped => return false,
+            BreakerState::Armed | BreakerState::Closed => return true,
+        }
+
+        breaker.probes_seen += 1;
+        let admit = (breaker.probes_admitted as f64) < breaker.probes_seen as f64 * fraction;
+        if admit {
+            breaker.probes_admitted += 1;
+        }
+        admit
+    }
+
+    /// Publish hashrate-cliff alerts and, if the rule asks for it, trip the
+    /// low-severity breaker (only logged in shadow mode)
+    fn handle_hashrate_alerts(&self, current_block: u64, alerts: Vec<HashrateDrop>) {
+        let trip_breaker = self.hashrate_drop_rule.is_some_and(|rule| rule.trip_breaker);
+
+        for alert in alerts {
+            log::warn!(
+                "📉 Hashrate drop at block {}: short-term {:.3e} vs baseline {:.3e} ({:.0}% down)",
+                alert.block_height,
+                alert.short_term_hashrate,
+                alert.baseline_hashrate,
+                alert.drop_fraction * 100.0
+            );
+            self.emit_event(GuardianEvent::HashrateDropAlert {
+                block_height: alert.block_height,
+                short_term_hashrate: alert.short_term_hashrate,
+                baseline_hashrate: alert.baseline_hashrate,
+                drop_fraction: alert.drop_fraction,
+                ts: self.clock.now(),
+            });
+
+            if !trip_breaker {
+                continue;
+            }
+            let reason = format!(
+                "Hashrate dropped {:.0}% below baseline at block {}",
+                alert.drop_fraction * 100.0,
+                alert.block_height
+            );
+            if self.guardian_state.read().shadow_mode {
+                log::warn!("👥 Shadow mode: circuit breaker would trip: {}", reason);
+            } else {
+                self.activate_breaker(current_block, BreakerReason::HashrateDrop, reason, BreakerSeverity::Low);
+            }
+        }
+    }
+
+    /// Deactivate circuit breaker (manual only)
+    ///
+    /// The deactivation guard, if any, runs first; its error is returned
+    /// and leaves the breaker untouched. Once the breaker is cleared the
+    /// deactivation hook runs once; a failure is scheduled for retry rather
+    /// than waited out here. Deactivating an inactive breaker runs neither.
+    /// A lockdown breaker can only be cleared by `release_lockdown`.
+    pub fn deactivate_circuit_breaker(&self) -> Result<(), AxiomError> {
+        let cleared = {
+            let lockdown = self.lockdown.lock();
+            if lockdown.is_some() {
+                return Err(AxiomError::AIProposalRejected {
+                    reason: "Circuit breaker is held by an emergency lockdown; use release_lockdown".to_string(),
+                });
+            }
+            let mut breaker = self.emergency_circuit_breaker.write();
+            if !breaker.is_active() {
+                return Ok(());
+            }
+            if let Some(guard) = &self.breaker_deactivation_guard {
+                guard(&breaker)?;
+            }
+
+            log::info!("✅ Emergency circuit breaker deactivated");
+            std::mem::replace(&mut *breaker, CircuitBreaker::closed())
+        };
+
+        // A newer deactivation supersedes any retry still pending
+        *self.pending_hook_retry.lock() = None;
+        self.run_breaker_deactivation_hook(cleared, 0);
+        Ok(())
+    }
+
+    /// Retry a failed deactivation hook if its backoff has elapsed; called
+    /// from `on_new_block`, or directly by embedders that want retries
+    /// sooner. A retry is dropped if the breaker has tripped again since.
+    /// Returns whether a retry is still pending.
+    pub fn poll_breaker_deactivation_hook(&self) -> bool {
+        let retry = {
+            let mut pending = self.pending_hook_retry.lock();
+            if pending.as_ref().is_some_and(|retry| retry.retry_at > self.clock.now()) {
+                return true;
+            }
+            match pending.take() {
+                Some(retry) => retry,
+                None => return false,
+            }
+        };
+
+        if self.emergency_circuit_breaker.read().is_active() {
+            log::warn!("⚠️  Circuit breaker active again; dropping the deactivation hook retry");
+            return false;
+        }
+        self.run_breaker_deactivation_hook(retry.cleared, retry.attempts);
+        self.pending_hook_retry.lock().is_some()
+    }
+
+    /// Make one attempt at the recovery hook. The breaker is already
+    /// cleared, so a failure is logged and scheduled for retry with jittered
+    /// exponential backoff instead of being reported to the caller.
+    fn run_breaker_deactivation_hook(&self, cleared: CircuitBreaker, attempts: u32) {
+        let Some(hook) = &self.breaker_deactivation_hook else {
+            return;
+        };
+        let e = match hook(&cleared) {
+            Ok(()) => return,
+            Err(e) => e,
+        };
+        let attempts = attempts + 1;
+        if attempts >= BREAKER_HOOK_ATTEMPTS {
+            log::error!("🚨 Breaker deactivation hook failed after {} attempts: {}", attempts, e);
+            return;
+        }
+
+        let backoff = BREAKER_HOOK_BACKOFF_SECS << (attempts - 1);
+        let delay = backoff + self.retry_rng.lock().next_u64() % (backoff + 1);
+        log::warn!(
+            "⚠️  Breaker deactivation hook failed (attempt {}/{}), retrying in {} s: {}",
+            attempts, BREAKER_HOOK_ATTEMPTS, delay, e
+        );
+        *self.pending_hook_retry.lock() = Some(HookRetry {
+            cleared,
+            attempts,
+            retry_at: self.clock.now() + delay,
+        });
+    }
+
+    /// Escape hatch: restore the configured safe consensus parameters and
+    /// clear all PID state, e.g. after the AI has driven the parameters into
+    /// a bad region
+    ///
+    /// Only allowed while the manual override is engaged. The reset counts
+    /// as an application, so proposals generated before it can no longer be
+    /// applied. Audited and emitted as `GuardianEvent::ParametersReset`.
+    pub fn reset_to_safe_defaults(&self, authorization: ResetAuthorization) -> Result<(), AxiomError> {
+        if !self.guardian_state.read().manual_override_active {
+            return Err(AxiomError::AIProposalRejected {
+                reason: "Safe-default reset requires the manual override to be engaged".to_string(),
+            });
+        }
+        if authorization.operator.trim().is_empty() {
+            return Err(AxiomError::AIProposalRejected {
+                reason: "Safe-default reset requires an operator identity".to_string(),
+            });
+        }
+
+        let defaults = self.safe_defaults;
+        if defaults.vdf_iterations < SovereignInvariants::MINIMUM_VDF_ITERATIONS {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!(
+                    "Safe-default VDF iterations {} below minimum {}",
+                    defaults.vdf_iterations,
+                    SovereignInvariants::MINIMUM_VDF_ITERATIONS
+                ),
+            });
+        }
+
+        let previous = {
+            let mut consensus = self.consensus_ai.write();
+            let previous = consensus.parameters();
+            consensus.current_difficulty = defaults.difficulty;
+            consensus.current_vdf_iterations = defaults.vdf_iterations;
+            consensus.current_min_gas = defaults.min_gas;
+            consensus.reset_pids();
+            consensus.application_nonce += 1;
+            previous
+        };
+
+        log::warn!(
+            "🧯 Consensus parameters reset to safe defaults by {} ({}): {:?} → {:?}",
+            authorization.operator, authorization.reason, previous, defaults
+        );
+
+        let ts = self.clock.now();
+        self.record_audit(AuditEntry {
+            timestamp: ts,
+            record: AuditRecord::ParametersReset {
+                operator: authorization.operator.clone(),
+                reason: authorization.reason,
+                from: previous,
+                to: defaults,
+            },
+        });
+        self.emit_event(GuardianEvent::ParametersReset {
+            operator: authorization.operator,
+            parameters: defaults,
+            ts,
+        });
+        Ok(())
+    }
+
+    /// Panic button: trip every defense at once in a confirmed emergency
+    ///
+    /// Trips the circuit breaker at `High` severity with no auto-recovery
+    /// block, disables auto-pilot, pauses optimization and raises the
+    /// lockdown signal that puts a linked sentinel into Emergency mode (see
+    /// `lockdown_signal`). All of it happens under the lockdown lock, which
+    /// every operation that could undo part of it also takes, and is
+    /// audited and emitted as a single `GuardianEvent::EmergencyLockdown`.
+    ///
+    /// A lockdown is never released automatically: breaker recovery skips
+    /// it, `deactivate_circuit_breaker` refuses it, and auto-pilot and
+    /// optimization stay off until an operator calls `release_lockdown`.
+    /// Breaker trips raised meanwhile are still emitted and are applied to
+    /// the breaker the lockdown holds back.
+    pub fn emergency_lockdown(&self, reason: String, authorization: ResetAuthorization) -> Result<(), AxiomError> {
+        if authorization.operator.trim().is_empty() {
+            return Err(AxiomError::AIProposalRejected {
+                reason: "Emergency lockdown requires an operator identity".to_string(),
+            });
+        }
+
+        let mut lockdown = self.lockdown.lock();
+        if lockdown.is_some() {
+            return Err(AxiomError::AIProposalRejected {
+                reason: "Emergency lockdown already active".to_string(),
+            });
+        }
+
+        let (auto_pilot_mode, optimization_paused) = {
+            let mut state = self.guardian_state.write();
+            let previous = (state.auto_pilot_mode, state.optimization_paused);
+            state.auto_pilot_mode = false;
+            state.optimization_paused = true;
+            previous
+        };
+        let block_height = self.block_feed.lock().recent.back().map_or(0, |block| block.height);
+        let breaker = {
+            let mut breaker = self.emergency_circuit_breaker.write();
+            let mut tripped = CircuitBreaker::inactive();
+            tripped.state = BreakerState::Tripped;
+            tripped.activation_block = Some(block_height);
+            tripped.reason = Some(reason.clone());
+            tripped.category = Some(BreakerReason::EmergencyLockdown);
+            tripped.severity = Some(BreakerSeverity::High);
+            std::mem::replace(&mut *breaker, tripped)
+        };
+        self.lockdown_signal.store(true, Ordering::SeqCst);
+        *lockdown = Some(Lockdown { auto_pilot_mode, optimization_paused, breaker });
+        drop(lockdown);
+
+        log::error!("🚨 EMERGENCY LOCKDOWN by {} at block {}: {}", authorization.operator, block_height, reason);
+
+        let ts = self.clock.now();
+        self.record_audit(AuditEntry {
+            timestamp: ts,
+            record: AuditRecord::EmergencyLockdown {
+                operator: authorization.operator.clone(),
+                reason: reason.clone(),
+                block_height,
+            },
+        });
+        self.emit_event(GuardianEvent::EmergencyLockdown {
+            operator: authorization.operator,
+            reason,
+            block_height,
+            ts,
+        });
+        Ok(())
+    }
+
+    /// Lift an emergency lockdown, restoring the circuit breaker, auto-pilot
+    /// and optimization state it replaced and lowering the lockdown signal.
+    /// The restored breaker includes any trip recorded during the lockdown.
+    /// Auto-pilot stays off if the manual override was engaged meanwhile.
+    pub fn release_lockdown(&self, authorization: ResetAuthorization) -> Result<(), AxiomError> {
+        if authorization.operator.trim().is_empty() {
+            return Err(AxiomError::AIProposalRejected {
+                reason: "Lockdown release requires an operator identity".to_string(),
+            });
+        }
+
+        let mut lockdown = self.lockdown.lock();
+        let Some(previous) = lockdown.take() else {
+            return Err(AxiomError::AIProposalRejected {
+                reason: "No emergency lockdown is active".to_string(),
+            });
+        };
+
+        *self.emergency_circuit_breaker.write() = previous.breaker;
+        {
+            let mut state = self.guardian_state.write();
+            state.auto_pilot_mode = previous.auto_pilot_mode && !state.manual_override_active;
+            state.optimization_paused = previous.optimization_paused;
+        }
+        self.lockdown_signal.store(false, Ordering::SeqCst);
+        drop(lockdown);
+
+        log::warn!("✅ Emergency lockdown released by {} ({})", authorization.operator, authorization.reason);
+
+        let ts = self.clock.now();
+        self.record_audit(AuditEntry {
+            timestamp: ts,
+            record: AuditRecord::LockdownReleased {
+                operator: authorization.operator.clone(),
+                reason: authorization.reason,
+            },
+        });
+        self.emit_event(GuardianEvent::LockdownReleased {
+            operator: authorization.operator,
+            ts,
+        });
+        Ok(())
+    }
+
+    /// Whether an emergency lockdown is in force
+    pub fn is_locked_down(&self) -> bool {
+        self.lockdown.lock().is_some()
+    }
+
+    /// Flag raised for the duration of an emergency lockdown; hand it to
+    /// `SovereignGuardian::with_lockdown_signal` so the sentinel holds
+    /// Emergency mode while it is set
+    pub fn lockdown_signal(&self) -> Arc<AtomicBool> {
+        self.lockdown_signal.clone()
+    }
+
+    /// Snapshot of the consensus controller's live state for persistence
+    pub fn export_controller_state(&self) -> ControllerSnapshot {
+        self.consensus_ai.read().export_state()
+    }
+
+    /// Switch the consensus AI to a named PID gain preset; see
+    /// `ConsensusAIController::activate_preset`
+    pub fn activate_pid_preset(&self, name: &str) -> Result<(), AxiomError> {
+        self.consensus_ai.write().activate_preset(name)
+    }
+
+    /// Replace the controller's live state with a verified snapshot. Like a
+    /// safe-default reset, this requires the manual override.
+    pub fn import_controller_state(&self, snapshot: &ControllerSnapshot) -> Result<(), AxiomError> {
+        if !self.guardian_state.read().manual_override_active {
+            return Err(AxiomError::AIProposalRejected {
+                reason: "Controller state import requires the manual override to be engaged".to_string(),
+            });
+        }
+
+        self.consensus_ai.write().import_state(snapshot)?;
+        // The imported nonce may repeat an epoch seen under other parameters
+        if let Some(cache) = &self.assessment_cache {
+            cache.lock().clear();
+        }
+        log::warn!(
+            "📥 Controller state imported: {:?} (snapshot nonce {}, now {})",
+            snapshot.parameters,
+            snapshot.application_nonce,
+            self.consensus_ai.read().application_nonce
+        );
+        Ok(())
+    }
+
+    /// Record an observed mempool size (pending transaction count). Feeds
+    /// both the congestion estimate and the AI gas adjustment. Prefer
+    /// `record_mempool_usage` when the byte total is known.
+    pub fn record_mempool_size(&self, pending_transactions: usize) {
+        self.consensus_ai.write().record_mempool_sample(MempoolSample {
+            transactions: pending_transactions,
+            bytes: None,
+        });
+    }
+
+    /// Record an observed mempool with its total size in bytes, so
+    /// congestion and gas follow block-space pressure rather than the count
+    pub fn record_mempool_usage(&self, pending_transactions: usize, pending_bytes: usize) {
+        self.consensus_ai.write().record_mempool_sample(MempoolSample {
+            transactions: pending_transactions,
+            bytes: Some(pending_bytes),
+        });
+    }
+
+    /// Suggested fee for a transaction of the given priority, scaled up from
+    /// the live minimum gas price by current mempool congestion. Never below
+    /// the manifest's `MIN_TRANSACTION_FEE`.
+    pub fn recommend_fee(&self, priority: FeePriority) -> u64 {
+        let consensus = self.consensus_ai.read();
+        let congestion = consensus.calculate_mempool_congestion().unwrap_or(0.0);
+        let fee = (consensus.current_min_gas as f64 * priority.fee_multiplier(congestion)).ceil() as u64;
+        fee.max(SovereignInvariants::MIN_TRANSACTION_FEE)
+    }
+
+    /// Fee distribution from recent blocks' average fees, priced like
+    /// `recommend_fee` (Low/Medium/High congestion premiums for p10/p50/p90).
+    /// Deterministic for a given state; never below `MIN_TRANSACTION_FEE`.
+    pub fn fee_percentiles(&self) -> FeePercentiles {
+        self.consensus_ai.read().fee_percentiles()
+    }
+
+    /// Current live consensus parameters
+    pub fn consensus_parameters(&self) -> ConsensusParameters {
+        self.consensus_ai.read().parameters()
+    }
+
+    /// Block height of the last applied proposal; `None` before the first
+    pub fn last_applied_height(&self) -> Option<u64> {
+        self.consensus_ai.read().last_applied_height
+    }
+
+    /// Network health score after each ingested block, oldest first
+    pub fn network_health_history(&self) -> Vec<f64> {
+        self.consensus_ai.read().network_health_history().to_vec()
+    }
+
+    /// Recent slope of the network health score (negative = degrading)
+    pub fn network_health_trend(&self) -> f64 {
+        self.consensus_ai.read().network_health_trend()
+    }
+
+    /// Relative hashrate change across the ingested history
+    pub fn try_hashrate_trend(&self) -> Telemetry<f64> {
+        self.consensus_ai.read().try_hashrate_trend()
+    }
+
+    /// Average mempool size as a fraction of one block's capacity
+    pub fn try_mempool_congestion(&self) -> Telemetry<f64> {
+        self.consensus_ai.read().try_mempool_congestion()
+    }
+
+    /// Combined block-time and hashrate stability score
+    pub fn try_network_health_score(&self) -> Telemetry<f64> {
+        self.consensus_ai.read().try_network_health_score()
+    }
+
+    /// Get Guardian statistics
+    pub fn get_guardian_stats(&self) -> GuardianStats {
+        self.stats_from(&self.guardian_state.read())
+    }
+
+    /// Consistent point-in-time view of the bridge for dashboards
+    ///
+    /// Every lock is held at once so the fields can't interleave with a
+    /// concurrent update. Locks are always acquired in this order:
+    ///
+    /// 1. `guardian_state`
+    /// 2. `emergency_circuit_breaker`
+    /// 3. `consensus_ai`
+    /// 4. `proposal_statuses`
+    /// 5. the audit sink (through `AuditSink::len`)
+    ///
+    /// Any code path that needs more than one of these at the same time must
+    /// follow the same order to stay deadlock-free.
+    pub fn full_snapshot(&self) -> BridgeSnapshot {
+        let state = self.guardian_state.read();
+        let breaker = self.emergency_circuit_breaker.read();
+        let consensus = self.consensus_ai.read();
+        let proposals = self.proposal_statuses.read();
+        let audit_entries = self.audit_sink.len();
+
+        BridgeSnapshot {
+            timestamp: self.clock.now(),
+            stats: self.stats_from(&state),
+            manual_override_active: state.manual_override_active,
+            circuit_breaker_active: breaker.is_active(),
+            circuit_breaker_state: breaker.state,
+            circuit_breaker_reason: breaker.reason.clone(),
+            circuit_breaker_category: breaker.category,
+            circuit_breaker_severity: breaker.severity,
+            circuit_breaker_activation_block: breaker.activation_block,
+            circuit_breaker_auto_recovery_block: breaker.auto_recovery_block,
+            parameters: consensus.parameters(),
+            avg_block_time: consensus.average_block_time(),
+            mempool_congestion: consensus.calculate_mempool_congestion().unwrap_or(0.0),
+            tracked_proposals: proposals.len(),
+            open_proposals: proposals.values().filter(|tracked| !tracked.status.is_terminal()).count(),
+            audit_entries,
+        }
+    }
+
+    fn stats_from(&self, state: &GuardianState) -> GuardianStats {
+        // Vetoes before total, mirroring the increment order
+        let shadow_vetoes = self.decision_counters.shadow_vetoes.load(Ordering::SeqCst);
+        let guardian_vetoes = self.decision_counters.guardian_vetoes.load(Ordering::SeqCst);
+        let total_ai_decisions = self.decision_counters.total_ai_decisions.load(Ordering::SeqCst);
+        GuardianStats {
+            ai_enabled: state.ai_enabled,
+            auto_pilot_mode: state.auto_pilot_mode,
+            shadow_mode: state.shadow_mode,
+            optimization_paused: state.optimization_paused,
+            total_ai_decisions,
+            guardian_vetoes,
+            shadow_vetoes,
+            veto_rate: if total_ai_decisions > 0 {
+                (guardian_vetoes as f64 / total_ai_decisions as f64) * 100.0
+            } else {
+                0.0
+            },
+            last_veto_reason: state.last_veto_reason.clone(),
+            pending_reviews: state.pending_reviews.len(),
+            review_queue_overflows: state.review_queue_overflows,
+            veto_categories: VetoCategory::ALL
+                .iter()
+                .map(|category| {
+                    let count = self.decision_counters.veto_categories[*category as usize].load(Ordering::SeqCst);
+                    (category.as_str().to_string(), count)
+                })
+                .collect(),
+            decision_latency: self.decision_latency.summary(),
+        }
+    }
+}
+
+// ==================== BUILDER ====================
+
+/// Fluent construction of an `AIGuardianBridge`. Anything left unset takes
+/// the same default as `AIGuardianBridge::new`.
+pub struct AIGuardianBridgeBuilder {
+    security_engine: Arc<dyn ThreatAssessor>,
+    config: BridgeConfig,
+    clock: Option<Arc<dyn Clock>>,
+    audit_sink: Option<Arc<dyn AuditSink>>,
+    threat_adjuster: Option<Arc<dyn ThreatAdjuster>>,
+}
+
+impl AIGuardianBridgeBuilder {
+    pub fn new(security_engine: Arc<dyn ThreatAssessor>) -> Self {
+        Self {
+            security_engine,
+            config: BridgeConfig::default(),
+            clock: None,
+            audit_sink: None,
+            threat_adjuster: None,
+        }
+    }
+
+    /// Replace the whole configuration; later setters still apply on top
+    pub fn config(mut self, config: BridgeConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub fn network_profile(mut self, profile: NetworkProfile) -> Self {
+        self.config.network_profile = profile;
+        self
+    }
+
+    pub fn controller_tuning(mut self, tuning: ControllerTuning) -> Self {
+        self.config.controller_tuning = tuning;
+        self
+    }
+
+    pub fn engine_failure_policy(mut self, policy: EngineFailurePolicy) -> Self {
+        self.config.engine_failure_policy = policy;
+        self
+    }
+
+    pub fn rng_seed(mut self, seed: u64) -> Self {
+        self.config.rng_seed = Some(seed);
+        self
+    }
+
+    /// Time source for audit entries, events and proposals (default: the
+    /// system clock)
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Audit trail destination (default: in-memory ring buffer)
+    pub fn audit_sink(mut self, sink: Arc<dyn AuditSink>) -> Self {
+        self.audit_sink = Some(sink);
+        self
+    }
+
+    pub fn threat_adjuster(mut self, adjuster: Arc<dyn ThreatAdjuster>) -> Self {
+        self.threat_adjuster = Some(adjuster);
+        self
+    }
+
+    /// Build the bridge, refusing an assessor whose configuration
+    /// contradicts the manifest (`ThreatAssessor::validate_against_manifest`)
+    /// or an invalid controller tuning (`ControllerTuning::validate`)
+    pub fn build(self) -> Result<AIGuardianBridge, AxiomError> {
+        self.security_engine.validate_against_manifest()?;
+        self.config.controller_tuning.validate()?;
+        Ok(self.assemble())
+    }
+
+    fn assemble(self) -> AIGuardianBridge {
+        let config = self.config;
+        let rng = match config.rng_seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        let mut controller = ConsensusAIController::with_tuning(config.network_profile, config.controller_tuning);
+        if let Some(n) = config.pid_derivative_filter {
+            controller.set_derivative_filter(n);
+        }
+        controller.warm_up_blocks = config.warm_up_blocks;
+        controller.hashrate_drop = config.hashrate_drop_rule.map(HashrateDropTracker::new);
+        if let Some(capacity) = config.mempool_capacity {
+            controller.mempool_capacity = capacity.max(1);
+        }
+        let safe_defaults = config.safe_defaults.unwrap_or_else(|| controller.parameters());
+
+        AIGuardianBridge {
+            security_engine: self.security_engine,
+            engine_failure_policy: config.engine_failure_policy,
+            fee_ratio_band: config.fee_ratio_band,
+            review_timeout_resolution: config.review_timeout_resolution,
+            review_queue_capacity: config.review_queue_capacity.unwrap_or(DEFAULT_REVIEW_QUEUE_CAPACITY).max(1),
+            review_queue_overflow: config.review_queue_overflow,
+            review_timeout_breaker: config.review_timeout_breaker,
+            voting_policy: config.voting_policy,
+            auto_pilot_scope: config.auto_pilot_scope,
+            change_percent_precision: config.change_percent_precision,
+            coinbase_reward_tolerance: config.coinbase_reward_tolerance,
+            escalation_fallbacks: config.escalation_fallbacks,
+            hashrate_drop_rule: config.hashrate_drop_rule,
+            guardian_state: Arc::new(RwLock::new(GuardianState {
+                ai_enabled: true,
+                auto_pilot_mode: false,
+                manual_override_active: false,
+                shadow_mode: config.shadow_mode,
+                optimization_paused: false,
+                last_veto_reason: None,
+                pending_reviews: VecDeque::new(),
+                review_queue_overflows: 0,
+                review_timeout_run: VecDeque::new(),
+            })),
+            consensus_ai: Arc::new(RwLock::new(controller)),
+            emergency_circuit_breaker: Arc::new(RwLock::new(CircuitBreaker::inactive())),
+            threat_window: Arc::new(RwLock::new(ThreatWindow::new(config.rolling_threat_breaker))),
+            breaker_recovery: config.breaker_recovery,
+            audit_sink: self.audit_sink.unwrap_or_else(|| Arc::new(InMemoryAuditSink::default())),
+            threat_adjuster: self.threat_adjuster,
+            events: Arc::new(RwLock::new(VecDeque::with_capacity(EVENT_QUEUE_CAPACITY))),
+            proposal_statuses: Arc::new(RwLock::new(HashMap::new())),
+            vote_tallies: Arc::new(Mutex::new(HashMap::new())),
+            retry_rng: Arc::new(Mutex::new(rng)),
+            pending_hook_retry: Arc::new(Mutex::new(None)),
+            proposal_sequence: Arc::new(AtomicU64::new(0)),
+            generation_guard: Arc::new(Mutex::new(())),
+            auto_optimize_interval: config.auto_optimize_interval,
+            safe_defaults,
+            block_feed: Arc::new(Mutex::new(BlockFeed::default())),
+            clock: self.clock.unwrap_or_else(|| Arc::new(SystemClock)),
+            decision_latency: Arc::new(LatencyHistogram::default()),
+            decision_counters: Arc::new(DecisionCounters::default()),
+            veto_monitor: config.veto_rate_alarm.map(|rule| Arc::new(Mutex::new(VetoRateMonitor::new(rule)))),
+            assessment_cache: config
+                .assessment_cache_capacity
+                .map(|capacity| Arc::new(Mutex::new(AssessmentCache::new(capacity)))),
+            breaker_deactivation_guard: None,
+            breaker_deactivation_hook: None,
+            lockdown: Arc::new(Mutex::new(None)),
+            lockdown_signal: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+// ==================== GUARDIAN DECISION ====================
+
+#[derive(Debug, Clone)]
+pub struct GuardianDecision {
+    pub approved: bool,
+    pub veto_reason: Option<String>,
+    pub action: GuardianAction,
+    pub threat_assessment: ThreatAssessment,
+    /// The security engine was unavailable; only manifest checks were applied
+    pub degraded: bool,
+    /// Fee-to-amount ratio outside the configured band (informational)
+    pub fee_anomaly: Option<FeeAnomaly>,
+    /// Why an `AcceptMonitored` decision was not a plain accept
+    pub monitoring_rationale: Option<String>,
+    /// Which rule vetoed the transaction; `None` when approved
+    pub veto_category: Option<VetoCategory>,
+    /// How sure the guardian is of this decision, 0.0-1.0: the threat
+    /// score's distance from the nearest action threshold, so decisions near
+    /// a boundary score low. Manifest-only decisions are 1.0, degraded ones 0.0.
+    pub decision_confidence: f64,
+}
+
+/// Rule responsible for a vetoed transaction, for audit analysis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VetoCategory {
+    /// Fee below the manifest minimum
+    MinimumFee,
+    /// Security engine failed under the fail-closed policy
+    EngineUnavailable,
+    /// Auto-pilot rejected a catastrophic threat
+    AutoPilot,
+    /// The security engine recommended rejection
+    AiRejected,
+    /// An escalation with no reviewer attached was auto-rejected
+    EscalationFallback,
+    /// The security engine requested a chain halt
+    ChainHalt,
+    /// A manual review was refused because the review queue was full
+    ReviewQueueFull,
+}
+
+impl VetoCategory {
+    /// Every category, in declaration order
+    pub const ALL: [VetoCategory; 7] = [
+        VetoCategory::MinimumFee,
+        VetoCategory::EngineUnavailable,
+        VetoCategory::AutoPilot,
+        VetoCategory::AiRejected,
+        VetoCategory::EscalationFallback,
+        VetoCategory::ChainHalt,
+        VetoCategory::ReviewQueueFull,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VetoCategory::MinimumFee => "minimum_fee",
+            VetoCategory::EngineUnavailable => "engine_unavailable",
+            VetoCategory::AutoPilot => "auto_pilot",
+            VetoCategory::AiRejected => "ai_rejected",
+            VetoCategory::EscalationFallback => "escalation_fallback",
+            VetoCategory::ChainHalt => "chain_halt",
+            VetoCategory::ReviewQueueFull => "review_queue_full",
+        }
+    }
+
+    /// Vetoes required by the sovereign manifest rather than the AI's
+    /// verdict; shadow mode still enforces these
+    pub fn is_manifest(&self) -> bool {
+        matches!(self, VetoCategory::MinimumFee)
+    }
+}
+
+/// Serialized internally tagged (`{"type": "Quarantine", "duration_blocks": 6}`)
+/// so a node can read actions added by newer versions as `Unknown`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum GuardianAction {
+    Accept,
+    AcceptMonitored,
+    Quarantine { duration_blocks: u64 },
+    Reject,
+    AutoReject,
+    RequireManualReview { threat_level: RiskLevel },
+    ChainHalt,
+    /// An action this node doesn't recognise, received from a newer peer.
+    /// Never produced locally; it is handled conservatively as a manual
+    /// review (see `resolve_unknown`) rather than trusted or dropped.
+    #[serde(other)]
+    Unknown,
+}
+
+impl GuardianAction {
+    /// HTTP status code an RPC front-end should return for this action
+    ///
+    /// | Action                       | Code |
+    /// |------------------------------|------|
+    /// | Accept, AcceptMonitored      | 200  |
+    /// | Quarantine                   | 202  |
+    /// | Reject, AutoReject           | 403  |
+    /// | RequireManualReview, Unknown | 409  |
+    /// | ChainHalt                    | 503  |
+    pub fn to_response_code(&self) -> u16 {
+        match self {
+            GuardianAction::Accept | GuardianAction::AcceptMonitored => 200,
+            GuardianAction::Quarantine { .. } => 202,
+            GuardianAction::Reject | GuardianAction::AutoReject => 403,
+            GuardianAction::RequireManualReview { .. } | GuardianAction::Unknown => 409,
+            GuardianAction::ChainHalt => 503,
+        }
+    }
+
+    /// Whether the transaction's fate is final. Quarantined, manual-review
+    /// and unknown actions are still awaiting a further decision.
+    pub fn is_terminal(&self) -> bool {
+        !matches!(
+            self,
+            GuardianAction::Quarantine { .. } | GuardianAction::RequireManualReview { .. } | GuardianAction::Unknown
+        )
+    }
+
+    /// Variant name without payload, e.g. `"Quarantine"`
+    pub fn name(&self) -> &'static str {
+        match self {
+            GuardianAction::Accept => "Accept",
+            GuardianAction::AcceptMonitored => "AcceptMonitored",
+            GuardianAction::Quarantine { .. } => "Quarantine",
+            GuardianAction::Reject => "Reject",
+            GuardianAction::AutoReject => "AutoReject",
+            GuardianAction::RequireManualReview { .. } => "RequireManualReview",
+            GuardianAction::ChainHalt => "ChainHalt",
+            GuardianAction::Unknown => "Unknown",
+        }
+    }
+
+    /// Map an unrecognised action to a critical manual review; every known
+    /// action is returned unchanged
+    pub fn resolve_unknown(self) -> GuardianAction {
+        match self {
+            GuardianAction::Unknown => GuardianAction::RequireManualReview {
+                threat_level: RiskLevel::Critical,
+            },
+            known => known,
+        }
+    }
+}
+
+/// Consistent view of the bridge assembled by [`AIGuardianBridge::full_snapshot`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BridgeSnapshot {
+    pub timestamp: u64,
+    pub stats: GuardianStats,
+    pub manual_override_active: bool,
+    pub circuit_breaker_active: bool,
+    #[serde(default)]
+    pub circuit_breaker_state: BreakerState,
+    pub circuit_breaker_reason: Option<String>,
+    pub circuit_breaker_category: Option<BreakerReason>,
+    pub circuit_breaker_severity: Option<BreakerSeverity>,
+    pub circuit_breaker_activation_block: Option<u64>,
+    pub circuit_breaker_auto_recovery_block: Option<u64>,
+    pub parameters: ConsensusParameters,
+    pub avg_block_time: f64,
+    pub mempool_congestion: f64,
+    pub tracked_proposals: usize,
+    pub open_proposals: usize,
+    pub audit_entries: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuardianStats {
+    pub ai_enabled: bool,
+    pub auto_pilot_mode: bool,
+    pub shadow_mode: bool,
+    /// Optimization generation and application paused by an operator
+    #[serde(default)]
+    pub optimization_paused: bool,
+    pub total_ai_decisions: u64,
+    /// Vetoes the AI + Guardian issued, including ones not enforced in shadow mode
+    pub guardian_vetoes: u64,
+    /// Vetoes that shadow mode turned into `AcceptMonitored`
+    pub shadow_vetoes: u64,
+    pub veto_rate: f64,
+    pub last_veto_reason: Option<String>,
+    /// Transactions awaiting manual review
+    #[serde(default)]
+    pub pending_reviews: usize,
+    /// Manual-review items that arrived while the queue was full
+    #[serde(default)]
+    pub review_queue_overflows: u64,
+    /// Vetoes per `VetoCategory::as_str`, every category listed. A
+    /// `BTreeMap` so the serialized key order is stable.
+    #[serde(default)]
+    pub veto_categories: BTreeMap<String, u64>,
+    /// Latency of `validate_transaction_with_guardian` since startup
+    #[serde(default)]
+    pub decision_latency: LatencySummary,
+}
+
+/// Latency percentiles, rounded up to the next power of two microseconds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct LatencySummary {
+    pub samples: u64,
+    pub p50_micros: u64,
+    pub p99_micros: u64,
+}
+
+// ==================== EVENTS & AUDIT ====================
+
+/// Security-critical guardian flags
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GuardianFlag {
+    AiEnabled,
+    AutoPilotMode,
+    ManualOverride,
+    ShadowMode,
+    OptimizationPaused,
+}
+
+/// Events emitted by the bridge for external consumers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum GuardianEvent {
+    StateChanged {
+        field: GuardianFlag,
+        old: bool,
+        new: bool,
+        ts: u64,
+    },
+    /// Consensus parameters were reset to the safe defaults
+    ParametersReset {
+        operator: String,
+        parameters: ConsensusParameters,
+        ts: u64,
+    },
+    /// `on_new_block` generated a proposal automatically
+    ProposalGenerated {
+        proposal: ConsensusOptimizationProposal,
+        ts: u64,
+    },
+    /// The recent veto rate reached the configured alarm threshold
+    VetoRateHigh {
+        rate: f64,
+        block_height: u64,
+        ts: u64,
+    },
+    /// The recent veto rate fell back below the alarm threshold
+    VetoRateCleared {
+        rate: f64,
+        block_height: u64,
+        ts: u64,
+    },
+    /// The circuit breaker was activated or escalated
+    CircuitBreakerActivated {
+        category: BreakerReason,
+        reason: String,
+        severity: BreakerSeverity,
+        block_height: u64,
+        ts: u64,
+    },
+    /// Short-term hashrate fell sharply below its baseline
+    HashrateDropAlert {
+        block_height: u64,
+        short_term_hashrate: f64,
+        baseline_hashrate: f64,
+        drop_fraction: f64,
+        ts: u64,
+    },
+    /// An operator tripped every defense at once
+    EmergencyLockdown {
+        operator: String,
+        reason: String,
+        block_height: u64,
+        ts: u64,
+    },
+    /// An operator lifted the emergency lockdown
+    LockdownReleased {
+        operator: String,
+        ts: u64,
+    },
+}
+
+/// A single entry in the guardian audit trail
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub timestamp: u64,
+    pub record: AuditRecord,
+}
+
+/// One enforced decision as written by the decision exports
+#[derive(Debug, Clone, Serialize)]
+pub struct DecisionRow {
+    pub timestamp: u64,
+    pub action: &'static str,
+    pub approved: bool,
+    pub veto_category: Option<VetoCategory>,
+    pub threat_score: f64,
+    pub amount: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum AuditRecord {
+    StateChanged {
+        field: GuardianFlag,
+        old: bool,
+        new: bool,
+    },
+    /// An enforced transaction decision
+    Decision {
+        tx_hash: String,
+        block_height: u64,
+        approved: bool,
+        action: GuardianAction,
+        veto_reason: Option<String>,
+        degraded: bool,
+        #[serde(default)]
+        veto_category: Option<VetoCategory>,
+        #[serde(default)]
+        threat_score: f64,
+        #[serde(default)]
+        amount: u64,
+    },
+    /// A proposal entered a new lifecycle state (`from` is `None` when it
+    /// starts being tracked)
+    ProposalStatusChanged {
+        proposal_id: String,
+        from: Option<ProposalStatus>,
+        to: ProposalStatus,
+    },
+    /// An operator reset the consensus parameters to the safe defaults
+    ParametersReset {
+        operator: String,
+        reason: String,
+        from: ConsensusParameters,
+        to: ConsensusParameters,
+    },
+    /// A manual-review item was auto-resolved after its review window
+    ReviewExpired {
+        tx_hash: String,
+        enqueued_block: u64,
+        expired_block: u64,
+        resolution: GuardianAction,
+    },
+    /// A decision computed in shadow mode that was not enforced
+    ShadowDecision {
+        tx_hash: String,
+        block_height: u64,
+        would_approve: bool,
+        would_action: GuardianAction,
+        veto_reason: Option<String>,
+    },
+    /// An operator engaged the emergency lockdown
+    EmergencyLockdown {
+        operator: String,
+        reason: String,
+        block_height: u64,
+    },
+    /// An operator released the emergency lockdown
+    LockdownReleased {
+        operator: String,
+        reason: String,
+    },
+}
+
+/// Operator-supplied deterministic rules (blocklists, allowlists, ...)
+/// applied on top of the AI's assessment
+///
+/// An adjuster may change any part of the assessment, including the
+/// recommended action. The sovereign manifest checks still run afterwards.
+pub trait ThreatAdjuster: Send + Sync {
+    fn adjust(&self, profile: &TransactionRiskProfile, assessment: &mut ThreatAssessment);
+}
+
+/// Destination for the guardian audit trail
+pub trait AuditSink: Send + Sync {
+    fn append(&self, entry: &AuditEntry) -> Result<(), AxiomError>;
+
+    /// Entries that can still be read back, oldest first. Write-only sinks
+    /// (files, remote stores) may keep the default, which returns nothing.
+    fn recent(&self) -> Vec<AuditEntry> {
+        Vec::new()
+    }
+
+    /// Number of readable entries. The default counts `recent()`; sinks
+    /// that know their size should override it to avoid the copy.
+    fn len(&self) -> usize {
+        self.recent().len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Visit readable entries oldest first, stopping at the first error.
+    /// The default walks `recent()`; sinks that can iterate in place should
+    /// override it to avoid the copy.
+    fn for_each_recent(
+        &self,
+        visit: &mut dyn FnMut(&AuditEntry) -> Result<(), AxiomError>,
+    ) -> Result<(), AxiomError> {
+        self.recent().iter().try_for_each(visit)
+    }
+}
+
+/// Default sink: bounded ring buffer, oldest entries are dropped first
+pub struct InMemoryAuditSink {
+    entries: RwLock<VecDeque<AuditEntry>>,
+    /// Entries dropped from the front so far; only changed under the
+    /// `entries` write lock, so it is consistent with any read guard
+    evicted: AtomicU64,
+    capacity: usize,
+}
+
+impl InMemoryAuditSink {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: RwLock::new(VecDeque::with_capacity(capacity.min(AUDIT_LOG_CAPACITY))),
+            evicted: AtomicU64::new(0),
+            capacity: capacity.max(1),
+        }
+    }
+}
+
+impl Default for InMemoryAuditSink {
+    fn default() -> Self {
+        Self::new(AUDIT_LOG_CAPACITY)
+    }
+}
+
+impl AuditSink for InMemoryAuditSink {
+    fn append(&self, entry: &AuditEntry) -> Result<(), AxiomError> {
+        let mut entries = self.entries.write();
+        if entries.len() >= self.capacity {
+            entries.pop_front();
+            self.evicted.fetch_add(1, Ordering::Relaxed);
+        }
+        entries.push_back(entry.clone());
+        Ok(())
+    }
+
+    fn recent(&self) -> Vec<AuditEntry> {
+        self.entries.read().iter().cloned().collect()
+    }
+
+    fn len(&self) -> usize {
+        self.entries.read().len()
+    }
+
+    /// Walks the entries present when the call starts, copying
+    /// `AUDIT_EXPORT_CHUNK` at a time and visiting each chunk with the lock
+    /// released, so a slow visitor (e.g. a writer) never blocks appends.
+    /// Entries evicted before their chunk is copied are skipped.
+    fn for_each_recent(
+        &self,
+        visit: &mut dyn FnMut(&AuditEntry) -> Result<(), AxiomError>,
+    ) -> Result<(), AxiomError> {
+        // Positions are absolute (evicted + index) so they survive evictions
+        let (mut next, end) = {
+            let entries = self.entries.read();
+            let evicted = self.evicted.load(Ordering::Relaxed);
+            (evicted, evicted + entries.len() as u64)
+        };
+        while next < end {
+            let chunk: Vec<AuditEntry> = {
+                let entries = self.entries.read();
+                let evicted = self.evicted.load(Ordering::Relaxed);
+                next = next.max(evicted);
+                if next >= end {
+                    break;
+                }
+                let start = (next - evicted) as usize;
+                let stop = ((end - evicted) as usize).min(start + AUDIT_EXPORT_CHUNK);
+                entries.range(start..stop).cloned().collect()
+            };
+            next += chunk.len() as u64;
+            chunk.iter().try_for_each(&mut *visit)?;
+        }
+        Ok(())
+    }
+}
+
+/// Rule 2 of `guardian_verify_ai_decision`, also enforced by the fail-open
+/// degraded path: the veto reason if the fee is below the manifest minimum
+fn minimum_fee_violation(profile: &TransactionRiskProfile) -> Option<String> {
+    (profile.gas_price < SovereignInvariants::MIN_TRANSACTION_FEE).then(|| {
+        format!(
+            "Transaction fee {} below minimum {}",
+            profile.gas_price,
+            SovereignInvariants::MIN_TRANSACTION_FEE
+        )
+    })
+}
+
+/// Neutral assessment for decisions made without the security engine
+fn unassessed(detailed_analysis: String) -> ThreatAssessment {
+    ThreatAssessment {
+        threat_score: 0.0,
+        confidence: 0.0,
+        identified_threats: Vec::new(),
+        risk_level: RiskLevel::Minimal,
+        recommended_action: SecurityAction::Accept,
+        detailed_analysis,
+        guardian_override_required: false,
+    }
+}
+
+fn unix_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+// ==================== CONSENSUS AI CONTROLLER ====================
+
+impl ConsensusAIController {
+    fn with_profile(profile: NetworkProfile) -> Self {
+        Self::with_tuning(profile, ControllerTuning::default())
+    }
+
+    /// `tuning` must already be validated
+    fn with_tuning(profile: NetworkProfile, tuning: ControllerTuning) -> Self {
+        let capacity = tuning.history_capacity;
+        Self {
+            current_difficulty: 1000,
+            current_vdf_iterations: 1_000_000,
+            current_min_gas: 1000,
+            profile,
+            tuning,
+            difficulty_pid: PIDController::new(
+                PIDPreset::DEFAULT.difficulty,
+                1.0 - tuning.difficulty_down_step(),
+                1.0 + tuning.difficulty_max_change,
+            ),
+            gas_pid: PIDController::with_max_change(PIDPreset::DEFAULT.gas, tuning.gas_max_change),
+            vdf_pid: PIDController::with_max_change(PIDPreset::DEFAULT.vdf, tuning.vdf_max_change),
+            pid_presets: BTreeMap::from([
+                ("aggressive".to_string(), PIDPreset::AGGRESSIVE),
+                ("conservative".to_string(), PIDPreset::CONSERVATIVE),
+                ("default".to_string(), PIDPreset::DEFAULT),
+            ]),
+            active_preset: "default".to_string(),
+            block_time_history: Vec::with_capacity(capacity),
+            hashrate_history: Vec::with_capacity(capacity),
+            block_time_moments: RunningMoments::default(),
+            hashrate_moments: RunningMoments::default(),
+            mempool_history: Vec::with_capacity(capacity),
+            fee_history: Vec::with_capacity(capacity),
+            health_history: Vec::with_capacity(capacity),
+            mempool_capacity: SovereignInvariants::MAX_BLOCK_SIZE_BYTES / AVERAGE_TRANSACTION_SIZE_BYTES,
+            last_ingested_height: None,
+            last_ingested_block: None,
+            warm_up_blocks: 0,
+            blocks_ingested: 0,
+            application_nonce: 0,
+            last_applied_height: None,
+            hashrate_drop: None,
+            hashrate_alerts: Vec::new(),
+            optimization_history: Vec::new(),
+        }
+    }
+
+    /// Preview what the AI would propose from a hypothetical baseline and
+    /// history, without touching any live bridge state
+    ///
+    /// A fresh mainnet controller is seeded with `baseline`, fed `history`
+    /// and discarded. The proposal is stamped with the last block's height
+    /// and timestamp, so identical inputs always yield identical output.
+    pub fn compute_proposal_from(
+        baseline: ConsensusParameters,
+        history: &[BlockMetrics],
+    ) -> Result<ConsensusOptimizationProposal, AxiomError> {
+        let last = history.last().ok_or_else(|| AxiomError::AIProposalRejected {
+            reason: "Insufficient block history for optimization".to_string(),
+        })?;
+
+        let mut controller = Self::with_profile(NetworkProfile::Mainnet);
+        controller.current_difficulty = baseline.difficulty;
+        controller.current_vdf_iterations = baseline.vdf_iterations;
+        controller.current_min_gas = baseline.min_gas;
+
+        let proposed = controller.compute_adjustments(history)?;
+        let avg_block_time = controller.average_block_time();
+        let ai_confidence = controller.calculate_confidence()?;
+        let requires_voting =
+            VotingPolicy::default().requires_voting(ai_confidence, controller.parameters(), proposed);
+
+        let mut proposal = AIGuardianBridge::assemble_proposal(
+            &controller,
+            format!("preview_{}", last.height),
+            last.height,
+            proposed,
+            avg_block_time,
+            ai_confidence,
+            requires_voting,
+        )?;
+        proposal.timestamp = last.timestamp;
+        Ok(proposal)
+    }
+
+    /// Blocks still needed before the startup warm-up ends, if any
+    fn warm_up_remaining(&self) -> Option<u64> {
+        let remaining = self.warm_up_blocks.saturating_sub(self.blocks_ingested);
+        (remaining > 0).then_some(remaining)
+    }
+
+    /// Ingest `blocks`, run the three PID adjustments and pre-validate them
+    /// against the manifest bounds
+    fn compute_adjustments(&mut self, blocks: &[BlockMetrics]) -> Result<ConsensusParameters, AxiomError> {
+        self.update_metrics(blocks)?;
+
+        // Calculate optimal parameters
+        let difficulty_proposal = self.calculate_difficulty_adjustment()?;
+        let vdf_proposal = self.calculate_vdf_adjustment()?;
+        let gas_proposal = self.calculate_gas_adjustment()?;
+
+        // Guardian pre-validation. The PID outputs are clamped to the
+        // manifest swing, so a failure here means the tuning (e.g. a
+        // `min_difficulty` floor far above the live difficulty) is out of
+        // line with the manifest; say which parameter and by how much.
+        SovereignInvariants::verify_ai_difficulty_proposal(self.current_difficulty, difficulty_proposal).map_err(
+            |e| {
+                Self::bound_violation(
+                    "difficulty",
+                    self.current_difficulty,
+                    difficulty_proposal,
+                    SovereignInvariants::MAX_AI_DIFFICULTY_SWING_PERCENT,
+                    e,
+                )
+            },
+        )?;
+        if vdf_proposal < SovereignInvariants::MINIMUM_VDF_ITERATIONS {
+            return Err(AxiomError::AIProposalRejected {
+                reason: format!(
+                    "Generated vdf_iterations proposal {} -> {} is {} iterations below the manifest minimum {}; check the controller tuning",
+                    self.current_vdf_iterations,
+                    vdf_proposal,
+                    SovereignInvariants::MINIMUM_VDF_ITERATIONS - vdf_proposal,
+                    SovereignInvariants::MINIMUM_VDF_ITERATIONS
+                ),
+            });
+        }
+        SovereignInvariants::verify_ai_vdf_proposal(self.current_vdf_iterations, vdf_proposal).map_err(|e| {
+            Self::bound_violation(
+                "vdf_iterations",
+                self.current_vdf_iterations,
+                vdf_proposal,
+                SovereignInvariants::MAX_AI_VDF_SWING_PERCENT,
+                e,
+            )
+        })?;
+        SovereignInvariants::verify_ai_gas_proposal(self.current_min_gas, gas_proposal).map_err(|e| {
+            Self::bound_violation(
+                "min_gas",
+                self.current_min_gas,
+                gas_proposal,
+                SovereignInvariants::MAX_AI_GAS_SWING_PERCENT,
+                e,
+            )
+        })?;
+
+        Ok(ConsensusParameters {
+            difficulty: difficulty_proposal,
+            vdf_iterations: vdf_proposal,
+            min_gas: gas_proposal,
+        })
+    }
+
+    /// Rejection for a generated parameter whose swing (measured like the
+    /// manifest, as the ratio of the larger to the smaller value) is past
+    /// `limit_percent`
+    fn bound_violation(parameter: &str, current: u64, proposed: u64, limit_percent: f32, cause: AxiomError) -> AxiomError {
+        let (low, high) = (current.min(proposed).max(1), current.max(proposed));
+        let swing = (high as f64 / low as f64 - 1.0) * 100.0;
+        AxiomError::AIProposalRejected {
+            reason: format!(
+                "Generated {} proposal {} -> {} swings {:.2}%, {:.2} points over the {:.1}% manifest bound; check the controller tuning ({})",
+                parameter,
+                current,
+                proposed,
+                swing,
+                swing - limit_percent as f64,
+                limit_percent,
+                cause
+            ),
+        }
+    }
+
+    /// Restore a controller from a snapshot taken by `export_state`
+    pub fn from_snapshot(profile: NetworkProfile, snapshot: &ControllerSnapshot) -> Result<Self, AxiomError> {
+        let mut controller = Self::with_profile(profile);
+        controller.import_state(snapshot)?;
+        Ok(controller)
+    }
+
+    /// Sealed copy of the live parameters, PID memory and ingestion cursor
+    pub fn export_state(&self) -> ControllerSnapshot {
+        let mut snapshot = ControllerSnapshot {
+            parameters: self.parameters(),
+            difficulty_pid: self.difficulty_pid.state(),
+            gas_pid: self.gas_pid.state(),
+            vdf_pid: self.vdf_pid.state(),
+            application_nonce: self.application_nonce,
+            last_ingested_height: self.last_ingested_height,
+            warm_up_blocks: self.warm_up_blocks,
+            blocks_ingested: self.blocks_ingested,
+            checksum: String::new(),
+        };
+        snapshot.checksum = snapshot.compute_checksum();
+        snapshot
+    }
+
+    /// Load `snapshot` after verifying its checksum and bounds. On error the
+    /// controller is left untouched. The checksum is not a MAC: only load
+    /// snapshots from a trusted source.
+    ///
+    /// The application nonce moves past both the live and the snapshot
+    /// value, as in `reset_to_safe_defaults`, so proposals generated before
+    /// the import can't be applied after it.
+    pub fn import_state(&mut self, snapshot: &ControllerSnapshot) -> Result<(), AxiomError> {
+        snapshot.verify(self.tuning.min_difficulty)?;
+
+        self.current_difficulty = snapshot.parameters.difficulty;
+        self.current_vdf_iterations = snapshot.parameters.vdf_iterations;
+        self.current_min_gas = snapshot.parameters.min_gas;
+        self.difficulty_pid.restore(&snapshot.difficulty_pid);
+        self.gas_pid.restore(&snapshot.gas_pid);
+        self.vdf_pid.restore(&snapshot.vdf_pid);
+        self.application_nonce = self.application_nonce.max(snapshot.application_nonce) + 1;
+        self.last_ingested_height = snapshot.last_ingested_height;
+        self.last_ingested_block = None;
+        self.warm_up_blocks = snapshot.warm_up_blocks;
+        self.blocks_ingested = snapshot.blocks_ingested;
+        Ok(())
+    }
+
+    /// Clear the integrator and derivative memory of every PID loop
+    fn reset_pids(&mut self) {
+        self.difficulty_pid.reset();
+        self.gas_pid.reset();
+        self.vdf_pid.reset();
+    }
+
+    fn set_derivative_filter(&mut self, n: f64) {
+        self.difficulty_pid.set_derivative_filter(Some(n));
+        self.gas_pid.set_derivative_filter(Some(n));
+        self.vdf_pid.set_derivative_filter(Some(n));
+    }
+
+    /// Switch all three PID loops to the named preset ("default",
+    /// "conservative" or "aggressive")
+    ///
+    /// The swap is bumpless: each integrator is rescaled so its
+    /// contribution to the output is unchanged, and the output limits stay
+    /// as tuned. Re-activating the active preset is a no-op.
+    pub fn activate_preset(&mut self, name: &str) -> Result<(), AxiomError> {
+        let preset = match self.pid_presets.get(name) {
+            Some(preset) => *preset,
+            None => {
+                return Err(AxiomError::AIProposalRejected {
+                    reason: format!(
+                        "Unknown PID preset {} (known: {})",
+                        name,
+                        self.pid_presets.keys().cloned().collect::<Vec<_>>().join(", ")
+                    ),
+                })
+            }
+        };
+        if self.active_preset == name {
+            return Ok(());
+        }
+
+        self.difficulty_pid.set_gains(preset.difficulty);
+        self.gas_pid.set_gains(preset.gas);
+        self.vdf_pid.set_gains(preset.vdf);
+        log::info!("🤖 PID preset: {} → {}", self.active_preset, name);
+        self.active_preset = name.to_string();
+        Ok(())
+    }
+
+    /// Name of the active PID preset
+    pub fn active_preset(&self) -> &str {
+        &self.active_preset
+    }
+
+    fn parameters(&self) -> ConsensusParameters {
+        ConsensusParameters {
+            difficulty: self.current_difficulty,
+            vdf_iterations: self.current_vdf_iterations,
+            min_gas: self.current_min_gas,
+        }
+    }
+
+    fn average_block_time(&self) -> f64 {
+        self.block_time_moments.mean
+    }
+
+    /// Ingest new blocks into the history. Blocks at or below the last
+    /// ingested height are replays (or overlap with a previous window) and
+    /// are skipped so they can't skew the averages.
+    ///
+    /// The blocks that would be ingested are validated as a series first,
+    /// starting from the last block already ingested, so a corrupted feed is
+    /// rejected without ingesting any of it, even one block at a time. Across
+    /// a height gap from the last ingested block only the timestamp order is
+    /// checked, since the skipped blocks' times are unknown.
+    fn update_metrics(&mut self, blocks: &[BlockMetrics]) -> Result<(), AxiomError> {
+        let mut last_height = self.last_ingested_height;
+        let mut previous = self.last_ingested_block.as_ref();
+        let mut previous_ingested = previous.is_some();
+        for block in blocks {
+            if last_height.is_some_and(|last| block.height <= last) {
+                continue;
+            }
+            if let Some(previous) = previous {
+                let gap = previous_ingested && block.height != previous.height + 1;
+                if !gap {
+                    previous.validate_successor(block)?;
+                } else if block.timestamp <= previous.timestamp {
+                    return Err(AxiomError::InvalidBlock(format!(
+                        "Block metrics timestamp not increasing: height {} at {} follows height {} at {}",
+                        block.height, block.timestamp, previous.height, previous.timestamp
+                    )));
+                }
+            }
+            previous = Some(block);
+            previous_ingested = false;
+            last_height = Some(block.height);
+        }
+
+        for block in blocks {
+            if self.last_ingested_height.is_some_and(|last| block.height <= last) {
+                log::debug!("Ignoring already-ingested block metrics at height {}", block.height);
+                continue;
+            }
+            self.last_ingested_height = Some(block.height);
+            self.last_ingested_block = Some(block.clone());
+            self.blocks_ingested += 1;
+
+            self.block_time_history.push(block.block_time);
+            self.hashrate_history.push(block.hashrate_estimate);
+            self.block_time_moments.push(block.block_time as f64);
+            self.hashrate_moments.push(block.hashrate_estimate);
+            if let Some(alert) = self
+                .hashrate_drop
+                .as_mut()
+                .and_then(|tracker| tracker.observe(block.height, block.hashrate_estimate))
+            {
+                self.hashrate_alerts.push(alert);
+            }
+
+            if self.block_time_history.len() > self.tuning.history_capacity {
+                let evicted_time = self.block_time_history.remove(0);
+                let evicted_hashrate = self.hashrate_history.remove(0);
+                self.block_time_moments.evict(evicted_time as f64);
+                self.hashrate_moments.evict(evicted_hashrate);
+            }
+
+            if block.transaction_count > 0 {
+                self.fee_history.push(block.total_fees / block.transaction_count as u64);
+                if self.fee_history.len() > self.tuning.history_capacity {
+                    self.fee_history.remove(0);
+                }
+            }
+
+            let health = self.calculate_network_health_score()?;
+            self.health_history.push(health);
+            if self.health_history.len() > self.tuning.history_capacity {
+                self.health_history.remove(0);
+            }
+        }
+        Ok(())
+    }
+
+    fn take_hashrate_alerts(&mut self) -> Vec<HashrateDrop> {
+        std::mem::take(&mut self.hashrate_alerts)
+    }
+
+    fn record_mempool_sample(&mut self, sample: MempoolSample) {
+        self.mempool_history.push(sample);
+        if self.mempool_history.len() > self.tuning.history_capacity {
+            self.mempool_history.remove(0);
+        }
+    }
+
+    /// Nearest-rank percentiles of the recent per-block average fees, each
+    /// floored at the live minimum gas price, then scaled by congestion.
+    /// With no fee history every percentile starts from the minimum gas.
+    fn fee_percentiles(&self) -> FeePercentiles {
+        let mut recent = self.fee_history[self.fee_history.len().saturating_sub(FEE_PERCENTILE_WINDOW)..].to_vec();
+        recent.sort_unstable();
+        let base = |percentile: usize| -> f64 {
+            let observed = if recent.is_empty() {
+                0
+            } else {
+                let rank = (percentile * recent.len()).div_ceil(100).max(1);
+                recent[rank - 1]
+            };
+            observed.max(self.current_min_gas) as f64
+        };
+
+        let congestion = self.calculate_mempool_congestion().unwrap_or(0.0);
+        let price = |percentile: usize, priority: FeePriority| -> u64 {
+            ((base(percentile) * priority.fee_multiplier(congestion)).ceil() as u64)
+                .max(SovereignInvariants::MIN_TRANSACTION_FEE)
+        };
+
+        let p10 = price(10, FeePriority::Low);
+        let p50 = price(50, FeePriority::Medium).max(p10);
+        let p90 = price(90, FeePriority::High).max(p50);
+        FeePercentiles { p10, p50, p90 }
+    }
+
+    /// Split the shared block-time error between difficulty and VDF so the
+    /// two adjustments never correct the same deviation twice
+    ///
+    /// The total error is positive when blocks are too fast. The part
+    /// explained by a sustained hashrate trend in the same direction is slow
+    /// drift and goes to VDF, capped at the tuned VDF budget share of the total.
+    /// Everything else, including sudden swings, goes to difficulty.
+    fn block_time_budget(&self) -> Result<CorrectionBudget, AxiomError> {
+        let target_time = self.profile.target_block_time() as f64;
+        let avg_time = self.average_block_time();
+        if avg_time == 0.0 {
+            return Ok(CorrectionBudget { difficulty_error: 0.0, vdf_error: 0.0 });
+        }
+
+        let total = (target_time - avg_time) / target_time;
+        let trend = self.calculate_hashrate_trend()?;
+
+        let vdf_error = if trend * total > 0.0 {
+            trend.signum() * trend.abs().min(total.abs() * self.tuning.vdf_budget_share)
+        } else {
+            0.0
+        };
+
+        Ok(CorrectionBudget {
+            difficulty_error: total - vdf_error,
+            vdf_error,
+        })
+    }
+
+    /// Tuned max-change fraction as a swing percent, never above the
+    /// manifest bound even after float rounding
+    fn max_swing_percent(&self, max_change: f64, manifest_percent: f32) -> f64 {
+        (max_change * 100.0).min(manifest_percent as f64)
+    }
+
+    /// Whether the average block time sits inside the tuned dead band
+    fn within_block_time_dead_band(&self) -> bool {
+        if self.block_time_history.is_empty() {
+            return false;
+        }
+        let target = self.profile.target_block_time() as f64;
+        (self.average_block_time() - target).abs() <= self.tuning.block_time_dead_band_secs as f64
+    }
+
+    fn calculate_difficulty_adjustment(&mut self) -> Result<u64, AxiomError> {
+        // Normal variance: no change, and no PID update so the integrator
+        // doesn't creep while inside the band
+        if self.within_block_time_dead_band() {
+            return Ok(self.current_difficulty);
+        }
+
+        // Positive when blocks are too fast → raise difficulty
+        let error = self.block_time_budget()?.difficulty_error;
+        let pid_output = self.difficulty_pid.update(error, 1.0);
+
+        let new_difficulty = (self.current_difficulty as f64 * pid_output) as u64;
+        let max_change = if new_difficulty >= self.current_difficulty {
+            self.tuning.difficulty_max_change
+        } else {
+            self.tuning.difficulty_down_step()
+        };
+        let bounded = bound_to_swing(
+            self.current_difficulty,
+            new_difficulty,
+            self.max_swing_percent(max_change, SovereignInvariants::MAX_AI_DIFFICULTY_SWING_PERCENT),
+        )
+        .max(self.tuning.min_difficulty);
+
+        self.difficulty_pid.settle(self.current_difficulty, new_difficulty, bounded);
+        Ok(bounded)
+    }
+
+    fn calculate_vdf_adjustment(&mut self) -> Result<u64, AxiomError> {
+        // Only the slow, hashrate-driven share of the block-time error
+        let error = self.block_time_budget()?.vdf_error;
+        let pid_output = self.vdf_pid.update(error, 1.0);
+
+        let new_vdf = (self.current_vdf_iterations as f64 * pid_output) as u64;
+        let bounded = bound_to_swing(
+            self.current_vdf_iterations,
+            new_vdf,
+            self.max_swing_percent(self.tuning.vdf_max_change, SovereignInvariants::MAX_AI_VDF_SWING_PERCENT),
+        )
+        .max(SovereignInvariants::MINIMUM_VDF_ITERATIONS);
+
+        self.vdf_pid.settle(self.current_vdf_iterations, new_vdf, bounded);
+        Ok(bounded)
+    }
+
+    /// Average mempool occupancy as a fraction of one block (may exceed 1),
+    /// byte-weighted for samples that carry a byte total
+    fn average_mempool_occupancy(&self) -> Option<f64> {
+        if self.mempool_history.is_empty() {
+            return None;
+        }
+        let total: f64 = self
+            .mempool_history
+            .iter()
+            .map(|sample| match sample.bytes {
+                Some(bytes) => bytes as f64 / SovereignInvariants::MAX_BLOCK_SIZE_BYTES as f64,
+                None => sample.transactions as f64 / self.mempool_capacity as f64,
+            })
+            .sum();
+        Some(total / self.mempool_history.len() as f64)
+    }
+
+    fn calculate_gas_adjustment(&mut self) -> Result<u64, AxiomError> {
+        // Aim for the tuned share of a block (half full by default)
+        let target = self.tuning.gas_target_utilization;
+        let occupancy = self.average_mempool_occupancy().unwrap_or(target);
+
+        let error = (occupancy - target) / target;
+        let pid_output = self.gas_pid.update(error, 1.0);
+
+        let new_gas = (self.current_min_gas as f64 * pid_output) as u64;
+        let bounded = bound_to_swing(
+            self.current_min_gas,
+            new_gas,
+            self.max_swing_percent(self.tuning.gas_max_change, SovereignInvariants::MAX_AI_GAS_SWING_PERCENT),
+        )
+        .max(SovereignInvariants::MIN_TRANSACTION_FEE);
+
+        self.gas_pid.settle(self.current_min_gas, new_gas, bounded);
+        Ok(bounded)
+    }
+
+    // The `calculate_*` telemetry methods are lenient: with too little data
+    // they return a neutral value so the proposal path always has an input.
+    // The `try_*` variants report the shortfall instead.
+
+    fn calculate_hashrate_trend(&self) -> Result<f64, AxiomError> {
+        Ok(self.try_hashrate_trend().unwrap_or(0.0))
+    }
+
+    /// Relative hashrate change across the history; needs 2 samples
+    pub fn try_hashrate_trend(&self) -> Telemetry<f64> {
+        Telemetry::require(self.hashrate_history.len(), 2, || {
+            let recent = *self.hashrate_history.last().unwrap();
+            let older = self.hashrate_history[0];
+            (recent - older) / older
+        })
+    }
+
+    /// Average mempool occupancy as a fraction of one block's capacity
+    fn calculate_mempool_congestion(&self) -> Result<f64, AxiomError> {
+        Ok(self.try_mempool_congestion().unwrap_or(0.0))
+    }
+
+    /// Mempool congestion; needs 1 recorded mempool size
+    pub fn try_mempool_congestion(&self) -> Telemetry<f64> {
+        Telemetry::require(self.mempool_history.len(), 1, || {
+            self.average_mempool_occupancy().unwrap_or(0.0).min(1.0)
+        })
+    }
+
+    fn calculate_network_health_score(&self) -> Result<f64, AxiomError> {
+        let block_time_score = self.calculate_block_time_stability()?;
+        let hashrate_score = self.calculate_hashrate_stability()?;
+        Ok((block_time_score + hashrate_score) / 2.0)
+    }
+
+    /// Network health score; needs the inputs of both stability scores
+    pub fn try_network_health_score(&self) -> Telemetry<f64> {
+        match (self.try_block_time_stability(), self.try_hashrate_stability()) {
+            (Telemetry::Ready(block_time_score), Telemetry::Ready(hashrate_score)) => {
+                Telemetry::Ready((block_time_score + hashrate_score) / 2.0)
+            }
+            (Telemetry::InsufficientData { have, need }, _) | (_, Telemetry::InsufficientData { have, need }) => {
+                Telemetry::InsufficientData { have, need }
+            }
+        }
+    }
+
+    /// Network health score recorded after each ingested block, oldest first
+    pub fn network_health_history(&self) -> &[f64] {
+        &self.health_history
+    }
+
+    /// Least-squares slope of the last `HEALTH_TREND_WINDOW` health scores,
+    /// in score per block. Negative means health is degrading; 0.0 until two
+    /// scores have been recorded.
+    pub fn network_health_trend(&self) -> f64 {
+        let recent = &self.health_history[self.health_history.len().saturating_sub(HEALTH_TREND_WINDOW)..];
+        if recent.len() < 2 {
+            return 0.0;
+        }
+
+        let n = recent.len() as f64;
+        let mean_x = (n - 1.0) / 2.0;
+        let mean_y = recent.iter().sum::<f64>() / n;
+        let (covariance, variance) = recent
+            .iter()
+            .enumerate()
+            .fold((0.0, 0.0), |(cov, var), (i, y)| {
+                let dx = i as f64 - mean_x;
+                (cov + dx * (y - mean_y), var + dx * dx)
+            });
+        covariance / variance
+    }
+
+    fn calculate_block_time_stability(&self) -> Result<f64, AxiomError> {
+        Ok(self.try_block_time_stability().unwrap_or(0.5))
+    }
+
+    fn try_block_time_stability(&self) -> Telemetry<f64> {
+        Telemetry::require(self.block_time_history.len(), 1, || {
+            let target = self.profile.target_block_time() as f64;
+            let deviation = ((self.average_block_time() - target) / target).abs();
+            (1.0 - deviation).max(0.0).min(1.0)
+        })
+    }
+
+    fn calculate_hashrate_stability(&self) -> Result<f64, AxiomError> {
+        Ok(self.try_hashrate_stability().unwrap_or(0.5))
+    }
+
+    fn try_hashrate_stability(&self) -> Telemetry<f64> {
+        Telemetry::require(self.hashrate_history.len(), self.tuning.min_hashrate_samples.max(2), || {
+            let cv = self.hashrate_moments.variance().sqrt() / self.hashrate_moments.mean;
+            (1.0 - cv).max(0.0).min(1.0)
+        })
+    }
+
+    fn calculate_confidence(&self) -> Result<f64, AxiomError> {
+        if self.block_time_history.len() < self.tuning.min_confidence_blocks {
+            return Ok(0.5 * self.calibration_factor());
+        }
+
+        let data_quality = (self.block_time_history.len() as f64 / self.tuning.history_capacity as f64).min(1.0);
+        let stability = self.calculate_network_health_score()?;
+
+        Ok((data_quality + stability) / 2.0 * self.calibration_factor())
+    }
+
+    /// Confidence multiplier from the last `CALIBRATION_WINDOW` recorded
+    /// outcomes: 1.0 when predictions held up, down to
+    /// `1 - calibration_penalty` when none of the promised improvement
+    /// materialised. Under-promising is never penalised.
+    fn calibration_factor(&self) -> f64 {
+        let (overshoot, promised) = self
+            .optimization_history
+            .iter()
+            .rev()
+            .filter_map(|record| record.actual_improvement.map(|actual| (record.predicted_improvement, actual)))
+            .take(CALIBRATION_WINDOW)
+            .fold((0.0, 0.0), |(overshoot, promised), (predicted, actual)| {
+                (overshoot + (predicted - actual).max(0.0), promised + predicted.abs())
+            });
+        if promised == 0.0 {
+            return 1.0;
+        }
+
+        let over_optimism = (overshoot / promised).min(1.0);
+        1.0 - self.tuning.calibration_penalty.clamp(0.0, 1.0) * over_optimism
+    }
+
+    /// Weighted deviation from targets: block time (50%), mempool
+    /// congestion (25%) and lost network health (25%)
+    fn calculate_urgency(&self, avg_block_time: f64, congestion: f64, health: f64) -> f64 {
+        let target = self.profile.target_block_time() as f64;
+        let block_time_deviation = ((avg_block_time - target) / target).abs().min(1.0);
+
+        let urgency = 0.5 * block_time_deviation
+            + 0.25 * congestion.clamp(0.0, 1.0)
+            + 0.25 * (1.0 - health.clamp(0.0, 1.0));
+        urgency.clamp(0.0, 1.0)
+    }
+
+    /// Expected improvement, in percentage points, from moving to `proposed`
+    ///
+    /// Block time is modelled as proportional to difficulty and VDF
+    /// iterations, and mempool demand as inversely proportional to the
+    /// minimum gas price. The gain is how much closer each metric is expected
+    /// to land to its target, block time weighted 3:1 over congestion, scaled
+    /// by `ai_confidence`. A change that moves away from the targets scores
+    /// negative; a no-op scores zero.
+    fn calculate_expected_improvement(
+        &self,
+        proposed: ConsensusParameters,
+        ai_confidence: f64,
+    ) -> Result<f64, AxiomError> {
+        let current_avg = self.average_block_time();
+        if current_avg == 0.0 {
+            return Ok(0.0);
+        }
+
+        let ratio = |current: u64, new: u64| new as f64 / current.max(1) as f64;
+
+        let target = self.profile.target_block_time() as f64;
+        let expected_avg = current_avg
+            * ratio(self.current_difficulty, proposed.difficulty)
+            * ratio(self.current_vdf_iterations, proposed.vdf_iterations);
+        let block_time_gain = ((current_avg - target).abs() - (expected_avg - target).abs()) / target;
+
+        let congestion = self.calculate_mempool_congestion()?;
+        let expected_congestion = (congestion / ratio(self.current_min_gas, proposed.min_gas)).min(1.0);
+        let target_utilization = self.tuning.gas_target_utilization;
+        let congestion_gain =
+            (congestion - target_utilization).abs() - (expected_congestion - target_utilization).abs();
+
+        Ok((0.75 * block_time_gain + 0.25 * congestion_gain) * 100.0 * ai_confidence.clamp(0.0, 1.0))
+    }
+}
+
+/// Clamp `proposed` to the symmetric ratio band the manifest enforces:
+/// `current / (1 + swing) <= proposed <= current * (1 + swing)`
+fn bound_to_swing(current: u64, proposed: u64, max_swing_percent: f64) -> u64 {
+    if current == 0 {
+        return proposed;
+    }
+
+    let max_ratio = 1.0 + max_swing_percent / 100.0;
+    let mut upper = (current as f64 * max_ratio).floor() as u64;
+    let mut lower = (current as f64 / max_ratio).ceil() as u64;
+
+    // Guard against float rounding pushing a bound past the manifest check
+    while upper > current && upper as f64 / current as f64 > max_ratio {
+        upper -= 1;
+    }
+    while lower < current && current as f64 / lower as f64 > max_ratio {
+        lower += 1;
+    }
+
+    proposed.clamp(lower, upper)
+}
+
+// ==================== PID CONTROLLER ====================
+
+impl PIDController {
+    fn new(gains: PIDGains, output_min: f64, output_max: f64) -> Self {
+        Self {
+            kp: gains.kp,
+            ki: gains.ki,
+            kd: gains.kd,
+            integral: 0.0,
+            previous_error: 0.0,
+            last_raw_output: 1.0,
+            derivative_filter: None,
+            filtered_derivative: 0.0,
+            output_min,
+            output_max,
+        }
+    }
+
+    /// Output clamped to `1 ± max_change`, matching a per-proposal swing bound
+    fn with_max_change(gains: PIDGains, max_change: f64) -> Self {
+        Self::new(gains, 1.0 - max_change, 1.0 + max_change)
+    }
+
+    /// Replace the gains without a jump in output: the integrator is
+    /// rescaled to keep `ki * integral` constant (and cleared if the new
+    /// `ki` is zero)
+    fn set_gains(&mut self, gains: PIDGains) {
+        self.integral = if gains.ki == 0.0 {
+            0.0
+        } else {
+            self.integral * self.ki / gains.ki
+        };
+        self.kp = gains.kp;
+        self.ki = gains.ki;
+        self.kd = gains.kd;
+    }
+
+    /// Forget accumulated state; gains, limits and the filter setting stay
+    fn reset(&mut self) {
+        self.integral = 0.0;
+        self.previous_error = 0.0;
+        self.last_raw_output = 1.0;
+        self.filtered_derivative = 0.0;
+    }
+
+    fn state(&self) -> PIDState {
+        PIDState {
+            integral: self.integral,
+            previous_error: self.previous_error,
+            last_raw_output: self.last_raw_output,
+            filtered_derivative: self.filtered_derivative,
+        }
+    }
+
+    fn restore(&mut self, state: &PIDState) {
+        self.integral = state.integral;
+        self.previous_error = state.previous_error;
+        self.last_raw_output = state.last_raw_output;
+        self.filtered_derivative = state.filtered_derivative;
+    }
+
+    /// Low-pass the derivative term with coefficient `n` (larger = less
+    /// smoothing); `None` restores the raw derivative
+    fn set_derivative_filter(&mut self, n: Option<f64>) {
+        self.derivative_filter = n.filter(|n| n.is_finite() && *n > 0.0);
+        self.filtered_derivative = 0.0;
+    }
+
+    /// Returns a multiplicative adjustment factor centred on 1.0
+    /// (zero error → no change), clamped to `[output_min, output_max]`
+    fn update(&mut self, error: f64, dt: f64) -> f64 {
+        self.integral += error * dt;
+        let raw_derivative = (error - self.previous_error) / dt;
+        self.previous_error = error;
+
+        // Discrete first-order filter: D += α (D_raw − D), α = N·dt / (1 + N·dt)
+        let derivative = match self.derivative_filter {
+            Some(n) => {
+                let alpha = n * dt / (1.0 + n * dt);
+                self.filtered_derivative += alpha * (raw_derivative - self.filtered_derivative);
+                self.filtered_derivative
+            }
+            None => raw_derivative,
+        };
+
+        let output = 1.0 + self.kp * error + self.ki * self.integral + self.kd * derivative;
+        self.last_raw_output = output;
+        output.max(self.output_min).min(self.output_max)
+    }
+
+    /// Whether the last update hit the output clamp
+    fn is_saturated(&self) -> bool {
+        self.last_raw_output < self.output_min || self.last_raw_output > self.output_max
+    }
+
+    /// Anti-windup by back-calculation: shift the integrator by the gap
+    /// between the factor last requested and the one actually `applied`, so
+    /// it stops accumulating error the clamp never let through. The
+    /// correction can unwind the integrator to zero but never flips its sign.
+    fn back_calculate(&mut self, applied: f64) {
+        if self.ki == 0.0 {
+            return;
+        }
+        let corrected = self.integral + (applied - self.last_raw_output) / self.ki;
+        self.integral = if corrected * self.integral < 0.0 { 0.0 } else { corrected };
+        self.last_raw_output = applied;
+    }
+
+    /// Feed back the parameter value that survived the swing bound and
+    /// floors. Only engages when a clamp actually held the change back.
+    fn settle(&mut self, current: u64, requested: u64, applied: u64) {
+        if applied != requested || self.is_saturated() {
+            self.back_calculate(applied as f64 / current.max(1) as f64);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block_series(count: usize, block_time: u64) -> Vec<BlockMetrics> {
+        (0..count as u64)
+            .map(|i| BlockMetrics {
+                height: i + 1,
+                timestamp: 1_700_000_000 + (i + 1) * block_time,
+                block_time,
+                difficulty: 1000,
+                vdf_iterations: 1_000_000,
+                transaction_count: 100,
+                total_fees: 100_000,
+                hashrate_estimate: 1e12,
+            })
+            .collect()
+    }
+
+    fn tx_profile(gas_price: u64) -> TransactionRiskProfile {
+        TransactionRiskProfile {
+            hash: "tx_shadow".to_string(),
+            timestamp: 1,
+            sender: "alice".to_string(),
+            recipient: "bob".to_string(),
+            amount: 100_00000000,
+            gas_price,
+            zk_proof_size: 500,
+            sender_history_count: 10,
+            recipient_history_count: 10,
+            sender_reputation_score: 0.9,
+            time_since_last_sender_tx: 100,
+            time_since_last_recipient_tx: 100,
+            is_contract_deployment: false,
+            contract_bytecode_size: 0,
+            vdf_verification_time_ms: 1000,
+        }
+    }
+
+    /// Four registered validators of weight 1, so the default 3-of-4 rule
+    fn voting_config() -> BridgeConfig {
+        BridgeConfig {
+            voting_policy: VotingPolicy {
+                validators: ["v1", "v2", "v3", "v4"].iter().map(|v| (v.to_string(), 1)).collect(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Open a vote on a tracked proposal and pass it with three approvals
+    fn approve_by_vote(bridge: &AIGuardianBridge, proposal_id: &str) {
+        bridge.transition_proposal(proposal_id, ProposalStatus::PendingVote).unwrap();
+        for validator in ["v1", "v2", "v3"] {
+            bridge.cast_vote(proposal_id, validator, true, None).unwrap();
+        }
+        assert_eq!(bridge.finalize_vote(proposal_id).unwrap(), ProposalStatus::Approved);
+    }
+
+    #[test]
+    fn test_guardian_bridge_creation() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let bridge = AIGuardianBridge::new(engine);
+        let stats = bridge.get_guardian_stats();
+        assert_eq!(stats.total_ai_decisions, 0);
+    }
+
+    #[test]
+    fn test_auto_pilot_toggle_is_audited() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let bridge = AIGuardianBridge::new(engine);
+
+        bridge.set_auto_pilot_mode(true);
+        assert!(bridge.get_guardian_stats().auto_pilot_mode);
+
+        let events = bridge.drain_events();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            events[0],
+            GuardianEvent::StateChanged { field: GuardianFlag::AutoPilotMode, old: false, new: true, .. }
+        ));
+
+        let audit = bridge.audit_log();
+        assert_eq!(audit.len(), 1);
+        assert!(matches!(
+            audit[0].record,
+            AuditRecord::StateChanged { field: GuardianFlag::AutoPilotMode, old: false, new: true }
+        ));
+
+        // Re-applying the same value is not a transition
+        bridge.set_auto_pilot_mode(true);
+        assert!(bridge.drain_events().is_empty());
+        assert_eq!(bridge.audit_log().len(), 1);
+        assert_eq!(bridge.full_snapshot().audit_entries, 1);
+    }
+
+    #[test]
+    fn test_manual_proposal_within_bounds() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let bridge = AIGuardianBridge::new(engine);
+        let live = bridge.consensus_parameters();
+
+        let proposal = bridge
+            .manual_proposal(live.difficulty + 20, live.vdf_iterations + 10_000, live.min_gas + 50, 500)
+            .expect("in-bounds manual proposal should be accepted");
+
+        assert!(proposal.proposal_id.starts_with("manual_consensus_500_"));
+        assert_eq!(proposal.proposed_difficulty, live.difficulty + 20);
+        assert_eq!(proposal.current_difficulty, live.difficulty);
+        assert!(proposal.pre_approval.bounds_ok);
+        assert!(proposal.requires_voting);
+    }
+
+    #[test]
+    fn test_manual_proposal_out_of_bounds() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let bridge = AIGuardianBridge::new(engine);
+        let live = bridge.consensus_parameters();
+
+        // Doubling difficulty breaches the ±5% swing bound
+        let result = bridge.manual_proposal(live.difficulty * 2, live.vdf_iterations, live.min_gas, 500);
+        assert!(matches!(result, Err(AxiomError::AIProposalRejected { .. })));
+    }
+
+    #[test]
+    fn test_apply_rechecks_untracked_proposals() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        // Anything under 90% confidence needs a vote here
+        let config = BridgeConfig {
+            voting_policy: VotingPolicy {
+                governance_confidence: 0.9,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let bridge = AIGuardianBridge::try_new_with_config(engine.clone(), config).unwrap();
+        let live = bridge.consensus_parameters();
+        // Built elsewhere, so untracked here; every field is the sender's claim
+        let template = AIGuardianBridge::new(engine)
+            .manual_proposal(live.difficulty + 20, live.vdf_iterations, live.min_gas, 500)
+            .unwrap();
+        let reject_reason = |edit: &dyn Fn(&mut ConsensusOptimizationProposal)| {
+            let mut forged = template.clone();
+            edit(&mut forged);
+            match bridge.apply_consensus_optimization(&forged) {
+                Err(AxiomError::AIProposalRejected { reason }) => reason,
+                other => panic!("forged proposal accepted: {:?}", other),
+            }
+        };
+
+        // Claimed pre-approval for a VDF below the manifest floor
+        let reason = reject_reason(&|p| p.proposed_vdf = SovereignInvariants::MINIMUM_VDF_ITERATIONS - 1);
+        assert!(reason.contains("fails the manifest checks"), "{}", reason);
+
+        // Measured against parameters the chain isn't at
+        let reason = reject_reason(&|p| {
+            p.current_difficulty *= 5;
+            p.proposed_difficulty = p.current_difficulty;
+        });
+        assert!(reason.contains("but the live parameters are"), "{}", reason);
+
+        // Within bounds, but waiving its own vote with an inflated confidence:
+        // this controller's own confidence without history is 0.5
+        let reason = reject_reason(&|p| {
+            p.requires_voting = false;
+            p.ai_confidence = 1.0;
+        });
+        assert!(reason.contains("requires voting"), "{}", reason);
+
+        assert_eq!(bridge.consensus_parameters(), live);
+    }
+
+    #[test]
+    fn test_proposal_lifecycle() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let bridge = AIGuardianBridge::try_new_with_config(engine, voting_config()).unwrap();
+        let live = bridge.consensus_parameters();
+
+        let proposal = bridge
+            .manual_proposal(live.difficulty + 10, live.vdf_iterations, live.min_gas, 100)
+            .unwrap();
+        let id = proposal.proposal_id.clone();
+        assert_eq!(bridge.proposal_status(&id), Some(ProposalStatus::Generated));
+
+        // Voting is required, so it can't be applied or approved straight
+        // from Generated, even if the caller's copy claims otherwise
+        assert!(bridge.apply_consensus_optimization(&proposal).is_err());
+        assert!(bridge.transition_proposal(&id, ProposalStatus::Approved).is_err());
+        let unvoted = ConsensusOptimizationProposal {
+            requires_voting: false,
+            ..proposal.clone()
+        };
+        assert!(bridge.apply_consensus_optimization(&unvoted).is_err());
+
+        // Once pending a vote, only the vote decides it
+        bridge.transition_proposal(&id, ProposalStatus::PendingVote).unwrap();
+        assert!(bridge.transition_proposal(&id, ProposalStatus::Approved).is_err());
+        assert!(bridge.transition_proposal(&id, ProposalStatus::Rejected).is_err());
+        for validator in ["v1", "v2", "v3"] {
+            bridge.cast_vote(&id, validator, true, None).unwrap();
+        }
+        assert_eq!(bridge.finalize_vote(&id).unwrap(), ProposalStatus::Approved);
+        bridge.apply_consensus_optimization(&proposal).unwrap();
+        assert_eq!(bridge.proposal_status(&id), Some(ProposalStatus::Applied));
+        assert_eq!(bridge.consensus_parameters().difficulty, live.difficulty + 10);
+
+        // Terminal: nothing moves an applied proposal
+        assert!(bridge.transition_proposal(&id, ProposalStatus::Rejected).is_err());
+        assert!(bridge.apply_consensus_optimization(&proposal).is_err());
+    }
+
+    #[test]
+    fn test_over_optimistic_predictions_lower_confidence() {
+        let bridge = AIGuardianBridge::try_new_with_config(
+            Arc::new(MultiLayerSecurityEngine::new(Default::default())),
+            voting_config(),
+        ).unwrap();
+        // Slow blocks, so easing difficulty promises an improvement
+        bridge.consensus_ai.write().update_metrics(&block_series(144, 2_000)).unwrap();
+        let uncalibrated = bridge.consensus_ai.read().calculate_confidence().unwrap();
+
+        // Three applied proposals each promised an improvement and delivered none
+        for height in 200..203 {
+            let live = bridge.consensus_parameters();
+            let mut proposal = bridge
+                .manual_proposal(live.difficulty - 10, live.vdf_iterations, live.min_gas, height)
+                .unwrap();
+            let promised = proposal.expected_improvement;
+            assert!(promised > 0.0);
+
+            // The caller's copy can't inflate the recorded prediction
+            proposal.expected_improvement = 1_000.0;
+            approve_by_vote(&bridge, &proposal.proposal_id);
+            bridge.apply_consensus_optimization(&proposal).unwrap();
+            let recorded = bridge.consensus_ai.read().optimization_history.last().unwrap().predicted_improvement;
+            assert_eq!(recorded, promised);
+            bridge.record_optimization_outcome(&proposal.proposal_id, 0.0).unwrap();
+
+            assert!(bridge.record_optimization_outcome(&proposal.proposal_id, 0.0).is_err());
+        }
+        assert!(bridge.record_optimization_outcome("unknown", 1.0).is_err());
+
+        // None of the promised improvement came: the full 0.5 penalty
+        let calibrated = bridge.consensus_ai.read().calculate_confidence().unwrap();
+        assert!(calibrated < uncalibrated);
+        assert!((calibrated - uncalibrated * 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pid_preset_switch_mid_operation() {
+        let blocks = block_series(288, 1_700);
+        let mut default = ConsensusAIController::with_profile(NetworkProfile::default());
+        default.compute_adjustments(&blocks[..144]).unwrap();
+        let mut switched = ConsensusAIController::with_profile(NetworkProfile::default());
+        switched.compute_adjustments(&blocks[..144]).unwrap();
+
+        let integral_term = switched.difficulty_pid.ki * switched.difficulty_pid.integral;
+        switched.activate_preset("conservative").unwrap();
+        assert_eq!(switched.active_preset(), "conservative");
+        assert_eq!(switched.difficulty_pid.kp, PIDPreset::CONSERVATIVE.difficulty.kp);
+        assert_eq!(switched.gas_pid.ki, PIDPreset::CONSERVATIVE.gas.ki);
+        // Bumpless: the integrator's contribution carries over unchanged
+        assert!((switched.difficulty_pid.ki * switched.difficulty_pid.integral - integral_term).abs() < 1e-12);
+
+        // Same blocks, gentler correction
+        let default_step = default.compute_adjustments(&blocks[144..]).unwrap().difficulty;
+        let conservative_step = switched.compute_adjustments(&blocks[144..]).unwrap().difficulty;
+        assert!(conservative_step > 1_000, "{}", conservative_step);
+        assert!(conservative_step < default_step, "{} vs {}", conservative_step, default_step);
+
+        assert!(switched.activate_preset("reckless").is_err());
+        assert_eq!(switched.active_preset(), "conservative");
+    }
+
+    #[test]
+    fn test_bridges_generate_identical_proposals() {
+        // Unseeded: nothing consensus-facing may depend on the RNG
+        let config = BridgeConfig::default();
+        let blocks = block_series(200, 1_700);
+
+        let proposals: Vec<ConsensusOptimizationProposal> = (0..2)
+            .map(|_| {
+                let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+                let bridge = AIGuardianBridge::try_new_with_config(engine, config.clone()).unwrap();
+                bridge.generate_consensus_optimization(1_000, &blocks).unwrap()
+            })
+            .collect();
+
+        let (a, b) = (&proposals[0], &proposals[1]);
+        assert_eq!(a.proposal_id, b.proposal_id);
+        assert_eq!(a.proposed_difficulty, b.proposed_difficulty);
+        assert_eq!(a.proposed_vdf, b.proposed_vdf);
+        assert_eq!(a.proposed_min_gas, b.proposed_min_gas);
+        assert_eq!(a.ai_confidence, b.ai_confidence);
+        assert_eq!(a.expected_improvement, b.expected_improvement);
+
+        // Fast blocks must push difficulty up, within the manifest swing
+        assert!(a.proposed_difficulty > a.current_difficulty);
+        assert!(a.difficulty_change_percent <= 5.0);
+
+        // Identical proposals at one height still get distinct identifiers
+        let bridge = AIGuardianBridge::new(Arc::new(MultiLayerSecurityEngine::new(Default::default())));
+        let first = bridge.manual_proposal(1_010, 1_000_000, 1_000, 500).unwrap();
+        let second = bridge.manual_proposal(1_010, 1_000_000, 1_000, 500).unwrap();
+        assert_ne!(first.proposal_id, second.proposal_id);
+    }
+
+    #[test]
+    fn test_guardian_action_response_codes() {
+        let cases = [
+            (GuardianAction::Accept, 200, true),
+            (GuardianAction::AcceptMonitored, 200, true),
+            (GuardianAction::Quarantine { duration_blocks: 10 }, 202, false),
+            (GuardianAction::Reject, 403, true),
+            (GuardianAction::AutoReject, 403, true),
+            (GuardianAction::RequireManualReview { threat_level: RiskLevel::High }, 409, false),
+            (GuardianAction::ChainHalt, 503, true),
+        ];
+
+        for (action, code, terminal) in cases {
+            assert_eq!(action.to_response_code(), code, "{:?}", action);
+            assert_eq!(action.is_terminal(), terminal, "{:?}", action);
+        }
+    }
+
+    #[test]
+    fn test_regtest_profile_targets_its_own_block_time() {
+        let mut regtest = ConsensusAIController::with_profile(NetworkProfile::regtest());
+        regtest.update_metrics(&block_series(200, 10)).unwrap();
+        assert_eq!(regtest.calculate_difficulty_adjustment().unwrap(), regtest.current_difficulty);
+        assert_eq!(regtest.calculate_block_time_stability().unwrap(), 1.0);
+
+        // Mainnet-paced blocks are far too slow for regtest → difficulty drops
+        let mut slow = ConsensusAIController::with_profile(NetworkProfile::regtest());
+        slow.update_metrics(&block_series(200, 1_800)).unwrap();
+        assert!(slow.calculate_difficulty_adjustment().unwrap() < slow.current_difficulty);
+
+        // ...while mainnet sees 10s blocks as far too fast
+        let mut mainnet = ConsensusAIController::with_profile(NetworkProfile::Mainnet);
+        mainnet.update_metrics(&block_series(200, 10)).unwrap();
+        assert!(mainnet.calculate_difficulty_adjustment().unwrap() > mainnet.current_difficulty);
+    }
+
+    #[test]
+    fn test_swing_bound_matches_manifest_check() {
+        for current in [100u64, 953, 1_000, 999_999, 1_000_000, 123_456_789] {
+            for proposed in [0, current / 2, current, current * 2] {
+                let bounded = bound_to_swing(current, proposed, 5.0);
+                assert!(SovereignInvariants::verify_ai_difficulty_proposal(current, bounded).is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn test_rejected_proposal_cannot_be_applied() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let bridge = AIGuardianBridge::new(engine);
+        let live = bridge.consensus_parameters();
+
+        let proposal = bridge
+            .manual_proposal(live.difficulty + 10, live.vdf_iterations, live.min_gas, 100)
+            .unwrap();
+        let id = proposal.proposal_id.clone();
+
+        bridge.transition_proposal(&id, ProposalStatus::Rejected).unwrap();
+        assert!(bridge.transition_proposal(&id, ProposalStatus::Approved).is_err());
+        assert!(bridge.apply_consensus_optimization(&proposal).is_err());
+        assert_eq!(bridge.consensus_parameters(), live);
+        assert!(bridge.transition_proposal("no_such_proposal", ProposalStatus::Approved).is_err());
+    }
+
+    #[test]
+    fn test_shadow_mode_records_veto_but_accepts() {
+        let bridge = AIGuardianBridge::new_with_assessor(
+            Arc::new(CatastrophicEngine),
+            BridgeConfig::default(),
+        )
+        .unwrap();
+        bridge.set_shadow_mode(true);
+
+        // The AI's rejection would normally be enforced
+        let decision = bridge
+            .validate_transaction_with_guardian(tx_profile(2_000), 1000)
+            .unwrap();
+        assert!(decision.approved);
+        assert!(matches!(decision.action, GuardianAction::AcceptMonitored));
+
+        let stats = bridge.get_guardian_stats();
+        assert_eq!(stats.total_ai_decisions, 1);
+        assert_eq!(stats.guardian_vetoes, 1);
+        assert_eq!(stats.shadow_vetoes, 1);
+
+        let audit = bridge.audit_log();
+        assert!(matches!(
+            audit.last().unwrap().record,
+            AuditRecord::ShadowDecision {
+                would_approve: false,
+                would_action: GuardianAction::Reject,
+                block_height: 1000,
+                ..
+            }
+        ));
+
+        // Leaving shadow mode enforces the same decision again
+        bridge.set_shadow_mode(false);
+        let decision = bridge
+            .validate_transaction_with_guardian(tx_profile(2_000), 1001)
+            .unwrap();
+        assert!(!decision.approved);
+        assert!(matches!(decision.action, GuardianAction::Reject));
+        assert_eq!(bridge.get_guardian_stats().shadow_vetoes, 1);
+    }
+
+    #[test]
+    fn test_shadow_mode_still_enforces_minimum_fee() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let bridge = AIGuardianBridge::new(engine);
+        bridge.set_shadow_mode(true);
+
+        let decision = bridge
+            .validate_transaction_with_guardian(tx_profile(1), 1000)
+            .unwrap();
+        assert!(!decision.approved);
+        assert!(matches!(decision.action, GuardianAction::Reject));
+        assert_eq!(decision.veto_category, Some(VetoCategory::MinimumFee));
+
+        let stats = bridge.get_guardian_stats();
+        assert_eq!(stats.guardian_vetoes, 1);
+        assert_eq!(stats.shadow_vetoes, 0);
+        assert!(matches!(bridge.audit_log().last().unwrap().record, AuditRecord::Decision { approved: false, .. }));
+    }
+
+    #[test]
+    fn test_pid_derivative_filter_reduces_jitter() {
+        fn peak_to_peak(pid: &mut PIDController) -> f64 {
+            let outputs: Vec<f64> = (0..40)
+                .map(|i| pid.update(if i % 2 == 0 { 1.0 } else { -1.0 }, 1.0))
+                .skip(20)
+                .collect();
+            let max = outputs.iter().cloned().fold(f64::MIN, f64::max);
+            let min = outputs.iter().cloned().fold(f64::MAX, f64::min);
+            max - min
+        }
+
+        // Derivative-only controller with no effective clamp
+        let mut raw = PIDController::new(PIDGains { kp: 0.0, ki: 0.0, kd: 1.0 }, -1e9, 1e9);
+        let mut filtered = raw.clone();
+        filtered.set_derivative_filter(Some(0.5));
+
+        let raw_swing = peak_to_peak(&mut raw);
+        let filtered_swing = peak_to_peak(&mut filtered);
+        assert!((raw_swing - 4.0).abs() < 1e-9);
+        assert!(filtered_swing < raw_swing / 2.0);
+
+        // Bypassing the filter restores the raw behaviour
+        filtered.set_derivative_filter(None);
+        assert!((peak_to_peak(&mut filtered) - raw_swing).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_recommended_fee_rises_with_congestion() {
+        let quiet = AIGuardianBridge::new(Arc::new(MultiLayerSecurityEngine::new(Default::default())));
+        let busy = AIGuardianBridge::new(Arc::new(MultiLayerSecurityEngine::new(Default::default())));
+        for _ in 0..10 {
+            quiet.record_mempool_size(50);
+            busy.record_mempool_size(900);
+        }
+
+        let min_gas = quiet.consensus_parameters().min_gas;
+        assert!(quiet.recommend_fee(FeePriority::Low) >= min_gas);
+        assert!(quiet.recommend_fee(FeePriority::High) > quiet.recommend_fee(FeePriority::Low));
+
+        // Congestion lifts every tier, High the most
+        let quiet_high = quiet.recommend_fee(FeePriority::High);
+        let busy_high = busy.recommend_fee(FeePriority::High);
+        assert!(busy_high > quiet_high * 2, "{} vs {}", busy_high, quiet_high);
+        assert!(busy.recommend_fee(FeePriority::Medium) > quiet.recommend_fee(FeePriority::Medium));
+        assert!(busy_high > busy.recommend_fee(FeePriority::Medium));
+    }
+
+    #[test]
+    fn test_full_snapshot_is_consistent() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let bridge = AIGuardianBridge::new(engine);
+
+        // One vetoed transaction, then trip the breaker
+        bridge.validate_transaction_with_guardian(tx_profile(1), 500).unwrap();
+        bridge
+            .activate_circuit_breaker(500, "operator drill".to_string())
+            .unwrap();
+
+        let snapshot = bridge.full_snapshot();
+        assert!(snapshot.circuit_breaker_active);
+        assert_eq!(snapshot.circuit_breaker_reason.as_deref(), Some("operator drill"));
+        assert_eq!(snapshot.circuit_breaker_category, Some(BreakerReason::Manual));
+        assert_eq!(snapshot.circuit_breaker_severity, Some(BreakerSeverity::High));
+        assert_eq!(snapshot.circuit_breaker_activation_block, Some(500));
+        assert_eq!(snapshot.circuit_breaker_auto_recovery_block, Some(644));
+        assert_eq!(snapshot.stats.total_ai_decisions, 1);
+        assert_eq!(snapshot.stats.guardian_vetoes, 1);
+        assert_eq!(snapshot.parameters, bridge.consensus_parameters());
+        assert_eq!(snapshot.tracked_proposals, 0);
+    }
+
+    #[test]
+    fn test_urgency_tracks_block_time_deviation() {
+        let on_target = AIGuardianBridge::new(Arc::new(MultiLayerSecurityEngine::new(Default::default())))
+            .generate_consensus_optimization(144, &block_series(144, SovereignInvariants::TARGET_BLOCK_TIME_SECS))
+            .unwrap();
+        let off_target = AIGuardianBridge::new(Arc::new(MultiLayerSecurityEngine::new(Default::default())))
+            .generate_consensus_optimization(144, &block_series(144, 2 * SovereignInvariants::TARGET_BLOCK_TIME_SECS))
+            .unwrap();
+
+        assert!(on_target.urgency < 0.1, "on-target urgency {}", on_target.urgency);
+        assert!(off_target.urgency > 0.5, "off-target urgency {}", off_target.urgency);
+        assert!(off_target.is_urgent(0.5));
+        assert!(!on_target.is_urgent(0.5));
+    }
+
+    #[test]
+    fn test_proposal_preview_is_stateless() {
+        let baseline = ConsensusParameters {
+            difficulty: 5_000,
+            vdf_iterations: 2_000_000,
+            min_gas: 2_000,
+        };
+        let history = block_series(144, 1_700);
+
+        let first = ConsensusAIController::compute_proposal_from(baseline, &history).unwrap();
+        let second = ConsensusAIController::compute_proposal_from(baseline, &history).unwrap();
+        assert_eq!(first.proposal_id, second.proposal_id);
+        assert_eq!(first.timestamp, second.timestamp);
+        assert_eq!(first.proposed_difficulty, second.proposed_difficulty);
+        assert_eq!(first.proposed_vdf, second.proposed_vdf);
+        assert_eq!(first.proposed_min_gas, second.proposed_min_gas);
+        assert_eq!(first.current_difficulty, baseline.difficulty);
+
+        // A live bridge is unaffected by previews and vice versa
+        let bridge = AIGuardianBridge::new(Arc::new(MultiLayerSecurityEngine::new(Default::default())));
+        let live_before = bridge.consensus_parameters();
+        bridge.generate_consensus_optimization(144, &history).unwrap();
+        assert_eq!(bridge.consensus_parameters(), live_before);
+
+        let third = ConsensusAIController::compute_proposal_from(baseline, &history).unwrap();
+        assert_eq!(third.proposed_difficulty, first.proposed_difficulty);
+    }
+
+    #[test]
+    fn test_coinbase_skips_engine_but_checks_reward() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let bridge = AIGuardianBridge::new(engine);
+        let height = 10;
+        let reward = SovereignInvariants::calculate_expected_reward(height);
+
+        // An instant VDF would be flagged as a bypass by the engine
+        let mut coinbase = tx_profile(0);
+        coinbase.amount = reward;
+        coinbase.vdf_verification_time_ms = 0;
+
+        let decision = bridge.validate_block_transaction(coinbase.clone(), 0, height, 0).unwrap();
+        assert!(decision.approved);
+        assert!(decision.threat_assessment.identified_threats.is_empty());
+        assert_eq!(bridge.get_guardian_stats().total_ai_decisions, 0);
+
+        // The same transaction anywhere else in the block is a user transaction
+        bridge.validate_block_transaction(coinbase.clone(), 1, height, 0).unwrap();
+        assert_eq!(bridge.get_guardian_stats().total_ai_decisions, 1);
+
+        coinbase.amount = reward + 1;
+        assert!(matches!(
+            bridge.validate_block_transaction(coinbase, 0, height, 0),
+            Err(AxiomError::InvalidBlockReward { .. })
+        ));
+    }
+
+    #[test]
+    fn test_coinbase_collects_block_fees() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let bridge = AIGuardianBridge::new(engine.clone());
+        let height = 10;
+        let fees = 5_000;
+        let mut coinbase = tx_profile(0);
+        coinbase.amount = SovereignInvariants::calculate_expected_reward(height) + fees;
+
+        assert!(bridge.validate_block_transaction(coinbase.clone(), 0, height, fees).unwrap().approved);
+        // Claiming fees the block didn't collect is still an over-payment
+        assert!(matches!(
+            bridge.validate_block_transaction(coinbase.clone(), 0, height, fees - 1),
+            Err(AxiomError::InvalidBlockReward { .. })
+        ));
+
+        // Distribution rounding within the configured tolerance is accepted
+        let config = BridgeConfig {
+            coinbase_reward_tolerance: 1,
+            ..Default::default()
+        };
+        let tolerant = AIGuardianBridge::try_new_with_config(engine, config).unwrap();
+        assert!(tolerant.validate_block_transaction(coinbase.clone(), 0, height, fees - 1).unwrap().approved);
+        assert!(tolerant.validate_block_transaction(coinbase, 0, height, fees - 2).is_err());
+    }
+
+    #[test]
+    fn test_update_metrics_ignores_replayed_heights() {
+        let mut controller = ConsensusAIController::with_profile(NetworkProfile::Mainnet);
+        let mut blocks = block_series(5, 1_800);
+        blocks.insert(3, blocks[2].clone());
+        controller.update_metrics(&blocks).unwrap();
+        assert_eq!(controller.block_time_history.len(), 5);
+        assert_eq!(controller.hashrate_history.len(), 5);
+
+        // Re-sending an overlapping window only adds the new tail
+        controller.update_metrics(&block_series(7, 1_800)).unwrap();
+        assert_eq!(controller.block_time_history.len(), 7);
+        assert_eq!(controller.last_ingested_height, Some(7));
+    }
+
+    #[test]
+    fn test_streaming_moments_match_batch_across_evictions() {
+        let tuning = ControllerTuning {
+            history_capacity: 20,
+            min_hashrate_samples: 10,
+            ..Default::default()
+        };
+        let mut controller = ConsensusAIController::with_tuning(NetworkProfile::Mainnet, tuning);
+        let mut blocks = block_series(250, 0);
+        let mut timestamp = 1_700_000_000;
+        for (i, block) in blocks.iter_mut().enumerate() {
+            block.block_time = 1_200 + (i as u64 * 7_919) % 1_500;
+            timestamp += block.block_time;
+            block.timestamp = timestamp;
+            block.hashrate_estimate = 1e12 * (1.0 + ((i * 31) % 17) as f64 / 10.0);
+        }
+
+        let batch = |samples: Vec<f64>| {
+            let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+            let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / samples.len() as f64;
+            (mean, variance)
+        };
+        for chunk in blocks.chunks(9) {
+            controller.update_metrics(chunk).unwrap();
+
+            let times = controller.block_time_history.iter().map(|&t| t as f64).collect();
+            let (mean, variance) = batch(times);
+            assert!((controller.block_time_moments.mean - mean).abs() < 1e-6);
+            assert!((controller.block_time_moments.variance() - variance).abs() < 1e-6 * variance.max(1.0));
+
+            let (mean, variance) = batch(controller.hashrate_history.clone());
+            assert!((controller.hashrate_moments.mean - mean).abs() < 1e-6 * mean);
+            assert!((controller.hashrate_moments.variance() - variance).abs() < 1e-6 * variance.max(1.0));
+        }
+        assert_eq!(controller.block_time_moments.count, 20);
+        assert_eq!(controller.hashrate_moments.count, 20);
+    }
+
+    struct CountingEngine(std::sync::atomic::AtomicUsize);
+
+    impl ThreatAssessor for CountingEngine {
+        fn assess_transaction_threat(
+            &self,
+            profile: &TransactionRiskProfile,
+            current_block_height: u64,
+        ) -> Result<ThreatAssessment, AxiomError> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            ScoreByAmount.assess_transaction_threat(profile, current_block_height)
+        }
+    }
+
+    #[test]
+    fn test_reorg_reentry_reuses_assessment_within_epoch() {
+        let engine = Arc::new(CountingEngine(Default::default()));
+        let config = BridgeConfig {
+            assessment_cache_capacity: Some(16),
+            ..voting_config()
+        };
+        let bridge = AIGuardianBridge::new_with_assessor(engine.clone(), config).unwrap();
+
+        let first = bridge.validate_transaction_with_guardian(tx_profile(2_000), 100).unwrap();
+        assert_eq!(engine.0.load(Ordering::SeqCst), 1);
+
+        // Orphaned by a reorg and re-entering: parameters unchanged, cache hit
+        let reentered = bridge.validate_transaction_with_guardian(tx_profile(2_000), 101).unwrap();
+        assert_eq!(engine.0.load(Ordering::SeqCst), 1);
+        assert_eq!(reentered.approved, first.approved);
+        assert_eq!(
+            reentered.threat_assessment.threat_score,
+            first.threat_assessment.threat_score
+        );
+
+        // A parameter change starts a new epoch: assessed afresh
+        let proposal = bridge.manual_proposal(1_010, 1_000_000, 1_000, 102).unwrap();
+        approve_by_vote(&bridge, &proposal.proposal_id);
+        bridge.apply_consensus_optimization(&proposal).unwrap();
+        bridge.validate_transaction_with_guardian(tx_profile(2_000), 103).unwrap();
+        assert_eq!(engine.0.load(Ordering::SeqCst), 2);
+
+        // Reusing a hash for different contents doesn't reuse the assessment
+        let mut forged = tx_profile(2_000);
+        forged.amount *= 10;
+        bridge.validate_transaction_with_guardian(forged, 104).unwrap();
+        assert_eq!(engine.0.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_cache_hit_not_counted_in_threat_window() {
+        let config = BridgeConfig {
+            assessment_cache_capacity: Some(16),
+            rolling_threat_breaker: Some(RollingThreatBreaker {
+                window: 2,
+                mean_threat_threshold: 0.0,
+            }),
+            ..Default::default()
+        };
+        let bridge = AIGuardianBridge::new_with_assessor(Arc::new(ScoreByAmount), config).unwrap();
+
+        // The same transaction re-entering fills the window only once
+        bridge.validate_transaction_with_guardian(tx_profile(2_000), 100).unwrap();
+        bridge.validate_transaction_with_guardian(tx_profile(2_000), 101).unwrap();
+        assert_eq!(bridge.threat_window.read().scores.len(), 1);
+        assert!(!bridge.full_snapshot().circuit_breaker_active);
+    }
+
+    struct FailingEngine;
+
+    impl ThreatAssessor for FailingEngine {
+        fn assess_transaction_threat(
+            &self,
+            _profile: &TransactionRiskProfile,
+            _current_block_height: u64,
+        ) -> Result<ThreatAssessment, AxiomError> {
+            Err(AxiomError::AIModelError("model offline".to_string()))
+        }
+    }
+
+    #[test]
+    fn test_engine_failure_fail_open_degrades() {
+        let bridge = AIGuardianBridge::new_with_assessor(Arc::new(FailingEngine), BridgeConfig::default()).unwrap();
+
+        let decision = bridge.validate_transaction_with_guardian(tx_profile(2_000), 100).unwrap();
+        assert!(decision.approved);
+        assert!(decision.degraded);
+        assert!(matches!(decision.action, GuardianAction::AcceptMonitored));
+
+        // Manifest checks still apply without the engine, with the same veto
+        // as the engine-backed path
+        let decision = bridge.validate_transaction_with_guardian(tx_profile(1), 100).unwrap();
+        assert!(!decision.approved);
+        assert!(decision.degraded);
+        let healthy = AIGuardianBridge::new(Arc::new(MultiLayerSecurityEngine::new(Default::default())));
+        let reference = healthy.validate_transaction_with_guardian(tx_profile(1), 100).unwrap();
+        assert!(!reference.degraded);
+        assert_eq!(decision.veto_reason, reference.veto_reason);
+        assert_eq!(decision.veto_category, Some(VetoCategory::MinimumFee));
+        assert_eq!(decision.veto_category, reference.veto_category);
+    }
+
+    #[test]
+    fn test_engine_failure_fail_closed_rejects() {
+        let config = BridgeConfig {
+            engine_failure_policy: EngineFailurePolicy::FailClosed,
+            ..Default::default()
+        };
+        let bridge = AIGuardianBridge::new_with_assessor(Arc::new(FailingEngine), config).unwrap();
+
+        let decision = bridge.validate_transaction_with_guardian(tx_profile(2_000), 100).unwrap();
+        assert!(!decision.approved);
+        assert!(decision.degraded);
+        assert!(matches!(decision.action, GuardianAction::Reject));
+        assert!(decision.veto_reason.unwrap().contains("fail-closed"));
+        assert_eq!(bridge.get_guardian_stats().guardian_vetoes, 1);
+    }
+
+    #[test]
+    fn test_block_time_overshoot_corrected_by_difficulty() {
+        let bridge = AIGuardianBridge::new(Arc::new(MultiLayerSecurityEngine::new(Default::default())));
+
+        // Blocks ~17% fast with flat hashrate: no slow drift for VDF to absorb
+        let proposal = bridge.generate_consensus_optimization(144, &block_series(144, 1_500)).unwrap();
+        assert!(proposal.difficulty_change_percent > 1.0);
+        assert!(proposal.vdf_change_percent.abs() < 0.5);
+
+        // With a rising hashrate, VDF takes part of the error but the two
+        // shares still add up to the single shared correction
+        let mut controller = ConsensusAIController::with_profile(NetworkProfile::Mainnet);
+        let mut blocks = block_series(144, 1_500);
+        for (i, block) in blocks.iter_mut().enumerate() {
+            block.hashrate_estimate = 1e12 * (1.0 + i as f64 / 1_000.0);
+        }
+        controller.update_metrics(&blocks).unwrap();
+        let budget = controller.block_time_budget().unwrap();
+        let total = (1_800.0 - 1_500.0) / 1_800.0;
+        assert!(budget.vdf_error > 0.0);
+        assert!(budget.vdf_error <= total * controller.tuning.vdf_budget_share + 1e-12);
+        assert!((budget.difficulty_error + budget.vdf_error - total).abs() < 1e-12);
+    }
+
+    #[derive(Default)]
+    struct CountingSink {
+        appended: std::sync::atomic::AtomicUsize,
+    }
+
+    impl AuditSink for CountingSink {
+        fn append(&self, _entry: &AuditEntry) -> Result<(), AxiomError> {
+            self.appended.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_audit_sink_receives_every_entry() {
+        let sink = Arc::new(CountingSink::default());
+        let bridge = AIGuardianBridge::try_new_with_config(
+            Arc::new(MultiLayerSecurityEngine::new(Default::default())),
+            voting_config(),
+        ).unwrap()
+        .with_audit_sink(sink.clone());
+
+        // Three decisions, one flag change, one proposal voted on and applied
+        for fee in [2_000, 1, 5_000] {
+            bridge.validate_transaction_with_guardian(tx_profile(fee), 100).unwrap();
+        }
+        bridge.set_auto_pilot_mode(true);
+        let proposal = bridge.manual_proposal(1010, 1_000_000, 1000, 100).unwrap();
+        approve_by_vote(&bridge, &proposal.proposal_id);
+        bridge.apply_consensus_optimization(&proposal).unwrap();
+
+        assert_eq!(sink.appended.load(std::sync::atomic::Ordering::SeqCst), 3 + 1 + 4);
+        // A write-only sink has nothing to read back
+        assert!(bridge.audit_log().is_empty());
+        assert_eq!(bridge.full_snapshot().audit_entries, 0);
+    }
+
+    #[test]
+    fn test_mempool_congestion_uses_configured_capacity() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let default_bridge = AIGuardianBridge::new(engine.clone());
+        let config = BridgeConfig {
+            mempool_capacity: Some(2_000),
+            ..Default::default()
+        };
+        let large_bridge = AIGuardianBridge::try_new_with_config(engine, config).unwrap();
+
+        for _ in 0..10 {
+            default_bridge.record_mempool_size(1_000);
+            large_bridge.record_mempool_size(1_000);
+        }
+
+        assert!((default_bridge.full_snapshot().mempool_congestion - 1.0).abs() < 1e-9);
+        assert!((large_bridge.full_snapshot().mempool_congestion - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_gas_follows_mempool_bytes_not_count() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let small = AIGuardianBridge::new(engine.clone());
+        let large = AIGuardianBridge::new(engine);
+
+        // Same 600 pending transactions: 120 KB vs 900 KB of a 1 MB block
+        for _ in 0..10 {
+            small.record_mempool_usage(600, 600 * 200);
+            large.record_mempool_usage(600, 600 * 1_500);
+        }
+        assert!((small.full_snapshot().mempool_congestion - 0.12).abs() < 1e-9);
+        assert!((large.full_snapshot().mempool_congestion - 0.9).abs() < 1e-9);
+
+        let blocks = block_series(144, SovereignInvariants::TARGET_BLOCK_TIME_SECS);
+        let quiet = small.generate_consensus_optimization(144, &blocks).unwrap();
+        let busy = large.generate_consensus_optimization(144, &blocks).unwrap();
+        assert!(busy.proposed_min_gas > busy.current_min_gas);
+        assert!(busy.proposed_min_gas > quiet.proposed_min_gas);
+    }
+
+    /// Scores each transaction's threat as its amount (0-100)
+    struct ScoreByAmount;
+
+    impl ThreatAssessor for ScoreByAmount {
+        fn assess_transaction_threat(
+            &self,
+            profile: &TransactionRiskProfile,
+            _current_block_height: u64,
+        ) -> Result<ThreatAssessment, AxiomError> {
+            let mut assessment = unassessed("scored by amount".to_string());
+            assessment.threat_score = profile.amount as f64;
+            Ok(assessment)
+        }
+    }
+
+    #[test]
+    fn test_breaker_dry_run_reports_trip_point() {
+        let config = BridgeConfig {
+            rolling_threat_breaker: Some(RollingThreatBreaker {
+                window: 3,
+                mean_threat_threshold: 75.0,
+            }),
+            ..Default::default()
+        };
+        let bridge = AIGuardianBridge::new_with_assessor(Arc::new(ScoreByAmount), config).unwrap();
+
+        let profiles: Vec<_> = [10, 20, 60, 80, 90, 95]
+            .iter()
+            .map(|&amount| {
+                let mut profile = tx_profile(2_000);
+                profile.amount = amount;
+                profile
+            })
+            .collect();
+
+        // Mean of 60/80/90 is the first to reach 75
+        let trips = bridge.would_trip_breaker(&profiles, 100);
+        assert_eq!(trips.len(), 1);
+        assert_eq!(trips[0].0, 4);
+        assert!(trips[0].1.contains("Rolling threat mean"));
+
+        let snapshot = bridge.full_snapshot();
+        assert!(!snapshot.circuit_breaker_active);
+        assert_eq!(snapshot.stats.total_ai_decisions, 0);
+
+        // Live validation trips at the same transaction
+        for profile in &profiles[..4] {
+            bridge.validate_transaction_with_guardian(profile.clone(), 100).unwrap();
+        }
+        assert!(!bridge.full_snapshot().circuit_breaker_active);
+        bridge.validate_transaction_with_guardian(profiles[4].clone(), 100).unwrap();
+        assert!(bridge.full_snapshot().circuit_breaker_active);
+    }
+
+    #[test]
+    fn test_clamped_difficulty_does_not_wind_up() {
+        let mut controller = ConsensusAIController::with_profile(NetworkProfile::Mainnet);
+
+        // Blocks persistently twice as slow: every step hits the clamp
+        let mut height = 0;
+        let mut timestamp = 1_700_000_000;
+        let mut feed = |controller: &mut ConsensusAIController, block_time: u64| {
+            let blocks: Vec<_> = block_series(1_000, block_time)
+                .into_iter()
+                .map(|mut block| {
+                    height += 1;
+                    timestamp += block_time;
+                    block.height = height;
+                    block.timestamp = timestamp;
+                    block
+                })
+                .collect();
+            controller.update_metrics(&blocks).unwrap();
+            let next = controller.calculate_difficulty_adjustment().unwrap();
+            controller.current_difficulty = next;
+            next
+        };
+
+        for _ in 0..30 {
+            feed(&mut controller, 3_600);
+        }
+        assert!(controller.difficulty_pid.integral.abs() < 1.0);
+
+        // Once blocks are back on target, difficulty must settle rather than
+        // keep falling on accumulated integral
+        let resolved = feed(&mut controller, SovereignInvariants::TARGET_BLOCK_TIME_SECS);
+        let mut settled = resolved;
+        for _ in 0..20 {
+            settled = feed(&mut controller, SovereignInvariants::TARGET_BLOCK_TIME_SECS);
+        }
+        let drift = (settled as f64 - resolved as f64).abs() / resolved as f64;
+        assert!(drift <= 0.05, "difficulty drifted {:.1}% after recovery", drift * 100.0);
+    }
+
+    #[test]
+    fn test_fee_ratio_band_flags_outliers() {
+        let config = BridgeConfig {
+            fee_ratio_band: Some(FeeRatioBand {
+                min_ratio: 1e-8,
+                max_ratio: 0.1,
+            }),
+            ..Default::default()
+        };
+        let bridge = AIGuardianBridge::try_new_with_config(
+            Arc::new(MultiLayerSecurityEngine::new(Default::default())),
+            config,
+        ).unwrap();
+
+        // 100 AXM paying 2000: ordinary
+        let normal = bridge.validate_transaction_with_guardian(tx_profile(2_000), 100).unwrap();
+        assert_eq!(normal.fee_anomaly, None);
+
+        // Dust transfer paying 40% of its value in fees
+        let mut dust = tx_profile(2_000);
+        dust.amount = 5_000;
+        let decision = bridge.validate_transaction_with_guardian(dust, 100).unwrap();
+        assert!(matches!(decision.fee_anomaly, Some(FeeAnomaly::Overpay { .. })));
+
+        // 10M AXM moved for the minimum fee
+        let mut whale = tx_profile(SovereignInvariants::MIN_TRANSACTION_FEE);
+        whale.amount = 10_000_000_00000000;
+        let decision = bridge.validate_transaction_with_guardian(whale, 100).unwrap();
+        assert!(matches!(decision.fee_anomaly, Some(FeeAnomaly::Underpay { .. })));
+
+        // Flags are informational: nothing was vetoed
+        assert_eq!(bridge.get_guardian_stats().guardian_vetoes, 0);
+    }
+
+    #[test]
+    fn test_warm_up_suppresses_proposals() {
+        let config = BridgeConfig {
+            warm_up_blocks: 200,
+            ..Default::default()
+        };
+        let bridge = AIGuardianBridge::try_new_with_config(
+            Arc::new(MultiLayerSecurityEngine::new(Default::default())),
+            config,
+        ).unwrap();
+
+        let blocks = block_series(300, 1_700);
+        // A short slice is refused without ingesting any of it
+        assert!(matches!(
+            bridge.generate_consensus_optimization(10, &blocks[..10]),
+            Err(AxiomError::WarmingUp { blocks_remaining: 200 })
+        ));
+        assert_eq!(bridge.consensus_ai.read().last_ingested_height, None);
+
+        assert!(matches!(
+            bridge.generate_consensus_optimization(144, &blocks[..144]),
+            Err(AxiomError::WarmingUp { blocks_remaining: 56 })
+        ));
+        // Re-sending the same blocks doesn't count twice
+        assert!(matches!(
+            bridge.generate_consensus_optimization(144, &blocks[..144]),
+            Err(AxiomError::WarmingUp { blocks_remaining: 56 })
+        ));
+
+        assert!(bridge.generate_consensus_optimization(300, &blocks[156..]).is_ok());
+    }
+
+    /// Escalates every transaction to the guardian for manual review
+    struct EscalatingEngine;
+
+    impl ThreatAssessor for EscalatingEngine {
+        fn assess_transaction_threat(
+            &self,
+            _profile: &TransactionRiskProfile,
+            _current_block_height: u64,
+        ) -> Result<ThreatAssessment, AxiomError> {
+            let mut assessment = unassessed("needs a human".to_string());
+            assessment.recommended_action = SecurityAction::EscalateToGuardian {
+                threat_level: RiskLevel::High,
+            };
+            Ok(assessment)
+        }
+    }
+
+    #[test]
+    fn test_preview_action_for_every_risk_level() {
+        let config = BridgeConfig {
+            escalation_fallbacks: BTreeMap::from([
+                (RiskLevel::Low, EscalationFallback::AcceptMonitored),
+                (RiskLevel::Medium, EscalationFallback::Quarantine { duration_blocks: 5_000 }),
+                (RiskLevel::High, EscalationFallback::AutoReject),
+            ]),
+            ..Default::default()
+        };
+        let bridge = AIGuardianBridge::new_with_assessor(Arc::new(ScoreByAmount), config).unwrap();
+
+        let review = |threat_level| GuardianAction::RequireManualReview { threat_level };
+        let quarantine = GuardianAction::Quarantine {
+            duration_blocks: SovereignInvariants::MAX_QUARANTINE_BLOCKS,
+        };
+        let expected = [
+            (RiskLevel::Minimal, review(RiskLevel::Minimal), review(RiskLevel::Minimal)),
+            (RiskLevel::Low, GuardianAction::AcceptMonitored, GuardianAction::AcceptMonitored),
+            (RiskLevel::Medium, quarantine.clone(), quarantine),
+            (RiskLevel::High, GuardianAction::AutoReject, GuardianAction::AutoReject),
+            (RiskLevel::Critical, review(RiskLevel::Critical), review(RiskLevel::Critical)),
+            // Only auto-pilot rejects catastrophic escalations outright
+            (RiskLevel::Catastrophic, review(RiskLevel::Catastrophic), GuardianAction::AutoReject),
+        ];
+        for (level, manual, auto_pilot) in expected {
+            assert_eq!(
+                format!("{:?}", bridge.preview_action_for(50.0, level, false)),
+                format!("{:?}", manual),
+                "{:?} without auto-pilot",
+                level
+            );
+            assert_eq!(
+                format!("{:?}", bridge.preview_action_for(50.0, level, true)),
+                format!("{:?}", auto_pilot),
+                "{:?} with auto-pilot",
+                level
+            );
+        }
+
+        // An attached reviewer takes every escalation
+        bridge.set_manual_override(true);
+        assert!(matches!(
+            bridge.preview_action_for(99.0, RiskLevel::High, true),
+            GuardianAction::RequireManualReview { threat_level: RiskLevel::High }
+        ));
+    }
+
+    #[test]
+    fn test_review_queue_overflow_policies() {
+        let flood = |overflow: ReviewQueueOverflow| {
+            let config = BridgeConfig {
+                review_queue_capacity: Some(2),
+                review_queue_overflow: overflow,
+                ..Default::default()
+            };
+            let bridge = AIGuardianBridge::new_with_assessor(Arc::new(EscalatingEngine), config).unwrap();
+            let decisions: Vec<_> = (0..3)
+                .map(|i| {
+                    let mut profile = tx_profile(2_000);
+                    profile.hash = format!("tx_{}", i);
+                    bridge.validate_transaction_with_guardian(profile, 100 + i).unwrap()
+                })
+                .collect();
+            (bridge, decisions)
+        };
+
+        // Newest refused: the first two stay queued
+        let (bridge, decisions) = flood(ReviewQueueOverflow::RejectNewest);
+        assert!(matches!(decisions[2].action, GuardianAction::Reject));
+        assert!(!decisions[2].approved);
+        assert_eq!(decisions[2].veto_category, Some(VetoCategory::ReviewQueueFull));
+        let stats = bridge.get_guardian_stats();
+        assert_eq!((stats.pending_reviews, stats.review_queue_overflows), (2, 1));
+        assert!(bridge.complete_review("tx_0").is_some());
+
+        // Oldest evicted: the newcomer is queued in its place
+        let (bridge, decisions) = flood(ReviewQueueOverflow::RejectOldest);
+        assert!(matches!(decisions[2].action, GuardianAction::RequireManualReview { .. }));
+        let stats = bridge.get_guardian_stats();
+        assert_eq!((stats.pending_reviews, stats.review_queue_overflows), (2, 1));
+        assert!(bridge.complete_review("tx_0").is_none());
+        assert!(bridge.complete_review("tx_2").is_some());
+        assert!(bridge.audit_log().iter().any(|entry| matches!(
+            &entry.record,
+            AuditRecord::ReviewExpired { tx_hash, resolution: GuardianAction::Reject, .. } if tx_hash == "tx_0"
+        )));
+
+        let (bridge, decisions) = flood(ReviewQueueOverflow::AutoReject);
+        assert!(matches!(decisions[2].action, GuardianAction::AutoReject));
+        assert_eq!(bridge.get_guardian_stats().pending_reviews, 2);
+    }
+
+    #[test]
+    fn test_stats_serialization_is_deterministic() {
+        assert!(VetoCategory::ALL.iter().enumerate().all(|(i, category)| *category as usize == i));
+
+        let bridge = AIGuardianBridge::new_with_assessor(
+            Arc::new(CatastrophicEngine),
+            BridgeConfig::default(),
+        )
+        .unwrap();
+        bridge.validate_transaction_with_guardian(tx_profile(2_000), 100).unwrap();
+        bridge.validate_transaction_with_guardian(tx_profile(1), 101).unwrap();
+
+        let stats = bridge.get_guardian_stats();
+        assert_eq!(stats.veto_categories.len(), VetoCategory::ALL.len());
+        assert_eq!(stats.veto_categories["ai_rejected"], 1);
+        assert_eq!(stats.veto_categories["minimum_fee"], 1);
+
+        let first = serde_json::to_string(&stats).unwrap();
+        let second = serde_json::to_string(&bridge.get_guardian_stats()).unwrap();
+        assert_eq!(first, second);
+        // Keys come out sorted
+        assert!(first.find("\"ai_rejected\"").unwrap() < first.find("\"minimum_fee\"").unwrap());
+    }
+
+    #[test]
+    fn test_review_timeouts_trip_breaker() {
+        let config = BridgeConfig {
+            review_timeout_breaker: Some(ReviewTimeoutBreaker {
+                max_timeouts: 3,
+                window_blocks: 50,
+            }),
+            ..Default::default()
+        };
+        let bridge = AIGuardianBridge::new_with_assessor(Arc::new(EscalatingEngine), config).unwrap();
+        let escalate = |i: u64| {
+            let mut profile = tx_profile(2_000);
+            profile.hash = format!("tx_{}", i);
+            bridge.validate_transaction_with_guardian(profile, 100 + i).unwrap();
+        };
+
+        // Two timeouts, then an operator answers: the run starts over
+        escalate(0);
+        escalate(1);
+        assert_eq!(bridge.expire_stale_reviews(120, 10).len(), 2);
+        escalate(2);
+        assert!(bridge.complete_review("tx_2").is_some());
+        escalate(3);
+        assert_eq!(bridge.expire_stale_reviews(130, 10).len(), 1);
+        assert!(!bridge.full_snapshot().circuit_breaker_active);
+
+        // Two more unanswered: three in a row within the window
+        escalate(4);
+        escalate(5);
+        assert_eq!(bridge.expire_stale_reviews(140, 10).len(), 2);
+        let snapshot = bridge.full_snapshot();
+        assert!(snapshot.circuit_breaker_active);
+        assert_eq!(snapshot.circuit_breaker_category, Some(BreakerReason::ReviewTimeouts));
+        assert!(bridge.drain_events().iter().any(|event| matches!(
+            event,
+            GuardianEvent::CircuitBreakerActivated {
+                category: BreakerReason::ReviewTimeouts,
+                severity: BreakerSeverity::Low,
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn test_stale_reviews_expire_to_configured_default() {
+        let bridge = AIGuardianBridge::new_with_assessor(Arc::new(EscalatingEngine), BridgeConfig::default()).unwrap();
+
+        let decision = bridge.validate_transaction_with_guardian(tx_profile(2_000), 100).unwrap();
+        assert!(matches!(decision.action, GuardianAction::RequireManualReview { .. }));
+        assert_eq!(bridge.get_guardian_stats().pending_reviews, 1);
+
+        // Still inside the 10-block window
+        assert!(bridge.expire_stale_reviews(109, 10).is_empty());
+        assert_eq!(bridge.get_guardian_stats().pending_reviews, 1);
+
+        let expired = bridge.expire_stale_reviews(110, 10);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].tx_hash, "tx_shadow");
+        assert_eq!(bridge.get_guardian_stats().pending_reviews, 0);
+        assert!(matches!(
+            bridge.audit_log().last().unwrap().record,
+            AuditRecord::ReviewExpired { resolution: GuardianAction::Reject, expired_block: 110, .. }
+        ));
+
+        // Lenient configuration resolves to monitored acceptance instead
+        let config = BridgeConfig {
+            review_timeout_resolution: ReviewTimeoutResolution::AcceptMonitored,
+            ..Default::default()
+        };
+        let lenient = AIGuardianBridge::new_with_assessor(Arc::new(EscalatingEngine), config).unwrap();
+        lenient.validate_transaction_with_guardian(tx_profile(2_000), 100).unwrap();
+        lenient.expire_stale_reviews(200, 10);
+        assert!(matches!(
+            lenient.audit_log().last().unwrap().record,
+            AuditRecord::ReviewExpired { resolution: GuardianAction::AcceptMonitored, .. }
+        ));
+    }
+
+    /// Reports a catastrophic threat that requires a guardian override
+    struct CatastrophicEngine;
+
+    impl ThreatAssessor for CatastrophicEngine {
+        fn assess_transaction_threat(
+            &self,
+            _profile: &TransactionRiskProfile,
+            _current_block_height: u64,
+        ) -> Result<ThreatAssessment, AxiomError> {
+            let mut assessment = unassessed("catastrophic".to_string());
+            assessment.risk_level = RiskLevel::Catastrophic;
+            assessment.guardian_override_required = true;
+            assessment.recommended_action = SecurityAction::Reject {
+                reason: "catastrophic".to_string(),
+            };
+            Ok(assessment)
+        }
+    }
+
+    #[test]
+    fn test_manual_override_supersedes_auto_pilot() {
+        let bridge = AIGuardianBridge::new_with_assessor(
+            Arc::new(CatastrophicEngine),
+            BridgeConfig::default(),
+        )
+        .unwrap();
+
+        bridge.set_auto_pilot_mode(true);
+        let decision = bridge.validate_transaction_with_guardian(tx_profile(2_000), 100).unwrap();
+        assert!(matches!(decision.action, GuardianAction::AutoReject));
+
+        // Engaging the override turns auto-pilot off, and it stays off
+        bridge.set_manual_override(true);
+        assert!(!bridge.get_guardian_stats().auto_pilot_mode);
+        bridge.set_auto_pilot_mode(true);
+        assert!(!bridge.get_guardian_stats().auto_pilot_mode);
+
+        // Even if both flags end up set, the override wins, but a
+        // catastrophic rejection is held for review rather than approved
+        bridge.guardian_state.write().auto_pilot_mode = true;
+        let decision = bridge.validate_transaction_with_guardian(tx_profile(2_000), 101).unwrap();
+        assert!(matches!(
+            decision.action,
+            GuardianAction::RequireManualReview { threat_level: RiskLevel::Catastrophic }
+        ));
+        assert!(!decision.approved);
+        assert_eq!(decision.veto_category, Some(VetoCategory::AiRejected));
+        assert_eq!(bridge.get_guardian_stats().pending_reviews, 1);
+    }
+
+    const V1_PROPOSAL_JSON: &str = r#"{
+        "proposal_id": "ai_consensus_1000_0000abcd",
+        "block_height": 1000,
+        "timestamp": 1700000000,
+        "current_difficulty": 1000,
+        "proposed_difficulty": 1050,
+        "difficulty_change_percent": 5.0,
+        "current_vdf": 1000000,
+        "proposed_vdf": 1000000,
+        "vdf_change_percent": 0.0,
+        "current_min_gas": 1000,
+        "proposed_min_gas": 1000,
+        "gas_change_percent": 0.0,
+        "avg_block_time_last_144": 1750.0,
+        "hashrate_trend": 0.01,
+        "mempool_congestion": 0.2,
+        "network_health_score": 0.9,
+        "ai_confidence": 0.85,
+        "expected_improvement": 0.1,
+        "guardian_pre_approved": true,
+        "requires_voting": false
+    }"#;
+
+    #[test]
+    fn test_deserialize_v1_proposal_fills_defaults() {
+        let proposal = ConsensusOptimizationProposal::deserialize_compat(V1_PROPOSAL_JSON).unwrap();
+
+        assert_eq!(proposal.schema_version, PROPOSAL_SCHEMA_VERSION);
+        assert_eq!(proposal.proposal_id, "ai_consensus_1000_0000abcd");
+        assert_eq!(proposal.proposed_difficulty, 1050);
+        assert_eq!(proposal.urgency, 0.0);
+        assert!(!proposal.is_urgent(0.1));
+        assert!(proposal.pre_approval.bounds_ok);
+        assert_eq!(proposal.pre_approval.checked_at_block, 1000);
+
+        // A legacy pre-approval the manifest disagrees with doesn't survive
+        let out_of_bounds =
+            V1_PROPOSAL_JSON.replace(r#""proposed_difficulty": 1050"#, r#""proposed_difficulty": 5000"#);
+        let proposal = ConsensusOptimizationProposal::deserialize_compat(&out_of_bounds).unwrap();
+        assert!(!proposal.pre_approval.bounds_ok);
+        assert!(proposal.pre_approval.notes[0].starts_with("difficulty: "));
+        assert!(!proposal.pre_approval.notes[0].ends_with("within manifest bounds"));
+    }
+
+    #[test]
+    fn test_deserialize_compat_round_trips_current_and_rejects_unknown() {
+        let mut proposal = ConsensusOptimizationProposal::deserialize_compat(V1_PROPOSAL_JSON).unwrap();
+        proposal.urgency = 0.7;
+
+        let json = serde_json::to_string(&proposal).unwrap();
+        let decoded = ConsensusOptimizationProposal::deserialize_compat(&json).unwrap();
+        assert_eq!(decoded.schema_version, PROPOSAL_SCHEMA_VERSION);
+        assert_eq!(decoded.urgency, 0.7);
+
+        let future = V1_PROPOSAL_JSON.replacen('{', r#"{ "schema_version": 99,"#, 1);
+        assert!(matches!(
+            ConsensusOptimizationProposal::deserialize_compat(&future),
+            Err(AxiomError::SerializationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_hashrate_cliff_raises_alert_and_low_severity_breaker() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let config = BridgeConfig {
+            hashrate_drop_rule: Some(HashrateDropRule {
+                short_window: 6,
+                long_window: 144,
+                max_drop_fraction: 0.3,
+                trip_breaker: true,
+            }),
+            ..Default::default()
+        };
+        let bridge = AIGuardianBridge::try_new_with_config(engine, config).unwrap();
+
+        // A steady baseline raises nothing
+        let mut blocks = block_series(200, SovereignInvariants::TARGET_BLOCK_TIME_SECS);
+        bridge.generate_consensus_optimization(200, &blocks).unwrap();
+        assert!(bridge.drain_events().is_empty());
+
+        // Then 60% of the hashrate disappears
+        for block in blocks.iter_mut() {
+            block.height += 200;
+            block.timestamp += 200 * SovereignInvariants::TARGET_BLOCK_TIME_SECS;
+            block.hashrate_estimate *= 0.4;
+        }
+        let result = bridge.generate_consensus_optimization(400, &blocks[..OPTIMIZATION_WINDOW]);
+        assert!(result.is_err());
+
+        let alerts: Vec<_> = bridge
+            .drain_events()
+            .into_iter()
+            .filter_map(|event| match event {
+                GuardianEvent::HashrateDropAlert { drop_fraction, .. } => Some(drop_fraction),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(alerts.len(), 1, "alert fires once per cliff");
+        assert!(alerts[0] > 0.3);
+
+        // Low severity pauses optimization but not transaction validation
+        let snapshot = bridge.full_snapshot();
+        assert!(snapshot.circuit_breaker_active);
+        assert_eq!(snapshot.circuit_breaker_severity, Some(BreakerSeverity::Low));
+        assert!(bridge.validate_transaction_with_guardian(tx_profile(2_000), 400).is_ok());
+
+        // An operator trip escalates it
+        bridge.activate_circuit_breaker(401, "operator".to_string()).unwrap();
+        assert_eq!(bridge.full_snapshot().circuit_breaker_severity, Some(BreakerSeverity::High));
+        assert!(bridge.validate_transaction_with_guardian(tx_profile(2_000), 401).is_err());
+    }
+
+    #[test]
+    fn test_generated_proposal_records_pre_approval_checks() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let bridge = AIGuardianBridge::new(engine);
+
+        let blocks = block_series(144, SovereignInvariants::TARGET_BLOCK_TIME_SECS / 2);
+        let proposal = bridge.generate_consensus_optimization(777, &blocks).unwrap();
+
+        let pre_approval = &proposal.pre_approval;
+        assert!(pre_approval.bounds_ok);
+        assert_eq!(pre_approval.checked_at_block, 777);
+        assert_eq!(pre_approval.notes.len(), 3);
+        assert!(pre_approval.notes.iter().all(|note| note.ends_with("within manifest bounds")));
+
+        // An out-of-bounds change is recorded with its violation
+        let current = bridge.consensus_parameters();
+        let rejected = PreApproval::evaluate(
+            current,
+            ConsensusParameters {
+                difficulty: current.difficulty * 10,
+                ..current
+            },
+            778,
+        );
+        assert!(!rejected.bounds_ok);
+        assert!(rejected.notes[0].starts_with("difficulty: "));
+        assert!(!rejected.notes[0].ends_with("within manifest bounds"));
+    }
+
+    #[test]
+    fn test_concurrent_generation_is_rejected() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let bridge = AIGuardianBridge::new(engine);
+        let blocks = block_series(144, SovereignInvariants::TARGET_BLOCK_TIME_SECS);
+
+        // A generation in flight blocks a second one without touching PID state
+        let in_flight = bridge.generation_guard.lock();
+        assert!(matches!(
+            bridge.generate_consensus_optimization(144, &blocks),
+            Err(AxiomError::ProposalInProgress)
+        ));
+        assert_eq!(bridge.consensus_ai.read().blocks_ingested, 0);
+        drop(in_flight);
+
+        // Racing callers either win or get the busy error, never anything else
+        let barrier = std::sync::Barrier::new(2);
+        let results: Vec<_> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..2u64)
+                .map(|i| {
+                    let (bridge, barrier, blocks) = (&bridge, &barrier, &blocks);
+                    scope.spawn(move || {
+                        barrier.wait();
+                        bridge.generate_consensus_optimization(1_000 + i, blocks)
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+        });
+
+        assert!(results.iter().any(|result| result.is_ok()));
+        assert!(results
+            .iter()
+            .all(|result| matches!(result, Ok(_) | Err(AxiomError::ProposalInProgress))));
+    }
+
+    #[test]
+    fn test_unknown_action_is_handled_as_manual_review() {
+        let unknown = GuardianAction::Unknown;
+        assert_eq!(unknown.to_response_code(), 409);
+        assert!(!unknown.is_terminal());
+        assert!(matches!(
+            unknown.resolve_unknown(),
+            GuardianAction::RequireManualReview { threat_level: RiskLevel::Critical }
+        ));
+        assert!(matches!(GuardianAction::Reject.resolve_unknown(), GuardianAction::Reject));
+    }
+
+    #[test]
+    fn test_deserialize_unknown_guardian_action() {
+        let action: GuardianAction =
+            serde_json::from_str(r#"{"type": "Throttle", "max_tx_per_block": 5}"#).unwrap();
+        assert!(matches!(action, GuardianAction::Unknown));
+
+        let known: GuardianAction = serde_json::from_str(r#"{"type": "Quarantine", "duration_blocks": 6}"#).unwrap();
+        assert!(matches!(known, GuardianAction::Quarantine { duration_blocks: 6 }));
+    }
+
+    #[test]
+    fn test_veto_rate_alarm_and_clear() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let config = BridgeConfig {
+            veto_rate_alarm: Some(VetoRateAlarm {
+                window: 4,
+                threshold: 50.0,
+                cooldown_blocks: 100,
+            }),
+            ..Default::default()
+        };
+        let bridge = AIGuardianBridge::try_new_with_config(engine, config).unwrap();
+        let alarms = |bridge: &AIGuardianBridge| -> Vec<&'static str> {
+            bridge
+                .drain_events()
+                .into_iter()
+                .filter_map(|event| match event {
+                    GuardianEvent::VetoRateHigh { .. } => Some("high"),
+                    GuardianEvent::VetoRateCleared { .. } => Some("cleared"),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        // Fee 1 is below the minimum and always vetoed; 2_000 is accepted
+        for gas_price in [2_000, 2_000, 1, 1] {
+            bridge.validate_transaction_with_guardian(tx_profile(gas_price), 10).unwrap();
+        }
+        assert_eq!(alarms(&bridge), vec!["high"]);
+
+        // Staying above the threshold doesn't repeat the alarm
+        bridge.validate_transaction_with_guardian(tx_profile(1), 11).unwrap();
+        assert!(alarms(&bridge).is_empty());
+
+        for _ in 0..4 {
+            bridge.validate_transaction_with_guardian(tx_profile(2_000), 12).unwrap();
+        }
+        assert_eq!(alarms(&bridge), vec!["cleared"]);
+
+        // A second wave inside the cooldown stays quiet, then alarms once it ends
+        for _ in 0..4 {
+            bridge.validate_transaction_with_guardian(tx_profile(1), 50).unwrap();
+        }
+        assert!(alarms(&bridge).is_empty());
+        bridge.validate_transaction_with_guardian(tx_profile(1), 110).unwrap();
+        assert_eq!(alarms(&bridge), vec!["high"]);
+    }
+
+    #[test]
+    fn test_weighted_vote_minority_with_majority_weight_passes() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let config = BridgeConfig {
+            voting_policy: VotingPolicy {
+                validators: BTreeMap::from([
+                    ("whale".to_string(), 70),
+                    ("a".to_string(), 10),
+                    ("b".to_string(), 10),
+                    ("c".to_string(), 10),
+                ]),
+                vote_approval_weight: 67,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let bridge = AIGuardianBridge::try_new_with_config(engine.clone(), config).unwrap();
+        let proposal = bridge.manual_proposal(1_010, 1_000_000, 1_000, 100).unwrap();
+        let id = proposal.proposal_id.clone();
+
+        // Voting only opens once the proposal is pending a vote
+        assert!(bridge.cast_vote(&id, "whale", true, None).is_err());
+        bridge.transition_proposal(&id, ProposalStatus::PendingVote).unwrap();
+
+        // One validator of four, holding 70% of the weight
+        bridge.cast_vote(&id, "whale", true, None).unwrap();
+        for validator in ["a", "b", "c"] {
+            bridge.cast_vote(&id, validator, false, None).unwrap();
+        }
+        assert!(bridge.cast_vote(&id, "whale", true, None).is_err(), "one vote per validator");
+        assert!(bridge.cast_vote(&id, "mallory", true, None).is_err(), "unregistered validator");
+        assert_eq!(bridge.finalize_vote(&id).unwrap(), ProposalStatus::Approved);
+        bridge.apply_consensus_optimization(&proposal).unwrap();
+
+        // Equal weights keep the 3-of-4 count rule
+        let bridge = AIGuardianBridge::try_new_with_config(engine.clone(), voting_config()).unwrap();
+        let tally = |approvals: usize| {
+            let proposal = bridge.manual_proposal(1_010, 1_000_000, 1_000, 100).unwrap();
+            let id = proposal.proposal_id;
+            bridge.transition_proposal(&id, ProposalStatus::PendingVote).unwrap();
+            for (i, validator) in ["v1", "v2", "v3", "v4"].iter().enumerate() {
+                bridge.cast_vote(&id, validator, i < approvals, None).unwrap();
+            }
+            bridge.finalize_vote(&id).unwrap()
+        };
+        assert_eq!(tally(2), ProposalStatus::Rejected);
+        assert_eq!(tally(3), ProposalStatus::Approved);
+
+        // A registered validator can't claim more weight than it holds
+        let bridge = AIGuardianBridge::try_new_with_config(engine.clone(), voting_config()).unwrap();
+        let proposal = bridge.manual_proposal(1_010, 1_000_000, 1_000, 100).unwrap();
+        bridge.transition_proposal(&proposal.proposal_id, ProposalStatus::PendingVote).unwrap();
+        assert!(bridge.cast_vote(&proposal.proposal_id, "v1", true, Some(3)).is_err());
+        bridge.cast_vote(&proposal.proposal_id, "v1", true, Some(1)).unwrap();
+
+        // Without a registered set, ballots default to equal weights: 3-of-4
+        let bridge = AIGuardianBridge::new(engine);
+        let open_vote = |votes: &[(&str, Option<u64>)]| {
+            let proposal = bridge.manual_proposal(1_010, 1_000_000, 1_000, 100).unwrap();
+            let id = proposal.proposal_id;
+            bridge.transition_proposal(&id, ProposalStatus::PendingVote).unwrap();
+            for (validator, weight) in votes {
+                bridge.cast_vote(&id, validator, true, *weight).unwrap();
+            }
+            bridge.finalize_vote(&id).unwrap()
+        };
+        assert_eq!(open_vote(&[("a", None), ("b", None)]), ProposalStatus::Rejected);
+        assert_eq!(open_vote(&[("a", None), ("b", None), ("c", None)]), ProposalStatus::Approved);
+        // ...or to the weights supplied with the ballots
+        assert_eq!(open_vote(&[("whale", Some(3))]), ProposalStatus::Approved);
+        assert_eq!(open_vote(&[("a", Some(1)), ("b", Some(1))]), ProposalStatus::Rejected);
+    }
+
+    #[test]
+    fn test_first_application_sets_baseline() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let bridge = AIGuardianBridge::new(engine);
+        let genesis = bridge.consensus_parameters();
+        assert_eq!(bridge.last_applied_height(), None);
+
+        // No prior application: nonce 0 against the genesis parameters
+        let blocks = block_series(144, SovereignInvariants::TARGET_BLOCK_TIME_SECS / 2);
+        let first = bridge.generate_consensus_optimization(1_000, &blocks).unwrap();
+        assert_eq!(first.application_nonce, 0);
+        assert_eq!(first.current_difficulty, genesis.difficulty);
+        bridge.apply_consensus_optimization(&first).unwrap();
+
+        assert_eq!(bridge.last_applied_height(), Some(1_000));
+        assert_eq!(bridge.consensus_ai.read().application_nonce, 1);
+        let baseline = bridge.consensus_parameters();
+        assert_eq!(baseline.difficulty, first.proposed_difficulty);
+
+        // The next proposal builds on the first one's parameters
+        let next = bridge.generate_consensus_optimization(1_010, &blocks).unwrap();
+        assert_eq!(next.application_nonce, 1);
+        assert_eq!(next.current_difficulty, baseline.difficulty);
+        assert_eq!(next.current_vdf, baseline.vdf_iterations);
+        bridge.apply_consensus_optimization(&next).unwrap();
+        assert_eq!(bridge.last_applied_height(), Some(1_010));
+    }
+
+    #[test]
+    fn test_apply_returns_resulting_parameters() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let bridge = AIGuardianBridge::new(engine);
+
+        let blocks = block_series(144, SovereignInvariants::TARGET_BLOCK_TIME_SECS / 2);
+        let proposal = bridge.generate_consensus_optimization(1_000, &blocks).unwrap();
+        let applied = bridge.apply_consensus_optimization(&proposal).unwrap();
+
+        assert_eq!(applied.parameters, ConsensusParameters {
+            difficulty: proposal.proposed_difficulty,
+            vdf_iterations: proposal.proposed_vdf,
+            min_gas: proposal.proposed_min_gas,
+        });
+        assert_eq!(applied.application_nonce, proposal.application_nonce + 1);
+        assert_eq!(applied.parameters, bridge.consensus_parameters());
+
+        // The returned nonce is the one the next proposal carries
+        let next = bridge.generate_consensus_optimization(1_144, &blocks).unwrap();
+        assert_eq!(next.application_nonce, applied.application_nonce);
+    }
+
+    #[test]
+    fn test_application_nonce_rejects_replays() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let bridge = AIGuardianBridge::new(engine);
+        let blocks = block_series(144, SovereignInvariants::TARGET_BLOCK_TIME_SECS / 2);
+
+        // Both generated against nonce 0
+        let first = bridge.generate_consensus_optimization(1_000, &blocks).unwrap();
+        let stale = bridge.generate_consensus_optimization(1_001, &blocks).unwrap();
+        assert_eq!(first.application_nonce, 0);
+        assert_eq!(stale.application_nonce, 0);
+
+        bridge.apply_consensus_optimization(&first).unwrap();
+        let applied = bridge.consensus_parameters();
+
+        // The sibling is now out of order and leaves the parameters alone
+        assert!(bridge.apply_consensus_optimization(&stale).is_err());
+        assert_eq!(bridge.proposal_status(&stale.proposal_id), Some(ProposalStatus::Generated));
+
+        // A proposal claiming a future nonce is rejected too
+        let next = bridge.generate_consensus_optimization(1_002, &blocks).unwrap();
+        assert_eq!(next.application_nonce, 1);
+        let mut skipped = next.clone();
+        skipped.application_nonce = 2;
+        skipped.proposal_id = "skipped".to_string();
+        assert!(bridge.apply_consensus_optimization(&skipped).is_err());
+        assert_eq!(bridge.consensus_parameters(), applied);
+
+        bridge.apply_consensus_optimization(&next).unwrap();
+        assert_eq!(bridge.consensus_ai.read().application_nonce, 2);
+    }
+
+    #[test]
+    fn test_block_feed_auto_generates_one_proposal() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let config = BridgeConfig {
+            auto_optimize_interval: Some(144),
+            ..Default::default()
+        };
+        let bridge = AIGuardianBridge::try_new_with_config(engine, config).unwrap();
+
+        let mut generated = Vec::new();
+        for block in block_series(200, SovereignInvariants::TARGET_BLOCK_TIME_SECS / 2) {
+            if let Some(proposal) = bridge.on_new_block(block).unwrap() {
+                generated.push(proposal.block_height);
+            }
+        }
+        assert_eq!(generated, vec![144]);
+
+        let events: Vec<_> = bridge
+            .drain_events()
+            .into_iter()
+            .filter_map(|event| match event {
+                GuardianEvent::ProposalGenerated { proposal, .. } => Some(proposal),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(bridge.proposal_status(&events[0].proposal_id), Some(ProposalStatus::Generated));
+
+        // Every pushed block was ingested exactly once
+        assert_eq!(bridge.consensus_ai.read().blocks_ingested, 200);
+    }
+
+    #[test]
+    fn test_change_percents_rounded_to_configured_precision() {
+        let config = BridgeConfig {
+            change_percent_precision: Some(2),
+            ..Default::default()
+        };
+        let bridge = AIGuardianBridge::new_with_assessor(Arc::new(ScoreByAmount), config).unwrap();
+        let proposal = bridge.manual_proposal(1_017, 1_012_345, 988, 500).unwrap();
+
+        let recomputed = |old: u64, new: u64| {
+            let percent = AIGuardianBridge::calculate_change_percent(old, new);
+            ((percent * 100.0).round() / 100.0, percent)
+        };
+        for (stored, old, new) in [
+            (proposal.difficulty_change_percent, 1_000, 1_017),
+            (proposal.vdf_change_percent, 1_000_000, 1_012_345),
+            (proposal.gas_change_percent, 1_000, 988),
+        ] {
+            let (rounded, raw) = recomputed(old, new);
+            assert_eq!(stored, rounded);
+            assert!((stored - raw).abs() <= ConsensusOptimizationProposal::change_percent_tolerance(2));
+        }
+        // Positive means an increase
+        assert_eq!(proposal.vdf_change_percent, 1.23);
+        assert_eq!(proposal.gas_change_percent, -1.2);
+        assert!(proposal.difficulty_change_percent > 0.0);
+    }
+
+    #[test]
+    fn test_generation_names_out_of_bound_parameter() {
+        // A difficulty floor 5x the live difficulty forces a jump the
+        // manifest forbids
+        let config = BridgeConfig {
+            controller_tuning: ControllerTuning {
+                min_difficulty: 5_000,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let bridge = AIGuardianBridge::try_new_with_config(
+            Arc::new(MultiLayerSecurityEngine::new(Default::default())),
+            config,
+        )
+        .unwrap();
+
+        let err = bridge
+            .generate_consensus_optimization(144, &block_series(144, SovereignInvariants::TARGET_BLOCK_TIME_SECS / 4))
+            .unwrap_err();
+        let reason = match err {
+            AxiomError::AIProposalRejected { reason } => reason,
+            other => panic!("unexpected error: {:?}", other),
+        };
+        assert!(reason.contains("Generated difficulty proposal 1000 -> 5000 swings 400.00%"), "{}", reason);
+        assert!(reason.contains("395.00 points over the 5.0% manifest bound"), "{}", reason);
+        assert_eq!(bridge.consensus_ai.read().current_difficulty, 1000);
+    }
+
+    #[test]
+    fn test_expected_improvement_models_the_change() {
+        let mut controller = ConsensusAIController::with_profile(NetworkProfile::default());
+        controller
+            .update_metrics(&block_series(144, SovereignInvariants::TARGET_BLOCK_TIME_SECS * 2))
+            .unwrap();
+        let current = controller.parameters();
+
+        // Blocks are slow: easing difficulty brings them back toward target
+        let eased = ConsensusParameters {
+            difficulty: current.difficulty * 95 / 100,
+            ..current
+        };
+        let gain = controller.calculate_expected_improvement(eased, 0.8).unwrap();
+        assert!(gain > 0.0, "{}", gain);
+
+        // Lower confidence, smaller expected gain
+        assert!(controller.calculate_expected_improvement(eased, 0.4).unwrap() < gain);
+
+        // A no-op changes nothing
+        assert!(controller.calculate_expected_improvement(current, 0.8).unwrap().abs() < 1e-9);
+
+        // Raising difficulty makes slow blocks slower
+        let tightened = ConsensusParameters {
+            difficulty: current.difficulty * 105 / 100,
+            ..current
+        };
+        assert!(controller.calculate_expected_improvement(tightened, 0.8).unwrap() < 0.0);
+    }
+
+    #[test]
+    fn test_hashrate_stability_needs_minimum_samples() {
+        let noisy = |count: usize| {
+            let mut blocks = block_series(count, SovereignInvariants::TARGET_BLOCK_TIME_SECS);
+            for (i, block) in blocks.iter_mut().enumerate() {
+                block.hashrate_estimate = if i % 2 == 0 { 1e12 } else { 2e12 };
+            }
+            blocks
+        };
+
+        let mut controller = ConsensusAIController::with_profile(NetworkProfile::default());
+        controller.update_metrics(&noisy(5)).unwrap();
+        assert_eq!(controller.calculate_hashrate_stability().unwrap(), 0.5);
+        assert_eq!(
+            controller.try_hashrate_stability(),
+            Telemetry::InsufficientData { have: 5, need: 30 }
+        );
+
+        let mut controller = ConsensusAIController::with_profile(NetworkProfile::default());
+        controller.update_metrics(&noisy(50)).unwrap();
+        // Mean 1.5e12, standard deviation 0.5e12: CV 1/3
+        assert!((controller.calculate_hashrate_stability().unwrap() - 2.0 / 3.0).abs() < 1e-9);
+        assert!(controller.try_hashrate_stability().ready().is_some());
+
+        let tuning = ControllerTuning {
+            min_hashrate_samples: 1,
+            ..Default::default()
+        };
+        assert!(tuning.validate().is_err());
+    }
+
+    /// Flags every transaction as catastrophic and hands it to the guardian
+    struct CatastrophicEscalationEngine;
+
+    impl ThreatAssessor for CatastrophicEscalationEngine {
+        fn assess_transaction_threat(
+            &self,
+            _profile: &TransactionRiskProfile,
+            _current_block_height: u64,
+        ) -> Result<ThreatAssessment, AxiomError> {
+            let mut assessment = unassessed("catastrophic".to_string());
+            assessment.risk_level = RiskLevel::Catastrophic;
+            assessment.guardian_override_required = true;
+            assessment.recommended_action = SecurityAction::EscalateToGuardian {
+                threat_level: RiskLevel::Catastrophic,
+            };
+            Ok(assessment)
+        }
+    }
+
+    #[test]
+    fn test_optimization_only_auto_pilot() {
+        let config = BridgeConfig {
+            auto_optimize_interval: Some(144),
+            auto_pilot_scope: AutoPilotScope {
+                validation: false,
+                optimization: true,
+            },
+            voting_policy: VotingPolicy {
+                min_apply_confidence: 0.5,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let bridge = AIGuardianBridge::new_with_assessor(Arc::new(CatastrophicEscalationEngine), config).unwrap();
+        bridge.set_auto_pilot_mode(true);
+
+        let before = bridge.consensus_ai.read().parameters();
+        let mut generated = None;
+        for block in block_series(144, SovereignInvariants::TARGET_BLOCK_TIME_SECS * 9 / 10) {
+            if let Some(proposal) = bridge.on_new_block(block).unwrap() {
+                generated = Some(proposal);
+            }
+        }
+        let proposal = generated.expect("a proposal at block 144");
+        assert!(!proposal.requires_voting);
+        assert!(proposal.ai_confidence >= 0.5);
+
+        // The proposal was applied without anyone calling apply
+        assert_eq!(bridge.proposal_status(&proposal.proposal_id), Some(ProposalStatus::Applied));
+        assert_ne!(bridge.consensus_ai.read().parameters(), before);
+
+        // Validation is out of scope, so catastrophic threats still escalate
+        // to manual review instead of being auto-rejected
+        let decision = bridge.validate_transaction_with_guardian(tx_profile(2_000), 200).unwrap();
+        assert!(matches!(
+            decision.action,
+            GuardianAction::RequireManualReview { threat_level: RiskLevel::Catastrophic }
+        ));
+        assert_eq!(bridge.get_guardian_stats().pending_reviews, 1);
+    }
+
+    /// Asks for an over-long quarantine of every transaction
+    struct QuarantiningEngine;
+
+    impl ThreatAssessor for QuarantiningEngine {
+        fn assess_transaction_threat(
+            &self,
+            _profile: &TransactionRiskProfile,
+            _current_block_height: u64,
+        ) -> Result<ThreatAssessment, AxiomError> {
+            let mut assessment = unassessed("quarantine".to_string());
+            assessment.recommended_action = SecurityAction::Quarantine { duration_blocks: 100_000 };
+            Ok(assessment)
+        }
+    }
+
+    #[test]
+    fn test_quarantine_clamped_to_manifest_cap() {
+        let bridge = AIGuardianBridge::new_with_assessor(
+            Arc::new(QuarantiningEngine),
+            BridgeConfig::default(),
+        )
+        .unwrap();
+
+        let decision = bridge.validate_transaction_with_guardian(tx_profile(2_000), 100).unwrap();
+        match decision.action {
+            GuardianAction::Quarantine { duration_blocks } => {
+                assert_eq!(duration_blocks, SovereignInvariants::MAX_QUARANTINE_BLOCKS)
+            }
+            other => panic!("expected quarantine, got {:?}", other),
+        }
+    }
+
+    /// Treats every transaction from a listed sender as catastrophic
+    struct Blocklist(Vec<String>);
+
+    impl ThreatAdjuster for Blocklist {
+        fn adjust(&self, profile: &TransactionRiskProfile, assessment: &mut ThreatAssessment) {
+            if self.0.contains(&profile.sender) {
+                assessment.threat_score = 100.0;
+                assessment.risk_level = RiskLevel::Catastrophic;
+                assessment.recommended_action = SecurityAction::Reject {
+                    reason: format!("sender {} is blocklisted", profile.sender),
+                };
+            }
+        }
+    }
+
+    #[test]
+    fn test_threat_adjuster_blocklist_rejects() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let bridge = AIGuardianBridge::new(engine).with_threat_adjuster(Arc::new(Blocklist(vec!["mallory".to_string()])));
+
+        let decision = bridge.validate_transaction_with_guardian(tx_profile(2_000), 100).unwrap();
+        assert!(decision.approved);
+
+        let mut profile = tx_profile(2_000);
+        profile.sender = "mallory".to_string();
+        let decision = bridge.validate_transaction_with_guardian(profile, 100).unwrap();
+        assert!(!decision.approved);
+        assert!(matches!(decision.action, GuardianAction::Reject));
+        assert!(matches!(decision.threat_assessment.risk_level, RiskLevel::Catastrophic));
+    }
+
+    #[test]
+    fn test_non_monotonic_block_timestamps_rejected() {
+        let mut blocks = block_series(10, 1_800);
+        assert!(BlockMetrics::validate_series(&blocks).is_ok());
+
+        // Block 6 claims to be older than block 5
+        blocks[5].timestamp = blocks[4].timestamp - 1;
+        assert!(matches!(
+            BlockMetrics::validate_series(&blocks),
+            Err(AxiomError::InvalidBlock(_))
+        ));
+
+        let mut controller = ConsensusAIController::with_profile(NetworkProfile::Mainnet);
+        assert!(controller.update_metrics(&blocks).is_err());
+        assert!(controller.block_time_history.is_empty(), "nothing ingested from a bad feed");
+        assert_eq!(controller.last_ingested_height, None);
+
+        // Increasing, but block_time disagrees with the timestamp delta
+        let mut skewed = block_series(10, 1_800);
+        skewed[5].block_time = 600;
+        assert!(BlockMetrics::validate_series(&skewed).is_err());
+        skewed[5].block_time = 1_800 + BLOCK_TIMESTAMP_TOLERANCE_SECS;
+        assert!(BlockMetrics::validate_series(&skewed).is_ok());
+    }
+
+    #[test]
+    fn test_on_new_block_rejects_backwards_timestamp() {
+        let bridge = AIGuardianBridge::new(Arc::new(MultiLayerSecurityEngine::new(Default::default())));
+        let blocks = block_series(3, 1_800);
+        bridge.on_new_block(blocks[0].clone()).unwrap();
+        bridge.on_new_block(blocks[1].clone()).unwrap();
+
+        // Each block arrives alone, yet is still checked against its predecessor
+        let mut backwards = blocks[2].clone();
+        backwards.timestamp = blocks[1].timestamp - 1;
+        assert!(matches!(bridge.on_new_block(backwards), Err(AxiomError::InvalidBlock(_))));
+        assert_eq!(bridge.consensus_ai.read().last_ingested_height, Some(2));
+        assert_eq!(bridge.block_feed.lock().recent.len(), 2);
+
+        // The genuine block is still accepted afterwards
+        bridge.on_new_block(blocks[2].clone()).unwrap();
+        assert_eq!(bridge.consensus_ai.read().last_ingested_height, Some(3));
+    }
+
+    #[test]
+    fn test_network_health_trend_tracks_deterioration() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let bridge = AIGuardianBridge::new(engine);
+        assert_eq!(bridge.network_health_trend(), 0.0);
+
+        // On-target blocks, then block times drifting steadily slower
+        let target = SovereignInvariants::TARGET_BLOCK_TIME_SECS;
+        let mut timestamp = 1_700_000_000;
+        for height in 1..=288u64 {
+            let block_time = if height <= 144 { target } else { target + (height - 144) * 10 };
+            timestamp += block_time;
+            bridge
+                .on_new_block(BlockMetrics {
+                    height,
+                    timestamp,
+                    block_time,
+                    difficulty: 1000,
+                    vdf_iterations: 1_000_000,
+                    transaction_count: 100,
+                    total_fees: 100_000,
+                    hashrate_estimate: 1e12,
+                })
+                .unwrap();
+        }
+
+        let history = bridge.network_health_history();
+        assert_eq!(history.len(), 288);
+        assert!(history[143] > history[287]);
+        assert!(bridge.network_health_trend() < 0.0, "{}", bridge.network_health_trend());
+    }
+
+    #[test]
+    fn test_try_hashrate_trend_reports_insufficient_data() {
+        let mut controller = ConsensusAIController::with_profile(NetworkProfile::Mainnet);
+        assert_eq!(controller.try_mempool_congestion(), Telemetry::InsufficientData { have: 0, need: 1 });
+
+        controller.update_metrics(&block_series(1, 1_800)).unwrap();
+        assert_eq!(controller.try_hashrate_trend(), Telemetry::InsufficientData { have: 1, need: 2 });
+        assert_eq!(controller.try_network_health_score(), Telemetry::InsufficientData { have: 1, need: 30 });
+        // The lenient path still yields a neutral value
+        assert_eq!(controller.calculate_hashrate_trend().unwrap(), 0.0);
+
+        // A flat hashrate is a genuine zero trend, not missing data
+        controller.update_metrics(&block_series(40, 1_800)).unwrap();
+        assert_eq!(controller.try_hashrate_trend(), Telemetry::Ready(0.0));
+        assert!(controller.try_network_health_score().ready().is_some());
+    }
+
+    #[test]
+    fn test_reset_to_safe_defaults() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let defaults = ConsensusParameters {
+            difficulty: 1_200,
+            vdf_iterations: 1_500_000,
+            min_gas: 2_000,
+        };
+        let config = BridgeConfig {
+            safe_defaults: Some(defaults),
+            ..Default::default()
+        };
+        let bridge = AIGuardianBridge::try_new_with_config(engine, config).unwrap();
+        let authorization = || ResetAuthorization {
+            operator: "ops-1".to_string(),
+            reason: "difficulty oscillating at the bounds".to_string(),
+        };
+
+        // Drive the parameters and PID state somewhere else
+        let stale = bridge
+            .generate_consensus_optimization(1_000, &block_series(144, 900))
+            .unwrap();
+        bridge.apply_consensus_optimization(&stale).unwrap();
+        assert_ne!(bridge.consensus_parameters(), defaults);
+        {
+            let mut consensus = bridge.consensus_ai.write();
+            consensus.difficulty_pid.integral = 0.4;
+            consensus.gas_pid.previous_error = -0.2;
+            consensus.vdf_pid.integral = -0.3;
+        }
+
+        // Refused unless the operator has taken manual control
+        assert!(bridge.reset_to_safe_defaults(authorization()).is_err());
+        bridge.set_manual_override(true);
+        bridge.reset_to_safe_defaults(authorization()).unwrap();
+
+        assert_eq!(bridge.consensus_parameters(), defaults);
+        {
+            let consensus = bridge.consensus_ai.read();
+            for pid in [&consensus.difficulty_pid, &consensus.gas_pid, &consensus.vdf_pid] {
+                assert_eq!(pid.integral, 0.0);
+                assert_eq!(pid.previous_error, 0.0);
+            }
+        }
+        assert!(matches!(
+            bridge.audit_log().last().unwrap().record,
+            AuditRecord::ParametersReset { to, .. } if to == defaults
+        ));
+        assert!(bridge
+            .drain_events()
+            .iter()
+            .any(|event| matches!(event, GuardianEvent::ParametersReset { .. })));
+
+        // Unsafe configured defaults are refused
+        let unsafe_config = BridgeConfig {
+            safe_defaults: Some(ConsensusParameters {
+                vdf_iterations: SovereignInvariants::MINIMUM_VDF_ITERATIONS - 1,
+                ..defaults
+            }),
+            ..Default::default()
+        };
+        let unsafe_bridge =
+            AIGuardianBridge::try_new_with_config(
+                Arc::new(MultiLayerSecurityEngine::new(Default::default())),
+                unsafe_config,
+            )
+            .unwrap();
+        unsafe_bridge.set_manual_override(true);
+        assert!(unsafe_bridge.reset_to_safe_defaults(authorization()).is_err());
+    }
+
+    #[test]
+    fn test_controller_tuning_max_change() {
+        let tuning = ControllerTuning {
+            difficulty_max_change: 0.01,
+            ..Default::default()
+        };
+        tuning.validate().unwrap();
+
+        // Blocks persistently far too fast: the adjustment hits the tuned bound
+        let mut controller = ConsensusAIController::with_tuning(NetworkProfile::Mainnet, tuning);
+        controller.current_difficulty = 10_000;
+        controller.update_metrics(&block_series(200, 10)).unwrap();
+        assert_eq!(controller.calculate_difficulty_adjustment().unwrap(), 10_100);
+
+        // Through the bridge config as well
+        let config = BridgeConfig {
+            controller_tuning: tuning,
+            ..Default::default()
+        };
+        let bridge = AIGuardianBridge::try_new_with_config(
+            Arc::new(MultiLayerSecurityEngine::new(Default::default())),
+            config,
+        )
+        .unwrap();
+        let proposal = bridge
+            .generate_consensus_optimization(1_000, &block_series(144, 10))
+            .unwrap();
+        assert_eq!(proposal.proposed_difficulty, 1_010);
+
+        // Fractions beyond the manifest swing bounds are refused
+        let too_wide = ControllerTuning {
+            gas_max_change: 0.2,
+            ..Default::default()
+        };
+        assert!(too_wide.validate().is_err());
+        assert!(ControllerTuning { vdf_max_change: 0.0, ..Default::default() }.validate().is_err());
+
+        // ...and so is a bridge built with one, rather than falling back to defaults
+        let config = BridgeConfig {
+            controller_tuning: too_wide,
+            ..Default::default()
+        };
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        assert!(matches!(
+            AIGuardianBridge::try_new_with_config(engine.clone(), config.clone()),
+            Err(AxiomError::AIProposalRejected { reason }) if reason.contains("Tuned gas max change 0.2")
+        ));
+        assert!(AIGuardianBridge::builder(engine).config(config).build().is_err());
+    }
+
+    #[test]
+    fn test_fee_percentiles_under_congestion() {
+        let bridge = AIGuardianBridge::new(Arc::new(MultiLayerSecurityEngine::new(Default::default())));
+        let floor = SovereignInvariants::MIN_TRANSACTION_FEE;
+
+        // No data yet: everything sits at the floor-clamped minimum gas
+        let idle = bridge.fee_percentiles();
+        assert!(idle.p10 >= floor && idle.p10 >= bridge.consensus_parameters().min_gas);
+
+        // A spread of accepted fees and a nearly full mempool
+        let mut blocks = block_series(100, 1_800);
+        for (i, block) in blocks.iter_mut().enumerate() {
+            block.total_fees = block.transaction_count as u64 * (1_000 + 50 * i as u64);
+        }
+        for block in blocks {
+            bridge.on_new_block(block).unwrap();
+        }
+        for _ in 0..10 {
+            bridge.record_mempool_size(900);
+        }
+
+        let fees = bridge.fee_percentiles();
+        assert!(fees.p90 > fees.p50, "{:?}", fees);
+        assert!(fees.p50 > fees.p10, "{:?}", fees);
+        assert!(fees.p10 >= floor);
+        assert!(fees.p10 > idle.p10);
+
+        // Deterministic for the same state
+        assert_eq!(bridge.fee_percentiles(), fees);
+    }
+
+    struct FixedClock(u64);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> u64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_builder_wires_clock_and_audit_sink() {
+        let sink = Arc::new(InMemoryAuditSink::new(64));
+        let bridge = AIGuardianBridge::builder(Arc::new(MultiLayerSecurityEngine::new(Default::default())))
+            .clock(Arc::new(FixedClock(1_234_567)))
+            .audit_sink(sink.clone())
+            .rng_seed(7)
+            .build()
+            .unwrap();
+
+        bridge.validate_transaction_with_guardian(tx_profile(2_000), 100).unwrap();
+        bridge.set_auto_pilot_mode(true);
+        let proposal = bridge
+            .generate_consensus_optimization(1_000, &block_series(144, 1_800))
+            .unwrap();
+
+        // Entries land in the supplied sink, stamped by the supplied clock
+        let entries = sink.recent();
+        assert!(entries.len() >= 3);
+        assert!(entries.iter().all(|entry| entry.timestamp == 1_234_567));
+        assert_eq!(proposal.timestamp, 1_234_567);
+    }
+
+    /// Accepts everything after a fixed delay
+    struct SlowEngine(std::time::Duration);
+
+    impl ThreatAssessor for SlowEngine {
+        fn assess_transaction_threat(
+            &self,
+            _profile: &TransactionRiskProfile,
+            _current_block_height: u64,
+        ) -> Result<ThreatAssessment, AxiomError> {
+            std::thread::sleep(self.0);
+            Ok(unassessed("slow engine".to_string()))
+        }
+    }
+
+    #[test]
+    fn test_decision_latency_recorded() {
+        let engine = SlowEngine(std::time::Duration::from_millis(5));
+        let bridge = AIGuardianBridge::new_with_assessor(Arc::new(engine), BridgeConfig::default()).unwrap();
+        assert_eq!(bridge.get_guardian_stats().decision_latency, LatencySummary::default());
+
+        for _ in 0..10 {
+            bridge.validate_transaction_with_guardian(tx_profile(2_000), 100).unwrap();
+        }
+
+        let latency = bridge.full_snapshot().stats.decision_latency;
+        assert_eq!(latency.samples, 10);
+        // 5 ms lands in the (4.1 ms, 8.2 ms] bucket; allow one bucket of slack
+        assert!(latency.p50_micros >= 5_000, "{:?}", latency);
+        assert!(latency.p50_micros <= 16_384, "{:?}", latency);
+        assert!(latency.p99_micros >= latency.p50_micros);
+    }
+
+    #[test]
+    fn test_paused_optimization_still_validates() {
+        let bridge = AIGuardianBridge::new(Arc::new(MultiLayerSecurityEngine::new(Default::default())));
+        let proposal = bridge
+            .generate_consensus_optimization(1_000, &block_series(144, 1_800))
+            .unwrap();
+
+        bridge.pause_optimization();
+        assert!(bridge.get_guardian_stats().optimization_paused);
+        assert!(matches!(
+            bridge.generate_consensus_optimization(1_001, &block_series(144, 1_800)),
+            Err(AxiomError::OptimizationPaused)
+        ));
+        assert!(matches!(
+            bridge.apply_consensus_optimization(&proposal),
+            Err(AxiomError::OptimizationPaused)
+        ));
+
+        // Transaction validation carries on
+        let decision = bridge.validate_transaction_with_guardian(tx_profile(2_000), 1_001).unwrap();
+        assert!(decision.approved);
+
+        bridge.resume_optimization();
+        assert!(!bridge.get_guardian_stats().optimization_paused);
+        bridge.apply_consensus_optimization(&proposal).unwrap();
+    }
+
+    #[test]
+    fn test_difficulty_dead_band() {
+        let tuning = ControllerTuning::default();
+        assert_eq!(tuning.block_time_dead_band_secs, 30);
+
+        // 20s slow on average: inside the band, difficulty holds
+        let mut inside = ConsensusAIController::with_profile(NetworkProfile::Mainnet);
+        inside.update_metrics(&block_series(200, 1_820)).unwrap();
+        assert_eq!(inside.calculate_difficulty_adjustment().unwrap(), inside.current_difficulty);
+        assert_eq!(inside.difficulty_pid.integral, 0.0);
+
+        // 40s slow: outside the band, PID control lowers difficulty
+        let mut outside = ConsensusAIController::with_profile(NetworkProfile::Mainnet);
+        outside.update_metrics(&block_series(200, 1_840)).unwrap();
+        assert!(outside.calculate_difficulty_adjustment().unwrap() < outside.current_difficulty);
+
+        // A band as wide as the manifest deviation is refused
+        let too_wide = ControllerTuning {
+            block_time_dead_band_secs: SovereignInvariants::MAXIMUM_BLOCK_TIME_DEVIATION_SECS,
+            ..Default::default()
+        };
+        assert!(too_wide.validate().is_err());
+    }
+
+    /// Escalates every transaction at a fixed risk level
+    struct EscalatingAt(RiskLevel);
+
+    impl ThreatAssessor for EscalatingAt {
+        fn assess_transaction_threat(
+            &self,
+            _profile: &TransactionRiskProfile,
+            _current_block_height: u64,
+        ) -> Result<ThreatAssessment, AxiomError> {
+            let mut assessment = unassessed("escalated".to_string());
+            assessment.recommended_action = SecurityAction::EscalateToGuardian { threat_level: self.0 };
+            Ok(assessment)
+        }
+    }
+
+    #[test]
+    fn test_escalation_fallback_auto_rejects_critical() {
+        let config = BridgeConfig {
+            escalation_fallbacks: BTreeMap::from([(RiskLevel::Critical, EscalationFallback::AutoReject)]),
+            ..Default::default()
+        };
+        let critical = AIGuardianBridge::new_with_assessor(
+            Arc::new(EscalatingAt(RiskLevel::Critical)),
+            config.clone(),
+        )
+        .unwrap();
+
+        let decision = critical.validate_transaction_with_guardian(tx_profile(2_000), 100).unwrap();
+        assert!(!decision.approved);
+        assert!(matches!(decision.action, GuardianAction::AutoReject));
+        assert_eq!(critical.get_guardian_stats().pending_reviews, 0);
+
+        // With a reviewer attached the escalation is queued as before
+        critical.set_manual_override(true);
+        let decision = critical.validate_transaction_with_guardian(tx_profile(2_000), 101).unwrap();
+        assert!(matches!(decision.action, GuardianAction::RequireManualReview { .. }));
+        assert_eq!(critical.get_guardian_stats().pending_reviews, 1);
+
+        // Levels without a fallback keep the default manual review
+        let high = AIGuardianBridge::new_with_assessor(Arc::new(EscalatingAt(RiskLevel::High)), config).unwrap();
+        let decision = high.validate_transaction_with_guardian(tx_profile(2_000), 100).unwrap();
+        assert!(matches!(decision.action, GuardianAction::RequireManualReview { threat_level: RiskLevel::High }));
+    }
+
+    #[test]
+    fn test_proposal_diff() {
+        let bridge = AIGuardianBridge::new(Arc::new(MultiLayerSecurityEngine::new(Default::default())));
+        let rejected = bridge
+            .generate_consensus_optimization(1_000, &block_series(144, 1_500))
+            .unwrap();
+
+        let mut fresh = rejected.clone();
+        fresh.block_height += 48;
+        fresh.proposed_difficulty = rejected.proposed_difficulty - 30;
+        fresh.difficulty_change_percent = rejected.difficulty_change_percent - 3.0;
+        fresh.ai_confidence = rejected.ai_confidence + 0.1;
+        fresh.network_health_score = rejected.network_health_score - 0.25;
+
+        let delta = fresh.diff(&rejected);
+        assert_eq!(delta.block_height, 48);
+        assert_eq!(delta.difficulty.proposed, -30);
+        assert!((delta.difficulty.change_percent + 3.0).abs() < 1e-9);
+        assert_eq!(delta.vdf_iterations, ParameterDelta { proposed: 0, change_percent: 0.0 });
+        assert_eq!(delta.min_gas, ParameterDelta { proposed: 0, change_percent: 0.0 });
+        assert!((delta.ai_confidence - 0.1).abs() < 1e-9);
+        assert!((delta.network_health_score + 0.25).abs() < 1e-9);
+
+        // Diffing the other way round negates the delta
+        assert_eq!(rejected.diff(&fresh).difficulty.proposed, 30);
+    }
+
+    /// Recommends halting the chain at emergency level 9
+    struct HaltingEngine;
+
+    impl ThreatAssessor for HaltingEngine {
+        fn assess_transaction_threat(
+            &self,
+            _profile: &TransactionRiskProfile,
+            _current_block_height: u64,
+        ) -> Result<ThreatAssessment, AxiomError> {
+            let mut assessment = unassessed("chain-level threat".to_string());
+            assessment.recommended_action = SecurityAction::HaltChain { emergency_level: 9 };
+            Ok(assessment)
+        }
+    }
+
+    /// Halts the chain while `0` is set, otherwise accepts
+    struct SwitchableHaltEngine(std::sync::atomic::AtomicBool);
 
-        // Calculate optimal parameters
-        let difficulty_proposal = consensus.calculate_difficulty_adjustment()?;
-        let vdf_proposal = consensus.calculate_vdf_adjustment()?;
-        let gas_proposal = consensus.calculate_gas_adjustment()?;
+    impl ThreatAssessor for SwitchableHaltEngine {
+        fn assess_transaction_threat(
+            &self,
+            profile: &TransactionRiskProfile,
+            current_block_height: u64,
+        ) -> Result<ThreatAssessment, AxiomError> {
+            if self.0.load(Ordering::SeqCst) {
+                return HaltingEngine.assess_transaction_threat(profile, current_block_height);
+            }
+            Ok(unassessed("all clear".to_string()))
+        }
+    }
 
-        // Guardian pre-validation
-        SovereignInvariants::verify_ai_difficulty_proposal(consensus.current_difficulty, difficulty_proposal)?;
-        SovereignInvariants::verify_ai_vdf_proposal(consensus.current_vdf_iterations, vdf_proposal)?;
-        SovereignInvariants::verify_ai_gas_proposal(consensus.current_min_gas, gas_proposal)?;
+    #[test]
+    fn test_breaker_recovers_through_probe_phase() {
+        let recovering_bridge = || {
+            let engine = Arc::new(SwitchableHaltEngine(true.into()));
+            let config = BridgeConfig {
+                breaker_recovery: Some(BreakerRecovery {
+                    probe_blocks: 10,
+                    admit_fraction: 0.25,
+                }),
+                ..Default::default()
+            };
+            let bridge = AIGuardianBridge::new_with_assessor(engine.clone(), config).unwrap();
+            assert_eq!(bridge.full_snapshot().circuit_breaker_state, BreakerState::Armed);
+            bridge.validate_transaction_with_guardian(tx_profile(2_000), 100).unwrap();
+            assert_eq!(bridge.full_snapshot().circuit_breaker_state, BreakerState::Tripped);
+            engine.0.store(false, Ordering::SeqCst);
+            assert!(bridge.validate_transaction_with_guardian(tx_profile(2_000), 150).is_err());
+            (bridge, engine)
+        };
 
-        // Calculate metrics
-        let avg_block_time = recent_blocks.iter().map(|b| b.block_time).sum::<u64>() as f64
-            / recent_blocks.len() as f64;
+        // Tripped → Recovering at the auto-recovery block, admitting 1 in 4
+        let (bridge, _engine) = recovering_bridge();
+        let admitted = (244..252)
+            .filter(|&block| bridge.validate_transaction_with_guardian(tx_profile(2_000), block).is_ok())
+            .count();
+        assert_eq!(admitted, 2);
+        let snapshot = bridge.full_snapshot();
+        assert_eq!(snapshot.circuit_breaker_state, BreakerState::Recovering);
+        assert!(snapshot.circuit_breaker_active);
 
-        let hashrate_trend = consensus.calculate_hashrate_trend()?;
-        let mempool_congestion = consensus.calculate_mempool_congestion()?;
-        let network_health = consensus.calculate_network_health_score()?;
+        // → Closed once the probe phase passes quietly
+        assert!(bridge.validate_transaction_with_guardian(tx_profile(2_000), 254).is_ok());
+        let snapshot = bridge.full_snapshot();
+        assert_eq!(snapshot.circuit_breaker_state, BreakerState::Closed);
+        assert!(!snapshot.circuit_breaker_active);
 
-        let proposal = ConsensusOptimizationProposal {
-            proposal_id: format!("ai_consensus_{}", current_block),
-            block_height: current_block,
-            timestamp: std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_secs(),
+        // A renewed threat on a probe sends it straight back to Tripped
+        let (bridge, engine) = recovering_bridge();
+        engine.0.store(true, Ordering::SeqCst);
+        let probe = bridge.validate_transaction_with_guardian(tx_profile(2_000), 244).unwrap();
+        assert!(matches!(probe.action, GuardianAction::ChainHalt));
+        let snapshot = bridge.full_snapshot();
+        assert_eq!(snapshot.circuit_breaker_state, BreakerState::Tripped);
+        assert_eq!(snapshot.circuit_breaker_activation_block, Some(244));
+        assert_eq!(snapshot.circuit_breaker_severity, Some(BreakerSeverity::High));
+        engine.0.store(false, Ordering::SeqCst);
+        assert!(bridge.validate_transaction_with_guardian(tx_profile(2_000), 250).is_err());
+    }
 
-            current_difficulty: consensus.current_difficulty,
-            proposed_difficulty: difficulty_proposal,
-            difficulty_change_percent: Self::calculate_change_percent(
-                consensus.current_difficulty,
-                difficulty_proposal,
-            ),
+    #[test]
+    fn test_contradictory_engine_config_fails_construction() {
+        use crate::ai_core::SecurityConfig;
 
-            current_vdf: consensus.current_vdf_iterations,
-            proposed_vdf: vdf_proposal,
-            vdf_change_percent: Self::calculate_change_percent(
-                consensus.current_vdf_iterations,
-                vdf_proposal,
-            ),
+        let build = |config: SecurityConfig| AIGuardianBridge::try_new(Arc::new(MultiLayerSecurityEngine::new(config)));
+        assert!(build(SecurityConfig::default()).is_ok());
 
-            current_min_gas: consensus.current_min_gas,
-            proposed_min_gas: gas_proposal,
-            gas_change_percent: Self::calculate_change_percent(consensus.current_min_gas, gas_proposal),
+        // An escalation threshold no score can reach: the Guardian never sees a threat
+        let never_escalates = SecurityConfig {
+            guardian_escalation_threshold: 1.5,
+            ..Default::default()
+        };
+        match build(never_escalates) {
+            Err(AxiomError::InvalidConfig(message)) => {
+                assert!(message.contains("guardian_escalation_threshold 1.5"), "{}", message)
+            }
+            other => panic!("expected InvalidConfig, got {:?}", other.map(|_| ())),
+        }
 
-            avg_block_time_last_144: avg_block_time,
-            hashrate_trend,
-            mempool_congestion,
-            network_health_score: network_health,
+        let inverted = SecurityConfig {
+            auto_quarantine_threshold: 0.99,
+            ..Default::default()
+        };
+        assert!(matches!(build(inverted), Err(AxiomError::InvalidConfig(message)) if message.contains("unreachable")));
+
+        let slow = SecurityConfig {
+            max_processing_time_ms: 60_000,
+            ..Default::default()
+        };
+        assert!(build(slow.clone()).is_err());
 
-            ai_confidence: consensus.calculate_confidence()?,
-            expected_improvement: consensus.calculate_expected_improvement()?,
+        // The builder and assessor paths check it too
+        let engine = Arc::new(MultiLayerSecurityEngine::new(slow));
+        assert!(AIGuardianBridge::builder(engine.clone()).build().is_err());
+        assert!(AIGuardianBridge::new_with_assessor(engine, BridgeConfig::default()).is_err());
+    }
 
-            guardian_pre_approved: true,
-            requires_voting: false,
+    #[test]
+    #[should_panic(expected = "contradicts the manifest")]
+    fn test_new_refuses_contradictory_engine_config() {
+        use crate::ai_core::SecurityConfig;
+
+        let never_escalates = SecurityConfig {
+            guardian_escalation_threshold: 1.5,
+            ..Default::default()
         };
+        AIGuardianBridge::new(Arc::new(MultiLayerSecurityEngine::new(never_escalates)));
+    }
 
-        Ok(proposal)
+    #[test]
+    fn test_halt_chain_breaker_reason_is_ai_detected() {
+        let bridge = AIGuardianBridge::new_with_assessor(Arc::new(HaltingEngine), BridgeConfig::default()).unwrap();
+        let decision = bridge.validate_transaction_with_guardian(tx_profile(2_000), 300).unwrap();
+        assert!(matches!(decision.action, GuardianAction::ChainHalt));
+
+        let snapshot = bridge.full_snapshot();
+        assert!(snapshot.circuit_breaker_active);
+        assert_eq!(snapshot.circuit_breaker_category, Some(BreakerReason::AiDetected));
+        assert!(bridge.drain_events().iter().any(|event| matches!(
+            event,
+            GuardianEvent::CircuitBreakerActivated { category: BreakerReason::AiDetected, block_height: 300, .. }
+        )));
+
+        bridge.deactivate_circuit_breaker().unwrap();
+        assert_eq!(bridge.full_snapshot().circuit_breaker_category, None);
     }
 
-    /// Apply consensus optimization (Guardian-verified)
-    pub fn apply_consensus_optimization(
-        &self,
-        proposal: &ConsensusOptimizationProposal,
-    ) -> Result<(), AxiomError> {
-        if !proposal.guardian_pre_approved {
-            return Err(AxiomError::AIProposalRejected {
-                reason: "Proposal not pre-approved by Guardian".to_string(),
-            });
-        }
+    #[test]
+    fn test_breaker_deactivation_hook_and_guard() {
+        use std::sync::atomic::AtomicUsize;
 
-        if proposal.ai_confidence < 0.8 && proposal.requires_voting {
-            return Err(AxiomError::AIProposalRejected {
-                reason: "Proposal requires voting but confidence too low".to_string(),
+        let calls = Arc::new(AtomicUsize::new(0));
+        let recorded = calls.clone();
+        let refuse = Arc::new(std::sync::atomic::AtomicBool::new(true));
+        let refusing = refuse.clone();
+        let bridge = AIGuardianBridge::new(Arc::new(MultiLayerSecurityEngine::new(Default::default())))
+            .with_breaker_deactivation_guard(move |breaker| {
+                assert!(breaker.is_active());
+                if refusing.load(Ordering::SeqCst) {
+                    return Err(AxiomError::AIProposalRejected { reason: "still draining".to_string() });
+                }
+                Ok(())
+            })
+            .with_breaker_deactivation_hook(move |breaker| {
+                assert_eq!(breaker.category(), Some(BreakerReason::Manual));
+                recorded.fetch_add(1, Ordering::SeqCst);
+                Ok(())
             });
-        }
 
-        let mut consensus = self.consensus_ai.write();
-        consensus.current_difficulty = proposal.proposed_difficulty;
-        consensus.current_vdf_iterations = proposal.proposed_vdf;
-        consensus.current_min_gas = proposal.proposed_min_gas;
+        // No-op on an inactive breaker: neither guard nor hook runs
+        bridge.deactivate_circuit_breaker().unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
 
-        log::info!("🤖 Applied AI consensus optimization:");
-        log::info!("   Difficulty: {} → {} ({:+.2}%)", proposal.current_difficulty, proposal.proposed_difficulty, proposal.difficulty_change_percent);
-        log::info!("   VDF: {} → {} ({:+.2}%)", proposal.current_vdf, proposal.proposed_vdf, proposal.vdf_change_percent);
-        log::info!("   Min Gas: {} → {} ({:+.2}%)", proposal.current_min_gas, proposal.proposed_min_gas, proposal.gas_change_percent);
+        // A failing guard leaves the breaker exactly as it was
+        bridge.activate_circuit_breaker(10, "drill".to_string()).unwrap();
+        assert!(bridge.deactivate_circuit_breaker().is_err());
+        let snapshot = bridge.full_snapshot();
+        assert!(snapshot.circuit_breaker_active);
+        assert_eq!(snapshot.circuit_breaker_reason.as_deref(), Some("drill"));
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
 
-        Ok(())
+        refuse.store(false, Ordering::SeqCst);
+        bridge.deactivate_circuit_breaker().unwrap();
+        assert!(!bridge.full_snapshot().circuit_breaker_active);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        bridge.deactivate_circuit_breaker().unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
     }
 
-    fn calculate_change_percent(old: u64, new: u64) -> f64 {
-        if old == 0 {
-            return 0.0;
+    struct SteppedClock(AtomicU64);
+
+    impl Clock for SteppedClock {
+        fn now(&self) -> u64 {
+            self.0.load(Ordering::SeqCst)
         }
-        ((new as f64 - old as f64) / old as f64) * 100.0
     }
 
-    /// Activate emergency circuit breaker
-    pub fn activate_circuit_breaker(&self, current_block: u64, reason: String) -> Result<(), AxiomError> {
-        let mut breaker = self.emergency_circuit_breaker.write();
+    #[test]
+    fn test_breaker_deactivation_hook_retries() {
+        use std::sync::atomic::AtomicUsize;
 
-        if !breaker.is_active {
-            breaker.is_active = true;
-            breaker.activation_block = Some(current_block);
-            breaker.reason = Some(reason.clone());
-            breaker.auto_recovery_block = Some(current_block + 144);
+        let calls = Arc::new(AtomicUsize::new(0));
+        let recorded = calls.clone();
+        let clock = Arc::new(SteppedClock(AtomicU64::new(1_000)));
+        let bridge = AIGuardianBridge::builder(Arc::new(MultiLayerSecurityEngine::new(Default::default())))
+            .clock(clock.clone())
+            .build()
+            .unwrap()
+            .with_breaker_deactivation_hook(move |_| {
+                // Fails the first time only
+                if recorded.fetch_add(1, Ordering::SeqCst) == 0 {
+                    return Err(AxiomError::AIProposalRejected { reason: "subsystem busy".to_string() });
+                }
+                Ok(())
+            });
 
-            log::error!("🚨 EMERGENCY CIRCUIT BREAKER ACTIVATED at block {}", current_block);
-            log::error!("   Reason: {}", reason);
-            log::error!("   Auto-recovery: block {}", current_block + 144);
-        }
+        // The failure doesn't block deactivation; the retry waits its backoff
+        bridge.activate_circuit_breaker(10, "drill".to_string()).unwrap();
+        bridge.deactivate_circuit_breaker().unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(bridge.poll_breaker_deactivation_hook());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
 
-        Ok(())
+        clock.0.fetch_add(2 * BREAKER_HOOK_BACKOFF_SECS, Ordering::SeqCst);
+        assert!(!bridge.poll_breaker_deactivation_hook());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert!(!bridge.poll_breaker_deactivation_hook());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
     }
 
-    /// Deactivate circuit breaker (manual only)
-    pub fn deactivate_circuit_breaker(&self) -> Result<(), AxiomError> {
-        let mut breaker = self.emergency_circuit_breaker.write();
-
-        if breaker.is_active {
-            log::info!("✅ Emergency circuit breaker deactivated");
-            breaker.is_active = false;
-            breaker.activation_block = None;
-            breaker.reason = None;
-            breaker.auto_recovery_block = None;
+    #[test]
+    fn test_large_change_requires_voting() {
+        let bridge = AIGuardianBridge::new(Arc::new(MultiLayerSecurityEngine::new(Default::default())));
+        let mut blocks = block_series(144, 1_800);
+        for block in &mut blocks {
+            block.hashrate_estimate = 1e12;
         }
 
-        Ok(())
-    }
-
-    /// Get Guardian statistics
-    pub fn get_guardian_stats(&self) -> GuardianStats {
-        let state = self.guardian_state.read();
+        // On-target blocks: small change, no vote needed
+        let small = bridge.generate_consensus_optimization(1_000, &blocks).unwrap();
+        assert!(small.ai_confidence >= VotingPolicy::default().governance_confidence);
+        assert!(small.difficulty_change_percent.abs() <= 5.0);
+        assert!(!small.requires_voting);
 
-        GuardianStats {
-            ai_enabled: state.ai_enabled,
-            auto_pilot_mode: state.auto_pilot_mode,
-            total_ai_decisions: state.total_ai_decisions,
-            guardian_vetoes: state.guardian_vetoes,
-            veto_rate: if state.total_ai_decisions > 0 {
-                (state.guardian_vetoes as f64 / state.total_ai_decisions as f64) * 100.0
-            } else {
-                0.0
-            },
-            last_veto_reason: state.last_veto_reason.clone(),
+        // A saturated mempool pushes min gas up by the full 10% swing
+        let busy = AIGuardianBridge::new(Arc::new(MultiLayerSecurityEngine::new(Default::default())));
+        for _ in 0..10 {
+            busy.record_mempool_size(1_000);
         }
+        let large = busy.generate_consensus_optimization(1_000, &blocks).unwrap();
+        assert!(large.gas_change_percent > 5.0, "{}", large.gas_change_percent);
+        assert!(large.requires_voting);
+
+        // A stricter policy sends even the small change to a vote
+        let config = BridgeConfig {
+            voting_policy: VotingPolicy { large_change_percent: 0.0, governance_confidence: 1.0, ..Default::default() },
+            ..Default::default()
+        };
+        let strict = AIGuardianBridge::try_new_with_config(
+            Arc::new(MultiLayerSecurityEngine::new(Default::default())),
+            config,
+        )
+        .unwrap();
+        assert!(strict.generate_consensus_optimization(1_000, &blocks).unwrap().requires_voting);
     }
-}
 
-// ==================== GUARDIAN DECISION ====================
+    #[test]
+    fn test_low_confidence_proposal_applies_after_vote() {
+        let config = BridgeConfig {
+            voting_policy: VotingPolicy { governance_confidence: 1.0, ..voting_config().voting_policy },
+            ..Default::default()
+        };
+        let bridge = AIGuardianBridge::try_new_with_config(
+            Arc::new(MultiLayerSecurityEngine::new(Default::default())),
+            config,
+        )
+        .unwrap();
+        let blocks = block_series(144, SovereignInvariants::TARGET_BLOCK_TIME_SECS / 2);
+        let proposal = bridge.generate_consensus_optimization(1_000, &blocks).unwrap();
+        assert!(proposal.requires_voting);
+        assert!(proposal.ai_confidence < VotingPolicy::default().min_apply_confidence);
 
-#[derive(Debug, Clone)]
-pub struct GuardianDecision {
-    pub approved: bool,
-    pub veto_reason: Option<String>,
-    pub action: GuardianAction,
-    pub threat_assessment: ThreatAssessment,
-}
+        // Too unsure to apply on its own...
+        assert!(bridge.apply_consensus_optimization(&proposal).is_err());
 
-#[derive(Debug, Clone)]
-pub enum GuardianAction {
-    Accept,
-    AcceptMonitored,
-    Quarantine { duration_blocks: u64 },
-    Reject,
-    AutoReject,
-    RequireManualReview { threat_level: RiskLevel },
-    ChainHalt,
-}
+        // ...but the validators' approval settles it
+        approve_by_vote(&bridge, &proposal.proposal_id);
+        bridge.apply_consensus_optimization(&proposal).unwrap();
+        assert_eq!(bridge.proposal_status(&proposal.proposal_id), Some(ProposalStatus::Applied));
+    }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GuardianStats {
-    pub ai_enabled: bool,
-    pub auto_pilot_mode: bool,
-    pub total_ai_decisions: u64,
-    pub guardian_vetoes: u64,
-    pub veto_rate: f64,
-    pub last_veto_reason: Option<String>,
-}
+    #[test]
+    fn test_controller_snapshot_roundtrip() {
+        let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
+        let bridge = AIGuardianBridge::new(engine.clone());
+        let proposal = bridge
+            .generate_consensus_optimization(1_000, &block_series(144, 900))
+            .unwrap();
+        bridge.apply_consensus_optimization(&proposal).unwrap();
+        bridge.consensus_ai.write().difficulty_pid.integral = 0.25;
 
-// ==================== CONSENSUS AI CONTROLLER ====================
+        let snapshot = bridge.export_controller_state();
+        let restored = ConsensusAIController::from_snapshot(NetworkProfile::Mainnet, &snapshot).unwrap();
+        assert_eq!(restored.parameters(), bridge.consensus_parameters());
+        assert_eq!(restored.difficulty_pid.integral, 0.25);
+        // Everything but the nonce, which moves past the snapshot's
+        let mut exported = restored.export_state();
+        assert_eq!(exported.application_nonce, snapshot.application_nonce + 1);
+        exported.application_nonce = snapshot.application_nonce;
+        exported.checksum = exported.compute_checksum();
+        assert_eq!(exported, snapshot);
 
-impl ConsensusAIController {
-    fn new() -> Self {
-        Self {
-            current_difficulty: 1000,
-            current_vdf_iterations: 1_000_000,
-            current_min_gas: 1000,
-            difficulty_pid: PIDController::new(0.5, 0.1, 0.05, 0.95, 1.05),
-            gas_pid: PIDController::new(0.3, 0.05, 0.02, 0.9, 1.1),
-            vdf_pid: PIDController::new(0.2, 0.03, 0.01, 0.98, 1.02),
-            block_time_history: Vec::with_capacity(1000),
-            hashrate_history: Vec::with_capacity(1000),
-            mempool_history: Vec::with_capacity(1000),
-            optimization_history: Vec::new(),
-        }
+        // Importing into a live bridge needs the manual override
+        let fresh = AIGuardianBridge::new(engine);
+        assert!(fresh.import_controller_state(&snapshot).is_err());
+        fresh.set_manual_override(true);
+        fresh.import_controller_state(&snapshot).unwrap();
+        assert_eq!(fresh.consensus_parameters(), bridge.consensus_parameters());
     }
 
-    fn update_metrics(&mut self, blocks: &[BlockMetrics]) -> Result<(), AxiomError> {
-        for block in blocks {
-            self.block_time_history.push(block.block_time);
-            self.hashrate_history.push(block.hashrate_estimate);
+    #[test]
+    fn test_controller_import_does_not_roll_back_nonce() {
+        let bridge = AIGuardianBridge::new(Arc::new(MultiLayerSecurityEngine::new(Default::default())));
+        let blocks = block_series(144, SovereignInvariants::TARGET_BLOCK_TIME_SECS / 2);
 
-            if self.block_time_history.len() > 1000 {
-                self.block_time_history.remove(0);
-                self.hashrate_history.remove(0);
-            }
-        }
-        Ok(())
+        // Two siblings at nonce 0 and a snapshot taken before either applies
+        let first = bridge.generate_consensus_optimization(1_000, &blocks).unwrap();
+        let stale = bridge.generate_consensus_optimization(1_001, &blocks).unwrap();
+        let snapshot = bridge.export_controller_state();
+        assert_eq!(snapshot.application_nonce, 0);
+        bridge.apply_consensus_optimization(&first).unwrap();
+
+        // Rolling back to the snapshot must not revive the stale sibling
+        bridge.set_manual_override(true);
+        bridge.import_controller_state(&snapshot).unwrap();
+        bridge.set_manual_override(false);
+        assert_eq!(bridge.consensus_ai.read().application_nonce, 2);
+        assert!(matches!(
+            bridge.apply_consensus_optimization(&stale),
+            Err(AxiomError::AIProposalRejected { reason }) if reason.contains("replayed or out of order")
+        ));
+        assert_eq!(bridge.consensus_parameters(), snapshot.parameters);
     }
 
-    fn calculate_difficulty_adjustment(&mut self) -> Result<u64, AxiomError> {
-        let target_time = SovereignInvariants::TARGET_BLOCK_TIME_SECS as f64;
-        let avg_time = self.block_time_history.iter().sum::<u64>() as f64
-            / self.block_time_history.len() as f64;
+    #[test]
+    fn test_controller_snapshot_rejects_tampering() {
+        let controller = ConsensusAIController::with_profile(NetworkProfile::Mainnet);
 
-        let error = (avg_time - target_time) / target_time;
-        let pid_output = self.difficulty_pid.update(error, 1.0);
+        let mut corrupted = controller.export_state();
+        corrupted.parameters.min_gas += 1;
+        assert!(matches!(
+            ConsensusAIController::from_snapshot(NetworkProfile::Mainnet, &corrupted),
+            Err(AxiomError::StateCorruption(msg)) if msg.contains("checksum")
+        ));
 
-        let new_difficulty = (self.current_difficulty as f64 * pid_output) as u64;
+        // Re-sealed, but below the manifest VDF floor
+        let mut weak_vdf = controller.export_state();
+        weak_vdf.parameters.vdf_iterations = SovereignInvariants::MINIMUM_VDF_ITERATIONS - 1;
+        weak_vdf.checksum = weak_vdf.compute_checksum();
+        assert!(matches!(
+            ConsensusAIController::from_snapshot(NetworkProfile::Mainnet, &weak_vdf),
+            Err(AxiomError::StateCorruption(msg)) if msg.contains("VDF")
+        ));
 
-        let max_change = (self.current_difficulty as f64 * 0.05) as u64;
-        let bounded = if new_difficulty > self.current_difficulty {
-            (self.current_difficulty + max_change).min(new_difficulty)
-        } else {
-            (self.current_difficulty.saturating_sub(max_change)).max(new_difficulty)
-        };
+        let mut non_finite = controller.export_state();
+        non_finite.gas_pid.integral = f64::NAN;
+        non_finite.checksum = non_finite.compute_checksum();
+        assert!(ConsensusAIController::from_snapshot(NetworkProfile::Mainnet, &non_finite).is_err());
 
-        Ok(bounded.max(100))
+        // A failed import leaves the controller as it was
+        let mut live = ConsensusAIController::with_profile(NetworkProfile::Mainnet);
+        let before = live.parameters();
+        assert!(live.import_state(&weak_vdf).is_err());
+        assert_eq!(live.parameters(), before);
     }
 
-    fn calculate_vdf_adjustment(&mut self) -> Result<u64, AxiomError> {
-        let avg_hashrate = if self.hashrate_history.is_empty() {
-            1e12
-        } else {
-            self.hashrate_history.iter().sum::<f64>() / self.hashrate_history.len() as f64
-        };
-
-        let ratio = (avg_hashrate / 1e12).ln();
-        let error = ratio * 0.1;
-        let pid_output = self.vdf_pid.update(error, 1.0);
-
-        let new_vdf = (self.current_vdf_iterations as f64 * pid_output) as u64;
+    /// Recommends monitoring at a fixed threshold of 40
+    struct MonitoringEngine;
 
-        let max_change = (self.current_vdf_iterations as f64 * 0.02) as u64;
-        let bounded = if new_vdf > self.current_vdf_iterations {
-            (self.current_vdf_iterations + max_change).min(new_vdf)
-        } else {
-            (self.current_vdf_iterations.saturating_sub(max_change)).max(new_vdf)
-        };
+    impl ThreatAssessor for MonitoringEngine {
+        fn assess_transaction_threat(
+            &self,
+            _profile: &TransactionRiskProfile,
+            _current_block_height: u64,
+        ) -> Result<ThreatAssessment, AxiomError> {
+            let mut assessment = unassessed("borderline".to_string());
+            assessment.threat_score = 55.0;
+            assessment.risk_level = RiskLevel::Medium;
+            assessment.recommended_action = SecurityAction::AcceptWithMonitoring;
+            Ok(assessment)
+        }
 
-        Ok(bounded.max(SovereignInvariants::MINIMUM_VDF_ITERATIONS))
+        fn monitoring_threshold(&self) -> Option<f64> {
+            Some(40.0)
+        }
     }
 
-    fn calculate_gas_adjustment(&mut self) -> Result<u64, AxiomError> {
-        let avg_mempool = if self.mempool_history.is_empty() {
-            500
-        } else {
-            self.mempool_history.iter().sum::<usize>() / self.mempool_history.len()
-        };
+    #[test]
+    fn test_monitored_accept_carries_rationale() {
+        let bridge = AIGuardianBridge::new_with_assessor(Arc::new(MonitoringEngine), BridgeConfig::default()).unwrap();
 
-        let error = (avg_mempool as f64 - 500.0) / 500.0;
-        let pid_output = self.gas_pid.update(error, 1.0);
+        let decision = bridge.validate_transaction_with_guardian(tx_profile(2_000), 100).unwrap();
+        assert!(decision.approved);
+        assert!(matches!(decision.action, GuardianAction::AcceptMonitored));
+        assert_eq!(
+            decision.monitoring_rationale.as_deref(),
+            Some("threat_score 55.00 above monitoring threshold 40.00")
+        );
 
-        let new_gas = (self.current_min_gas as f64 * pid_output) as u64;
+        // Plain accepts need no explanation
+        let accepted = AIGuardianBridge::new_with_assessor(Arc::new(ScoreByAmount), BridgeConfig::default()).unwrap()
+            .validate_transaction_with_guardian(tx_profile(2_000), 100)
+            .unwrap();
+        assert!(accepted.monitoring_rationale.is_none());
+    }
 
-        let max_change = (self.current_min_gas as f64 * 0.10) as u64;
-        let bounded = if new_gas > self.current_min_gas {
-            (self.current_min_gas + max_change).min(new_gas)
-        } else {
-            (self.current_min_gas.saturating_sub(max_change)).max(new_gas)
-        };
+    /// Scores by amount, with a monitoring threshold at 40
+    struct ThresholdedEngine;
 
-        Ok(bounded.max(SovereignInvariants::MIN_TRANSACTION_FEE))
-    }
+    impl ThreatAssessor for ThresholdedEngine {
+        fn assess_transaction_threat(
+            &self,
+            profile: &TransactionRiskProfile,
+            current_block_height: u64,
+        ) -> Result<ThreatAssessment, AxiomError> {
+            ScoreByAmount.assess_transaction_threat(profile, current_block_height)
+        }
 
-    fn calculate_hashrate_trend(&self) -> Result<f64, AxiomError> {
-        if self.hashrate_history.len() < 2 {
-            return Ok(0.0);
+        fn monitoring_threshold(&self) -> Option<f64> {
+            Some(40.0)
         }
+    }
 
-        let recent = *self.hashrate_history.last().unwrap();
-        let older = self.hashrate_history[0];
+    #[test]
+    fn test_decision_confidence_reflects_threshold_margin() {
+        let bridge = AIGuardianBridge::new_with_assessor(Arc::new(ThresholdedEngine), BridgeConfig::default()).unwrap();
+        let decide = |amount: u64| {
+            let mut profile = tx_profile(2_000);
+            profile.amount = amount;
+            bridge.validate_transaction_with_guardian(profile, 100).unwrap()
+        };
 
-        Ok((recent - older) / older)
+        // 35 points below the threshold: certain
+        assert_eq!(decide(5).decision_confidence, 1.0);
+        // 2 points away: barely
+        let borderline = decide(42).decision_confidence;
+        assert!((borderline - 0.1).abs() < 1e-9, "{}", borderline);
+
+        // The real engine publishes all three of its thresholds
+        let engine = MultiLayerSecurityEngine::new(Default::default());
+        assert_eq!(engine.action_thresholds().len(), 3);
     }
 
-    fn calculate_mempool_congestion(&self) -> Result<f64, AxiomError> {
-        if self.mempool_history.is_empty() {
-            return Ok(0.0);
-        }
+    #[test]
+    fn test_concurrent_validations_count_exactly() {
+        const THREADS: u64 = 8;
+        const PER_THREAD: u64 = 256;
+        let bridge = AIGuardianBridge::new_with_assessor(Arc::new(ScoreByAmount), BridgeConfig::default()).unwrap();
 
-        let avg = self.mempool_history.iter().sum::<usize>() as f64 / self.mempool_history.len() as f64;
-        Ok((avg / 1000.0).min(1.0))
-    }
+        let rejected: u64 = std::thread::scope(|scope| {
+            let workers: Vec<_> = (0..THREADS)
+                .map(|_| {
+                    scope.spawn(|| {
+                        (0..PER_THREAD)
+                            .filter(|i| {
+                                // Every fourth transaction underpays the minimum fee
+                                let mut profile = tx_profile(if i % 4 == 0 { 1 } else { 2_000 });
+                                profile.amount = 10;
+                                !bridge.validate_transaction_with_guardian(profile, 100).unwrap().approved
+                            })
+                            .count() as u64
+                    })
+                })
+                .collect();
+            workers.into_iter().map(|worker| worker.join().unwrap()).sum()
+        });
 
-    fn calculate_network_health_score(&self) -> Result<f64, AxiomError> {
-        let block_time_score = self.calculate_block_time_stability()?;
-        let hashrate_score = self.calculate_hashrate_stability()?;
-        Ok((block_time_score + hashrate_score) / 2.0)
+        let stats = bridge.get_guardian_stats();
+        assert_eq!(stats.total_ai_decisions, THREADS * PER_THREAD);
+        assert_eq!(stats.guardian_vetoes, rejected);
+        assert_eq!(rejected, THREADS * PER_THREAD / 4);
     }
 
-    fn calculate_block_time_stability(&self) -> Result<f64, AxiomError> {
-        if self.block_time_history.is_empty() {
-            return Ok(0.5);
-        }
+    #[test]
+    fn test_asymmetric_difficulty_steps() {
+        let tuning = ControllerTuning {
+            difficulty_max_change: 0.05,
+            difficulty_max_decrease: Some(0.02),
+            ..Default::default()
+        };
+        tuning.validate().unwrap();
+
+        // Blocks far too fast: difficulty rises by the full 5%
+        let mut fast = ConsensusAIController::with_tuning(NetworkProfile::Mainnet, tuning);
+        fast.current_difficulty = 10_000;
+        fast.update_metrics(&block_series(200, 10)).unwrap();
+        assert_eq!(fast.calculate_difficulty_adjustment().unwrap(), 10_500);
 
-        let target = SovereignInvariants::TARGET_BLOCK_TIME_SECS as f64;
-        let avg = self.block_time_history.iter().sum::<u64>() as f64 / self.block_time_history.len() as f64;
+        // Blocks far too slow: it only falls by about 2%
+        let mut slow = ConsensusAIController::with_tuning(NetworkProfile::Mainnet, tuning);
+        slow.current_difficulty = 10_000;
+        slow.update_metrics(&block_series(200, 3_000)).unwrap();
+        let lowered = slow.calculate_difficulty_adjustment().unwrap();
+        assert!((9_800..10_000).contains(&lowered), "gentle decrease expected, got {}", lowered);
 
-        let deviation = ((avg - target) / target).abs();
-        Ok((1.0 - deviation).max(0.0).min(1.0))
+        // The decrease is held to the manifest ceiling too
+        let too_wide = ControllerTuning {
+            difficulty_max_decrease: Some(0.08),
+            ..Default::default()
+        };
+        assert!(too_wide.validate().is_err());
     }
 
-    fn calculate_hashrate_stability(&self) -> Result<f64, AxiomError> {
-        if self.hashrate_history.len() < 2 {
-            return Ok(0.5);
+    #[test]
+    fn test_export_decisions_csv() {
+        let bridge = AIGuardianBridge::new_with_assessor(Arc::new(ScoreByAmount), BridgeConfig::default()).unwrap();
+        for fee in [2_000, 1, 2_000] {
+            let mut profile = tx_profile(fee);
+            profile.amount = 10;
+            bridge.validate_transaction_with_guardian(profile, 100).unwrap();
         }
+        // Non-decision audit entries are skipped
+        bridge.set_manual_override(true);
 
-        let mean = self.hashrate_history.iter().sum::<f64>() / self.hashrate_history.len() as f64;
-        let variance = self
-            .hashrate_history
-            .iter()
-            .map(|x| (x - mean).powi(2))
-            .sum::<f64>()
-            / self.hashrate_history.len() as f64;
+        let mut csv = Vec::new();
+        bridge.export_decisions_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
 
-        let cv = variance.sqrt() / mean;
-        Ok((1.0 - cv).max(0.0).min(1.0))
+        assert_eq!(lines[0], "timestamp,action,approved,veto_category,threat_score,amount");
+        assert_eq!(lines.len(), 4);
+        assert!(lines[1].ends_with(",Accept,true,,10,10"));
+        assert!(lines[2].ends_with(",Reject,false,minimum_fee,10,10"));
     }
 
-    fn calculate_confidence(&self) -> Result<f64, AxiomError> {
-        if self.block_time_history.len() < 144 {
-            return Ok(0.5);
+    #[test]
+    fn test_audit_walk_does_not_block_appends() {
+        let entry = |ts: u64| AuditEntry {
+            timestamp: ts,
+            record: AuditRecord::StateChanged { field: GuardianFlag::AutoPilotMode, old: false, new: true },
+        };
+        let total = AUDIT_EXPORT_CHUNK * 2 + 10;
+        let sink = InMemoryAuditSink::new(total);
+        for ts in 0..total as u64 {
+            sink.append(&entry(ts)).unwrap();
         }
 
-        let data_quality = (self.block_time_history.len() as f64 / 1000.0).min(1.0);
-        let stability = self.calculate_network_health_score()?;
+        // Appending from inside the visitor would deadlock under a held lock.
+        // Each append evicts an already-visited entry, so every original
+        // entry is still seen once, in order, and the new ones are not.
+        let mut seen = Vec::new();
+        sink.for_each_recent(&mut |visited| {
+            seen.push(visited.timestamp);
+            sink.append(&entry(1_000_000 + visited.timestamp))
+        })
+        .unwrap();
 
-        Ok((data_quality + stability) / 2.0)
+        assert_eq!(seen, (0..total as u64).collect::<Vec<_>>());
+        assert_eq!(sink.len(), total);
+        assert_eq!(sink.recent()[0].timestamp, 1_000_000);
     }
 
-    fn calculate_expected_improvement(&self) -> Result<f64, AxiomError> {
-        let target = SovereignInvariants::TARGET_BLOCK_TIME_SECS as f64;
-        let current_avg =
-            self.block_time_history.iter().sum::<u64>() as f64 / self.block_time_history.len() as f64;
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_validation_emits_span() {
+        use std::sync::Mutex;
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
 
-        let current_deviation = ((current_avg - target) / target).abs();
-        Ok((current_deviation * 50.0).min(20.0))
-    }
-}
+        struct FieldRecorder<'a>(&'a mut Vec<(String, String)>);
 
-// ==================== PID CONTROLLER ====================
+        impl Visit for FieldRecorder<'_> {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0.push((field.name().to_string(), format!("{:?}", value)));
+            }
+        }
 
-impl PIDController {
-    fn new(kp: f64, ki: f64, kd: f64, output_min: f64, output_max: f64) -> Self {
-        Self {
-            kp,
-            ki,
-            kd,
-            integral: 0.0,
-            previous_error: 0.0,
-            output_min,
-            output_max,
+        /// Keeps every span's name and recorded fields
+        #[derive(Clone, Default)]
+        struct SpanCapture(Arc<Mutex<Vec<(&'static str, Vec<(String, String)>)>>>);
+
+        impl tracing::Subscriber for SpanCapture {
+            fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut spans = self.0.lock().unwrap();
+                let mut fields = Vec::new();
+                span.record(&mut FieldRecorder(&mut fields));
+                spans.push((span.metadata().name(), fields));
+                Id::from_u64(spans.len() as u64)
+            }
+            fn record(&self, span: &Id, values: &Record<'_>) {
+                let mut spans = self.0.lock().unwrap();
+                let (_, fields) = &mut spans[span.into_u64() as usize - 1];
+                values.record(&mut FieldRecorder(fields));
+            }
+            fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+            fn event(&self, _event: &tracing::Event<'_>) {}
+            fn enter(&self, _span: &Id) {}
+            fn exit(&self, _span: &Id) {}
         }
-    }
 
-    fn update(&mut self, error: f64, dt: f64) -> f64 {
-        self.integral += error * dt;
-        let derivative = (error - self.previous_error) / dt;
-        self.previous_error = error;
+        let capture = SpanCapture::default();
+        let bridge = AIGuardianBridge::new_with_assessor(Arc::new(ScoreByAmount), BridgeConfig::default()).unwrap();
+        tracing::subscriber::with_default(capture.clone(), || {
+            bridge.validate_transaction_with_guardian(tx_profile(1), 100).unwrap();
+        });
 
-        let output = self.kp * error + self.ki * self.integral + self.kd * derivative;
-        output.max(self.output_min).min(self.output_max)
+        let spans = capture.0.lock().unwrap();
+        let (_, fields) = spans
+            .iter()
+            .find(|(name, _)| *name == "validate_transaction_with_guardian")
+            .expect("validation span emitted");
+        let field = |name: &str| fields.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str());
+        assert_eq!(field("approved"), Some("false"));
+        assert_eq!(field("action"), Some("\"Reject\""));
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
     #[test]
-    fn test_guardian_bridge_creation() {
+    fn test_emergency_lockdown_and_release() {
         let engine = Arc::new(MultiLayerSecurityEngine::new(Default::default()));
-        let bridge = AIGuardianBridge::new(engine);
+        let config = BridgeConfig {
+            breaker_recovery: Some(BreakerRecovery {
+                probe_blocks: 10,
+                admit_fraction: 0.25,
+            }),
+            ..Default::default()
+        };
+        let bridge = AIGuardianBridge::try_new_with_config(engine, config).unwrap();
+        let sentinel = crate::guardian_sentinel::SovereignGuardian::new().with_lockdown_signal(bridge.lockdown_signal());
+        let authorization = || ResetAuthorization {
+            operator: "ops-1".to_string(),
+            reason: "confirmed double-spend campaign".to_string(),
+        };
+        bridge.set_auto_pilot_mode(true);
+        bridge.drain_events();
+
+        let anonymous = ResetAuthorization { operator: " ".to_string(), ..authorization() };
+        assert!(bridge.emergency_lockdown("attack".to_string(), anonymous).is_err());
+        assert!(!bridge.is_locked_down());
+
+        bridge.emergency_lockdown("attack".to_string(), authorization()).unwrap();
+        let snapshot = bridge.full_snapshot();
+        assert_eq!(snapshot.circuit_breaker_state, BreakerState::Tripped);
+        assert_eq!(snapshot.circuit_breaker_severity, Some(BreakerSeverity::High));
+        assert_eq!(snapshot.circuit_breaker_category, Some(BreakerReason::EmergencyLockdown));
+        assert_eq!(snapshot.circuit_breaker_auto_recovery_block, None);
         let stats = bridge.get_guardian_stats();
-        assert_eq!(stats.total_ai_decisions, 0);
+        assert!(!stats.auto_pilot_mode);
+        assert!(stats.optimization_paused);
+        assert!(sentinel.is_locked_down());
+        let events = bridge.drain_events();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(&events[0], GuardianEvent::EmergencyLockdown { operator, .. } if operator == "ops-1"));
+
+        // Nothing undoes the lockdown except an explicit release
+        assert!(bridge.emergency_lockdown("again".to_string(), authorization()).is_err());
+        assert!(bridge.deactivate_circuit_breaker().is_err());
+        bridge.set_auto_pilot_mode(true);
+        bridge.resume_optimization();
+        bridge.advance_breaker(10_000);
+        assert_eq!(bridge.full_snapshot().circuit_breaker_state, BreakerState::Tripped);
+        assert!(bridge.validate_transaction_with_guardian(tx_profile(2_000), 10_000).is_err());
+        let stats = bridge.get_guardian_stats();
+        assert!(!stats.auto_pilot_mode);
+        assert!(stats.optimization_paused);
+
+        bridge.release_lockdown(authorization()).unwrap();
+        assert!(!bridge.is_locked_down());
+        assert_eq!(bridge.full_snapshot().circuit_breaker_state, BreakerState::Armed);
+        let stats = bridge.get_guardian_stats();
+        assert!(stats.auto_pilot_mode);
+        assert!(!stats.optimization_paused);
+        assert!(!sentinel.is_locked_down());
+        assert!(matches!(bridge.drain_events().as_slice(), [GuardianEvent::LockdownReleased { .. }]));
+        assert!(bridge.release_lockdown(authorization()).is_err());
+
+        // A genuine trip during a lockdown is reported and outlives it
+        bridge.emergency_lockdown("attack".to_string(), authorization()).unwrap();
+        bridge.drain_events();
+        bridge
+            .activate_circuit_breaker_for(10_001, BreakerReason::SupplyBreach, "supply mismatch".to_string())
+            .unwrap();
+        assert!(matches!(
+            bridge.drain_events().as_slice(),
+            [GuardianEvent::CircuitBreakerActivated { category: BreakerReason::SupplyBreach, .. }]
+        ));
+        assert_eq!(bridge.full_snapshot().circuit_breaker_category, Some(BreakerReason::EmergencyLockdown));
+        bridge.release_lockdown(authorization()).unwrap();
+        let snapshot = bridge.full_snapshot();
+        assert_eq!(snapshot.circuit_breaker_state, BreakerState::Tripped);
+        assert_eq!(snapshot.circuit_breaker_category, Some(BreakerReason::SupplyBreach));
+        assert_eq!(snapshot.circuit_breaker_activation_block, Some(10_001));
     }
 }
@@ -5,9 +5,62 @@ pub mod ai_guardian_bridge;
 
 pub use ai_guardian_bridge::{
     AIGuardianBridge,
+    AIGuardianBridgeBuilder,
+    Clock,
+    SystemClock,
+    BridgeSnapshot,
+    BridgeConfig,
+    BreakerSeverity,
+    BreakerState,
+    BreakerRecovery,
+    BreakerReason,
+    BreakerCallback,
+    CircuitBreaker,
+    HashrateDropRule,
+    EngineFailurePolicy,
+    FeeAnomaly,
+    FeeRatioBand,
+    ConsensusAIController,
+    ControllerTuning,
+    ControllerSnapshot,
+    PIDState,
     ConsensusOptimizationProposal,
     BlockMetrics,
+    FeePriority,
+    FeePercentiles,
+    AppliedParameters,
+    ResetAuthorization,
+    VetoCategory,
+    DecisionRow,
+    PROPOSAL_SCHEMA_VERSION,
+    PreApproval,
+    VotingPolicy,
+    PIDGains,
+    PIDPreset,
+    AutoPilotScope,
+    ProposalDelta,
+    ParameterDelta,
+    PendingReview,
+    ProposalStatus,
+    ReviewTimeoutBreaker,
+    ReviewTimeoutResolution,
+    ReviewQueueOverflow,
+    EscalationFallback,
+    Telemetry,
+    RollingThreatBreaker,
+    VetoRateAlarm,
     GuardianDecision,
     GuardianAction,
     GuardianStats,
+    LatencySummary,
+    GuardianFlag,
+    GuardianEvent,
+    AuditEntry,
+    AuditRecord,
+    AuditSink,
+    InMemoryAuditSink,
+    ThreatAdjuster,
 };
+
+// Defined with the manifest checks that take it; re-exported for existing users
+pub use crate::guardian::ConsensusParameters;
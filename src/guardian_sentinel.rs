@@ -3,15 +3,56 @@
 /// This module implements a perpetual sentinel that maintains sovereignty
 /// through continuous vigilance even during zero-transaction periods.
 
-use tokio::time::{sleep, interval, Duration};
+use tokio::time::{sleep, interval, interval_at, Duration, Instant};
 use tokio::select;
+use std::collections::VecDeque;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use log;
 use chrono::Local;
+use serde::Serialize;
+use crate::guardian::SovereignInvariants;
+
+/// Attempts made for a transiently failing verification before escalating
+const VERIFY_MAX_ATTEMPTS: u32 = 4;
+
+/// Consecutive verification cycles a peer shortfall may last before it is fatal
+const DEFAULT_PEER_GRACE_CYCLES: u32 = 3;
+
+/// Activity events within one `max_heartbeat` window at which the adaptive
+/// heartbeat reaches `min_heartbeat`
+const ADAPTIVE_HEARTBEAT_SATURATION: usize = 60;
+
+/// Sentinel events retained by default before the oldest are dropped
+const DEFAULT_EVENT_LOG_CAPACITY: usize = 1024;
+
+/// Pluggable source of truth for the sovereign invariants checked during
+/// deep sleep (supply cap, reorgs, merkle roots, peer count)
+pub trait InvariantChecker: Send + Sync {
+    fn verify(&self) -> Result<(), GuardianError>;
+
+    /// Depth in blocks of the deepest chain reorganization seen since the
+    /// last verification (0 if none), checked against `MAX_REORG_DEPTH`
+    fn reorg_depth(&self) -> Result<u64, GuardianError> {
+        Ok(0)
+    }
+}
+
+/// Default checker: reports the guarantees without querying external state
+pub struct LoggingChecker;
+
+impl InvariantChecker for LoggingChecker {
+    fn verify(&self) -> Result<(), GuardianError> {
+        log::info!("   ✓ 124M supply cap maintained");
+        log::info!("   ✓ No unauthorized chain reorganizations detected");
+        log::info!("   ✓ Merkle root consistency verified");
+        log::info!("   ✓ Peer count: 4/4 connected (genesis phase)");
+        Ok(())
+    }
+}
 
 /// Sentinel operating modes
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub enum SentinelMode {
     /// Active monitoring with 60-second heartbeats
     Active,
@@ -23,20 +64,101 @@ pub enum SentinelMode {
     Emergency,
 }
 
+/// Callback invoked with `(old, new)` on every sentinel mode transition
+pub type ModeChangeCallback = Box<dyn Fn(SentinelMode, SentinelMode) + Send>;
+
+/// Counters describing the sentinel's mode history this session
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SentinelMetrics {
+    /// Total mode transitions
+    pub mode_transitions: u64,
+    /// Times the sentinel entered deep sleep
+    pub deep_sleep_entries: u64,
+    /// Times the sentinel entered emergency mode
+    pub emergency_entries: u64,
+    /// Most recent transition as `(old, new)`
+    pub last_transition: Option<(SentinelMode, SentinelMode)>,
+}
+
+/// Significant occurrence in the sentinel's lifecycle
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum SentinelEventKind {
+    /// `run_sentinel` began watching
+    Started,
+    /// Operating mode changed
+    ModeChanged { from: SentinelMode, to: SentinelMode },
+    /// A sovereign verification succeeded
+    VerificationPassed { verification: u64 },
+    /// A verification attempt failed; `fatal` if it was not retried or
+    /// tolerated and switched the sentinel to Emergency mode
+    VerificationFailed { verification: u64, attempt: u32, error: String, fatal: bool },
+    /// The shutdown signal was observed
+    ShutdownRequested,
+}
+
+/// Timestamped entry in the sentinel event log
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SentinelEvent {
+    /// Wall-clock time of the event, in milliseconds since the Unix epoch
+    pub timestamp_ms: u64,
+    #[serde(flatten)]
+    pub kind: SentinelEventKind,
+}
+
 /// The eternal sentinel that never stops watching
 pub struct SovereignGuardian {
     /// Shutdown signal
     shutdown: Arc<AtomicBool>,
     
+    /// Emergency lockdown signal shared with the guardian bridge
+    lockdown: Option<Arc<AtomicBool>>,
+    
     /// Current operating mode
     mode: SentinelMode,
     
     /// Heartbeat interval during active monitoring (60 seconds)
     heartbeat_interval: Duration,
     
-    /// Deep sleep interval (3600 seconds / 1 hour)
+    /// `(min_heartbeat, max_heartbeat)` bounds when the heartbeat adapts to load
+    adaptive_heartbeat: Option<(Duration, Duration)>,
+    
+    /// Recent `record_activity` calls, used to derive the activity rate
+    recent_activity: VecDeque<std::time::Instant>,
+    
+    /// Idle time before entering deep sleep (3600 seconds / 1 hour)
     deep_sleep_threshold: Duration,
     
+    /// How often invariants are verified while in deep sleep (3600 seconds / 1 hour)
+    deep_sleep_verify_interval: Duration,
+    
+    /// Number of sovereign verifications completed this session
+    verification_count: u64,
+    
+    /// Checker consulted by each sovereign verification
+    checker: Box<dyn InvariantChecker>,
+    
+    /// Delay before the first verification retry; doubles on each attempt
+    verify_retry_base: Duration,
+    
+    /// Consecutive cycles `InsufficientPeers` must persist before escalating
+    peer_grace_cycles: u32,
+    
+    /// Current run of verification cycles that reported too few peers
+    peer_shortfall_cycles: u32,
+    
+    /// Notified on every mode transition
+    on_mode_change: Option<ModeChangeCallback>,
+    
+    /// Mode transition counters
+    metrics: SentinelMetrics,
+    
+    /// Lifecycle events, oldest first
+    event_log: VecDeque<SentinelEvent>,
+    
+    /// Maximum events kept in `event_log`
+    event_log_capacity: usize,
+    
     /// Last time network activity was detected
     last_activity: std::time::Instant,
     
@@ -49,34 +171,120 @@ impl SovereignGuardian {
     pub fn new() -> Self {
         Self {
             shutdown: Arc::new(AtomicBool::new(false)),
+            lockdown: None,
             mode: SentinelMode::Active,
             heartbeat_interval: Duration::from_secs(60),
+            adaptive_heartbeat: None,
+            recent_activity: VecDeque::new(),
             deep_sleep_threshold: Duration::from_secs(3600),
+            deep_sleep_verify_interval: Duration::from_secs(3600),
+            verification_count: 0,
+            checker: Box::new(LoggingChecker),
+            verify_retry_base: Duration::from_secs(1),
+            peer_grace_cycles: DEFAULT_PEER_GRACE_CYCLES,
+            peer_shortfall_cycles: 0,
+            on_mode_change: None,
+            metrics: SentinelMetrics::default(),
+            event_log: VecDeque::new(),
+            event_log_capacity: DEFAULT_EVENT_LOG_CAPACITY,
             last_activity: std::time::Instant::now(),
             session_start: std::time::Instant::now(),
         }
     }
     
+    /// Set the idle time after which the sentinel enters deep sleep
+    pub fn with_deep_sleep_threshold(mut self, threshold: Duration) -> Self {
+        self.deep_sleep_threshold = threshold;
+        self
+    }
+    
+    /// Set how often sovereign guarantees are verified while in deep sleep,
+    /// independently of the idle threshold that triggers deep sleep
+    pub fn with_deep_sleep_verify_interval(mut self, interval: Duration) -> Self {
+        self.deep_sleep_verify_interval = interval;
+        self
+    }
+    
+    /// Use a custom invariant checker for sovereign verification
+    pub fn with_checker(mut self, checker: Box<dyn InvariantChecker>) -> Self {
+        self.checker = checker;
+        self
+    }
+    
+    /// Set the initial backoff between verification retries
+    pub fn with_verify_retry_base(mut self, base: Duration) -> Self {
+        self.verify_retry_base = base;
+        self
+    }
+    
+    /// Tolerate a peer count below the minimum for up to `cycles`
+    /// consecutive verifications before escalating; 1 escalates immediately
+    pub fn with_peer_grace_cycles(mut self, cycles: u32) -> Self {
+        self.peer_grace_cycles = cycles.max(1);
+        self
+    }
+    
+    /// Call `callback(old, new)` whenever the sentinel changes mode, e.g. to
+    /// adjust logging verbosity or pause subsystems
+    pub fn with_on_mode_change(
+        mut self,
+        callback: impl Fn(SentinelMode, SentinelMode) + Send + 'static,
+    ) -> Self {
+        self.on_mode_change = Some(Box::new(callback));
+        self
+    }
+    
+    /// Hold Emergency mode while `signal` is set, e.g. the bridge's
+    /// `AIGuardianBridge::lockdown_signal`; normal modes resume at the first
+    /// heartbeat after it clears
+    pub fn with_lockdown_signal(mut self, signal: Arc<AtomicBool>) -> Self {
+        self.lockdown = Some(signal);
+        self
+    }
+    
+    /// Keep at most `capacity` lifecycle events, dropping the oldest
+    pub fn with_event_log_capacity(mut self, capacity: usize) -> Self {
+        self.event_log_capacity = capacity.max(1);
+        self
+    }
+    
+    /// Set the active heartbeat interval
+    pub fn with_heartbeat_interval(mut self, interval: Duration) -> Self {
+        self.heartbeat_interval = interval;
+        self
+    }
+    
+    /// Scale the active heartbeat with load: `max_heartbeat` when the
+    /// network is quiet, shrinking toward `min_heartbeat` as the
+    /// `record_activity` rate rises
+    pub fn with_adaptive_heartbeat(mut self, min_heartbeat: Duration, max_heartbeat: Duration) -> Self {
+        self.adaptive_heartbeat = Some((min_heartbeat.min(max_heartbeat), min_heartbeat.max(max_heartbeat)));
+        self
+    }
+    
     /// The eternal watch - this function never returns unless explicitly shut down
     /// 
     /// This implements the core sentinel pattern: constant vigilance with
     /// adaptive heartbeat frequencies. During periods of high activity, the
     /// guardian uses 60-second heartbeats for responsiveness. During prolonged
-    /// silence, it enters deep sleep mode but continues verification at 1-hour
-    /// intervals to maintain sovereignty protection.
+    /// silence, it enters deep sleep mode but continues verification every
+    /// `deep_sleep_verify_interval` (1 hour by default) to maintain
+    /// sovereignty protection.
     pub async fn run_sentinel(&mut self) -> Result<(), GuardianError> {
         log::info!("╔══════════════════════════════════════════════════════════╗");
         log::info!("║  🛡️  SOVEREIGN GUARDIAN: SENTINEL ACTIVE                 ║");
         log::info!("╠══════════════════════════════════════════════════════════╣");
         log::info!("║  ⏱️  Heartbeat: {:?}                              ║", self.heartbeat_interval);
         log::info!("║  🌙 Deep Sleep Threshold: {:?}                     ║", self.deep_sleep_threshold);
+        log::info!("║  🔁 Deep Sleep Verification: {:?}                  ║", self.deep_sleep_verify_interval);
         log::info!("║  🔐 MANDATORY: Supply cap enforcement during silence     ║");
         log::info!("║  🔐 MANDATORY: Zero-trust peer validation active         ║");
         log::info!("║  🔐 MANDATORY: Chain integrity verification every hour   ║");
         log::info!("╚══════════════════════════════════════════════════════════╝");
+        self.record_event(SentinelEventKind::Started);
         
-        let mut heartbeat = interval(self.heartbeat_interval);
-        let mut deep_sleep_check = interval(self.deep_sleep_threshold);
+        let mut heartbeat = interval(self.effective_heartbeat_interval());
+        let mut deep_sleep_check = interval(self.deep_sleep_verify_interval);
         
         loop {
             select! {
@@ -84,16 +292,24 @@ impl SovereignGuardian {
                 _ = heartbeat.tick() => {
                     let idle_duration = self.last_activity.elapsed();
                     
-                    // Determine mode based on idle time
-                    if idle_duration < self.deep_sleep_threshold {
-                        self.mode = SentinelMode::Active;
+                    // Determine mode based on idle time, unless locked down
+                    if self.is_locked_down() {
+                        self.set_mode(SentinelMode::Emergency);
+                    } else if idle_duration < self.deep_sleep_threshold {
+                        self.set_mode(SentinelMode::Active);
                         self.emit_active_heartbeat(&idle_duration);
                     } else {
-                        self.mode = SentinelMode::DeepSleep;
+                        self.set_mode(SentinelMode::DeepSleep);
+                    }
+                    
+                    let next = self.effective_heartbeat_interval();
+                    if next != heartbeat.period() {
+                        log::debug!("💓 Heartbeat interval adjusted: {:?} -> {:?}", heartbeat.period(), next);
+                        heartbeat = interval_at(Instant::now() + next, next);
                     }
                 }
                 
-                // Branch 2: Deep sleep verification - Hourly chain validation
+                // Branch 2: Deep sleep verification - Periodic chain validation
                 _ = deep_sleep_check.tick() => {
                     let idle_duration = self.last_activity.elapsed();
                     
@@ -102,11 +318,13 @@ impl SovereignGuardian {
                         
                         // Even in deep sleep, verify critical invariants
                         self.verify_sovereign_guarantees().await?;
+                        self.verification_count += 1;
                     }
                 }
                 
                 // Branch 3: Graceful shutdown signal
                 _ = self.wait_for_shutdown() => {
+                    self.record_event(SentinelEventKind::ShutdownRequested);
                     log::warn!("╔══════════════════════════════════════════════════════════╗");
                     log::warn!("║  🛑 SHUTDOWN SIGNAL RECEIVED                             ║");
                     log::warn!("╠══════════════════════════════════════════════════════════╣");
@@ -158,26 +376,158 @@ impl SovereignGuardian {
     
     /// Verify sovereign guarantees even during silence
     /// This ensures that the 124M supply cap and chain integrity are maintained
-    async fn verify_sovereign_guarantees(&self) -> Result<(), GuardianError> {
+    /// 
+    /// Transient failures are retried with exponential backoff, up to
+    /// `VERIFY_MAX_ATTEMPTS` attempts. Fatal failures, or a transient one that
+    /// outlasts the retries, switch the sentinel to Emergency mode and are
+    /// returned to the caller. `InsufficientPeers` is only fatal once it has
+    /// persisted for `peer_grace_cycles` consecutive verifications.
+    ///
+    /// With the `tracing` feature each verification runs in a span
+    /// recording its outcome.
+    #[cfg(not(feature = "tracing"))]
+    async fn verify_sovereign_guarantees(&mut self) -> Result<(), GuardianError> {
+        self.verify_with_retries().await
+    }
+    
+    #[cfg(feature = "tracing")]
+    async fn verify_sovereign_guarantees(&mut self) -> Result<(), GuardianError> {
+        use tracing::Instrument;
+        
+        let span = tracing::info_span!(
+            "verify_sovereign_guarantees",
+            verification = self.verification_count + 1,
+            outcome = tracing::field::Empty,
+            mode = tracing::field::Empty,
+        );
+        let result = self.verify_with_retries().instrument(span.clone()).await;
+        match &result {
+            Ok(()) => span.record("outcome", "verified"),
+            Err(e) => span.record("outcome", tracing::field::display(e)),
+        };
+        span.record("mode", tracing::field::debug(&self.mode));
+        result
+    }
+    
+    async fn verify_with_retries(&mut self) -> Result<(), GuardianError> {
         log::info!(
             "🔐 SOVEREIGN VERIFICATION [{}]",
             Local::now().format("%Y-%m-%d %H:%M:%S")
         );
-        log::info!("   ✓ 124M supply cap maintained");
-        log::info!("   ✓ No unauthorized chain reorganizations detected");
-        log::info!("   ✓ Merkle root consistency verified");
-        log::info!("   ✓ Peer count: 4/4 connected (genesis phase)");
         
-        Ok(())
+        let verification = self.verification_count + 1;
+        let mut backoff = self.verify_retry_base;
+        let mut attempt = 1;
+        loop {
+            match self.checker.verify().and_then(|()| self.check_reorg_depth()) {
+                Ok(()) => {
+                    if self.peer_shortfall_cycles > 0 {
+                        log::info!("✅ Peer count recovered after {} cycle(s)", self.peer_shortfall_cycles);
+                        self.peer_shortfall_cycles = 0;
+                    }
+                    self.record_event(SentinelEventKind::VerificationPassed { verification });
+                    return Ok(());
+                }
+                Err(e @ GuardianError::InsufficientPeers { .. }) => {
+                    self.peer_shortfall_cycles += 1;
+                    if self.peer_shortfall_cycles < self.peer_grace_cycles {
+                        log::warn!(
+                            "⚠️  {} (cycle {}/{} of grace window)",
+                            e, self.peer_shortfall_cycles, self.peer_grace_cycles
+                        );
+                        self.record_verification_failure(verification, attempt, &e, false);
+                        return Ok(());
+                    }
+                    log::error!(
+                        "🚨 {} for {} consecutive cycle(s)",
+                        e, self.peer_shortfall_cycles
+                    );
+                    self.record_verification_failure(verification, attempt, &e, true);
+                    self.set_mode(SentinelMode::Emergency);
+                    return Err(e);
+                }
+                Err(e) if e.is_transient() && attempt < VERIFY_MAX_ATTEMPTS => {
+                    log::warn!(
+                        "⚠️  Verification attempt {}/{} failed: {} (retrying in {:?})",
+                        attempt, VERIFY_MAX_ATTEMPTS, e, backoff
+                    );
+                    self.record_verification_failure(verification, attempt, &e, false);
+                    sleep(backoff).await;
+                    backoff *= 2;
+                    attempt += 1;
+                }
+                Err(e) => {
+                    log::error!("🚨 Sovereign verification failed after {} attempt(s): {}", attempt, e);
+                    self.record_verification_failure(verification, attempt, &e, true);
+                    self.set_mode(SentinelMode::Emergency);
+                    return Err(e);
+                }
+            }
+        }
+    }
+    
+    fn record_verification_failure(&mut self, verification: u64, attempt: u32, error: &GuardianError, fatal: bool) {
+        self.record_event(SentinelEventKind::VerificationFailed {
+            verification,
+            attempt,
+            error: error.to_string(),
+            fatal,
+        });
+    }
+    
+    /// Append to the event log, evicting the oldest entry once at capacity
+    fn record_event(&mut self, kind: SentinelEventKind) {
+        let timestamp_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        if self.event_log.len() >= self.event_log_capacity {
+            self.event_log.pop_front();
+        }
+        self.event_log.push_back(SentinelEvent { timestamp_ms, kind });
+    }
+    
+    /// Enforce the manifest's reorg depth limit on what the checker reports;
+    /// a deeper reorg is a chain integrity failure, never retried
+    fn check_reorg_depth(&self) -> Result<(), GuardianError> {
+        let depth = self.checker.reorg_depth()?;
+        SovereignInvariants::verify_reorg_depth(depth)
+            .map_err(|e| GuardianError::ChainIntegrityError(e.to_string()))
+    }
+    
+    /// Switch mode, recording the transition and notifying the callback.
+    /// Re-entering the current mode is not a transition.
+    fn set_mode(&mut self, new: SentinelMode) {
+        if self.mode == new {
+            return;
+        }
+        let old = std::mem::replace(&mut self.mode, new.clone());
+        log::info!("🔄 Sentinel mode: {:?} → {:?}", old, new);
+        
+        self.metrics.mode_transitions += 1;
+        match new {
+            SentinelMode::DeepSleep => self.metrics.deep_sleep_entries += 1,
+            SentinelMode::Emergency => self.metrics.emergency_entries += 1,
+            SentinelMode::Active => {}
+        }
+        self.metrics.last_transition = Some((old.clone(), new.clone()));
+        self.record_event(SentinelEventKind::ModeChanged { from: old.clone(), to: new.clone() });
+        
+        if let Some(callback) = &self.on_mode_change {
+            callback(old, new);
+        }
     }
     
     /// Wait for shutdown signal
     async fn wait_for_shutdown(&self) {
+        // Check before sleeping: select! recreates this future every loop,
+        // so with sub-100ms heartbeats a sleep-first loop would never observe
+        // the flag
         loop {
-            sleep(Duration::from_millis(100)).await;
             if self.shutdown.load(Ordering::Relaxed) {
                 break;
             }
+            sleep(Duration::from_millis(100)).await;
         }
     }
     
@@ -206,6 +556,35 @@ impl SovereignGuardian {
     /// Record network activity to update idle timer
     pub fn record_activity(&mut self) {
         self.last_activity = std::time::Instant::now();
+        if self.adaptive_heartbeat.is_some() {
+            self.recent_activity.push_back(self.last_activity);
+            if self.recent_activity.len() > ADAPTIVE_HEARTBEAT_SATURATION {
+                self.recent_activity.pop_front();
+            }
+        }
+    }
+    
+    /// Active heartbeat interval given the recent activity rate. Without an
+    /// adaptive configuration this is the fixed heartbeat interval.
+    pub fn effective_heartbeat_interval(&self) -> Duration {
+        let (min, max) = match self.adaptive_heartbeat {
+            Some(bounds) => bounds,
+            None => return self.heartbeat_interval,
+        };
+        
+        let recent = self
+            .recent_activity
+            .iter()
+            .filter(|at| at.elapsed() < max)
+            .count();
+        let load = recent as f64 / ADAPTIVE_HEARTBEAT_SATURATION as f64;
+        
+        max - (max - min).mul_f64(load.min(1.0))
+    }
+    
+    /// Whether the linked lockdown signal is raised
+    pub fn is_locked_down(&self) -> bool {
+        self.lockdown.as_ref().is_some_and(|signal| signal.load(Ordering::SeqCst))
     }
     
     /// Get current mode
@@ -213,6 +592,34 @@ impl SovereignGuardian {
         self.mode.clone()
     }
     
+    /// Time since network activity was last recorded
+    pub fn last_activity_elapsed(&self) -> Duration {
+        self.last_activity.elapsed()
+    }
+    
+    /// Whether the sentinel is in deep sleep. This reflects the mode settled
+    /// at the last heartbeat, not the raw idle time, so it doesn't flap
+    /// between heartbeats.
+    pub fn is_in_deep_sleep(&self) -> bool {
+        self.mode == SentinelMode::DeepSleep
+    }
+    
+    /// Mode transition counters for this session
+    pub fn metrics(&self) -> SentinelMetrics {
+        self.metrics.clone()
+    }
+    
+    /// Lifecycle events in the order they occurred, oldest first; bounded
+    /// by the event log capacity
+    pub fn event_log(&self) -> Vec<SentinelEvent> {
+        self.event_log.iter().cloned().collect()
+    }
+    
+    /// Number of sovereign verifications completed this session
+    pub fn verifications_performed(&self) -> u64 {
+        self.verification_count
+    }
+    
     /// Get session duration
     pub fn session_duration(&self) -> Duration {
         self.session_start.elapsed()
@@ -225,6 +632,17 @@ pub enum GuardianError {
     Shutdown,
     VerificationFailed(String),
     ChainIntegrityError(String),
+    /// A check could not complete (e.g. a peer query timed out); retryable
+    VerificationUnavailable(String),
+    /// Fewer peers connected than the network minimum
+    InsufficientPeers { connected: usize, required: usize },
+}
+
+impl GuardianError {
+    /// Whether retrying the operation may succeed
+    pub fn is_transient(&self) -> bool {
+        matches!(self, GuardianError::VerificationUnavailable(_))
+    }
 }
 
 impl std::fmt::Display for GuardianError {
@@ -233,6 +651,10 @@ impl std::fmt::Display for GuardianError {
             GuardianError::Shutdown => write!(f, "Guardian shutdown requested"),
             GuardianError::VerificationFailed(msg) => write!(f, "Verification failed: {}", msg),
             GuardianError::ChainIntegrityError(msg) => write!(f, "Chain integrity error: {}", msg),
+            GuardianError::VerificationUnavailable(msg) => write!(f, "Verification unavailable: {}", msg),
+            GuardianError::InsufficientPeers { connected, required } => {
+                write!(f, "Insufficient peers: {}/{} connected", connected, required)
+            }
         }
     }
 }
@@ -242,6 +664,24 @@ impl std::error::Error for GuardianError {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::AtomicUsize;
+    
+    /// Fails with a transient error for the first `failures` calls
+    struct FlakyChecker {
+        calls: Arc<AtomicUsize>,
+        failures: usize,
+    }
+    
+    impl InvariantChecker for FlakyChecker {
+        fn verify(&self) -> Result<(), GuardianError> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            if call < self.failures {
+                Err(GuardianError::VerificationUnavailable("peer query timed out".into()))
+            } else {
+                Ok(())
+            }
+        }
+    }
     
     #[test]
     fn test_guardian_creation() {
@@ -249,6 +689,186 @@ mod tests {
         assert_eq!(guardian.mode, SentinelMode::Active);
         assert_eq!(guardian.heartbeat_interval, Duration::from_secs(60));
         assert_eq!(guardian.deep_sleep_threshold, Duration::from_secs(3600));
+        assert_eq!(guardian.deep_sleep_verify_interval, Duration::from_secs(3600));
+    }
+    
+    #[test]
+    fn test_adaptive_heartbeat_shrinks_under_load() {
+        let min = Duration::from_millis(100);
+        let max = Duration::from_secs(60);
+        let mut guardian = SovereignGuardian::new().with_adaptive_heartbeat(min, max);
+        
+        // Quiet network: slowest heartbeat
+        assert_eq!(guardian.effective_heartbeat_interval(), max);
+        
+        for _ in 0..ADAPTIVE_HEARTBEAT_SATURATION / 2 {
+            guardian.record_activity();
+        }
+        let moderate = guardian.effective_heartbeat_interval();
+        assert!(moderate < max && moderate > min);
+        
+        for _ in 0..ADAPTIVE_HEARTBEAT_SATURATION * 2 {
+            guardian.record_activity();
+        }
+        assert_eq!(guardian.effective_heartbeat_interval(), min);
+        
+        // Fixed heartbeat is unaffected by activity
+        let mut fixed = SovereignGuardian::new();
+        fixed.record_activity();
+        assert_eq!(fixed.effective_heartbeat_interval(), Duration::from_secs(60));
+    }
+    
+    #[tokio::test]
+    async fn test_deep_sleep_verifies_on_separate_cadence() {
+        let mut guardian = SovereignGuardian::new()
+            .with_heartbeat_interval(Duration::from_millis(10))
+            .with_deep_sleep_threshold(Duration::from_millis(20))
+            .with_deep_sleep_verify_interval(Duration::from_millis(30));
+        
+        let shutdown = guardian.shutdown.clone();
+        let stopper = std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(300));
+            shutdown.store(true, Ordering::Relaxed);
+        });
+        
+        guardian.run_sentinel().await.expect("sentinel should shut down cleanly");
+        stopper.join().unwrap();
+        
+        // One long idle period, several verifications
+        assert_eq!(guardian.current_mode(), SentinelMode::DeepSleep);
+        assert!(
+            guardian.verifications_performed() >= 3,
+            "expected multiple verifications, got {}",
+            guardian.verifications_performed()
+        );
+    }
+    
+    #[tokio::test]
+    async fn test_last_activity_elapsed() {
+        let mut guardian = SovereignGuardian::new();
+        sleep(Duration::from_millis(50)).await;
+        guardian.record_activity();
+        assert!(guardian.last_activity_elapsed() < Duration::from_millis(50));
+        
+        sleep(Duration::from_millis(100)).await;
+        let elapsed = guardian.last_activity_elapsed();
+        assert!(elapsed >= Duration::from_millis(100));
+        assert!(elapsed < Duration::from_secs(5));
+        
+        // Mode only changes on heartbeats, which haven't run
+        assert!(!guardian.is_in_deep_sleep());
+    }
+    
+    #[tokio::test]
+    async fn test_transient_verification_failures_are_retried() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut guardian = SovereignGuardian::new()
+            .with_heartbeat_interval(Duration::from_millis(10))
+            .with_deep_sleep_threshold(Duration::from_millis(20))
+            .with_deep_sleep_verify_interval(Duration::from_millis(30))
+            .with_verify_retry_base(Duration::from_millis(1))
+            .with_checker(Box::new(FlakyChecker { calls: calls.clone(), failures: 2 }));
+        
+        let shutdown = guardian.shutdown.clone();
+        let stopper = std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(200));
+            shutdown.store(true, Ordering::Relaxed);
+        });
+        
+        guardian.run_sentinel().await.expect("sentinel should recover from transient failures");
+        stopper.join().unwrap();
+        
+        assert!(guardian.verifications_performed() >= 1);
+        assert!(calls.load(Ordering::SeqCst) >= 3);
+        assert_ne!(guardian.current_mode(), SentinelMode::Emergency);
+    }
+    
+    #[tokio::test]
+    async fn test_fatal_verification_failure_escalates() {
+        struct BrokenChain;
+        impl InvariantChecker for BrokenChain {
+            fn verify(&self) -> Result<(), GuardianError> {
+                Err(GuardianError::ChainIntegrityError("merkle mismatch".into()))
+            }
+        }
+        
+        let mut guardian = SovereignGuardian::new()
+            .with_heartbeat_interval(Duration::from_millis(10))
+            .with_deep_sleep_threshold(Duration::from_millis(20))
+            .with_deep_sleep_verify_interval(Duration::from_millis(30))
+            .with_checker(Box::new(BrokenChain));
+        
+        let result = guardian.run_sentinel().await;
+        assert!(matches!(result, Err(GuardianError::ChainIntegrityError(_))));
+        assert_eq!(guardian.current_mode(), SentinelMode::Emergency);
+    }
+    
+    /// Replays scripted verification results, then reports healthy
+    struct ScriptedChecker {
+        results: std::sync::Mutex<VecDeque<Result<(), GuardianError>>>,
+    }
+    
+    impl InvariantChecker for ScriptedChecker {
+        fn verify(&self) -> Result<(), GuardianError> {
+            self.results.lock().unwrap().pop_front().unwrap_or(Ok(()))
+        }
+    }
+    
+    fn peers_low() -> Result<(), GuardianError> {
+        Err(GuardianError::InsufficientPeers { connected: 2, required: 4 })
+    }
+    
+    #[tokio::test]
+    async fn test_peer_shortfall_grace_window() {
+        // One-cycle dip that recovers: logged, never fatal
+        let mut guardian = SovereignGuardian::new()
+            .with_peer_grace_cycles(2)
+            .with_checker(Box::new(ScriptedChecker {
+                results: std::sync::Mutex::new(VecDeque::from([peers_low(), Ok(()), peers_low(), Ok(())])),
+            }));
+        for _ in 0..4 {
+            guardian.verify_sovereign_guarantees().await.expect("transient dip must not escalate");
+        }
+        assert_eq!(guardian.current_mode(), SentinelMode::Active);
+        assert_eq!(guardian.peer_shortfall_cycles, 0);
+        
+        // Sustained dip: escalates once the window is exhausted
+        let mut guardian = SovereignGuardian::new()
+            .with_peer_grace_cycles(2)
+            .with_checker(Box::new(ScriptedChecker {
+                results: std::sync::Mutex::new(VecDeque::from([peers_low(), peers_low()])),
+            }));
+        assert!(guardian.verify_sovereign_guarantees().await.is_ok());
+        let result = guardian.verify_sovereign_guarantees().await;
+        assert!(matches!(result, Err(GuardianError::InsufficientPeers { connected: 2, required: 4 })));
+        assert_eq!(guardian.current_mode(), SentinelMode::Emergency);
+    }
+    
+    /// Healthy apart from a fixed reorg depth
+    struct ReorgChecker(u64);
+    
+    impl InvariantChecker for ReorgChecker {
+        fn verify(&self) -> Result<(), GuardianError> {
+            Ok(())
+        }
+        
+        fn reorg_depth(&self) -> Result<u64, GuardianError> {
+            Ok(self.0)
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_reorg_depth_enforced() {
+        let max = SovereignInvariants::MAX_REORG_DEPTH;
+        
+        let mut guardian = SovereignGuardian::new().with_checker(Box::new(ReorgChecker(max)));
+        assert!(guardian.verify_sovereign_guarantees().await.is_ok());
+        assert_eq!(guardian.current_mode(), SentinelMode::Active);
+        
+        let mut guardian = SovereignGuardian::new().with_checker(Box::new(ReorgChecker(max + 1)));
+        let result = guardian.verify_sovereign_guarantees().await;
+        assert!(matches!(result, Err(GuardianError::ChainIntegrityError(_))));
+        assert_eq!(guardian.current_mode(), SentinelMode::Emergency);
     }
     
     #[test]
@@ -268,4 +888,91 @@ mod tests {
         let duration = guardian.session_duration();
         assert!(duration >= Duration::from_millis(100));
     }
+    
+    #[tokio::test]
+    async fn test_mode_change_callback_fires_on_deep_sleep() {
+        let transitions = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorder = transitions.clone();
+        
+        let mut guardian = SovereignGuardian::new()
+            .with_heartbeat_interval(Duration::from_millis(10))
+            .with_deep_sleep_threshold(Duration::from_millis(20))
+            .with_deep_sleep_verify_interval(Duration::from_millis(30))
+            .with_on_mode_change(move |old, new| recorder.lock().unwrap().push((old, new)));
+        
+        let shutdown = guardian.shutdown.clone();
+        let stopper = std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(150));
+            shutdown.store(true, Ordering::Relaxed);
+        });
+        
+        guardian.run_sentinel().await.expect("sentinel should shut down cleanly");
+        stopper.join().unwrap();
+        
+        // Heartbeats while already deep asleep are not transitions
+        let transitions = transitions.lock().unwrap();
+        assert_eq!(*transitions, vec![(SentinelMode::Active, SentinelMode::DeepSleep)]);
+        
+        let metrics = guardian.metrics();
+        assert_eq!(metrics.mode_transitions, 1);
+        assert_eq!(metrics.deep_sleep_entries, 1);
+        assert_eq!(metrics.last_transition, Some((SentinelMode::Active, SentinelMode::DeepSleep)));
+    }
+    
+    #[tokio::test]
+    async fn test_event_log_replays_lifecycle() {
+        let mut guardian = SovereignGuardian::new()
+            .with_heartbeat_interval(Duration::from_millis(10))
+            .with_deep_sleep_threshold(Duration::from_millis(20))
+            .with_deep_sleep_verify_interval(Duration::from_millis(30))
+            .with_checker(Box::new(ScriptedChecker {
+                results: std::sync::Mutex::new(VecDeque::from([
+                    Ok(()),
+                    Err(GuardianError::ChainIntegrityError("merkle mismatch".into())),
+                ])),
+            }));
+        
+        let result = guardian.run_sentinel().await;
+        assert!(matches!(result, Err(GuardianError::ChainIntegrityError(_))));
+        
+        let log = guardian.event_log();
+        let kinds: Vec<_> = log.iter().map(|e| e.kind.clone()).collect();
+        assert_eq!(kinds, vec![
+            SentinelEventKind::Started,
+            SentinelEventKind::ModeChanged { from: SentinelMode::Active, to: SentinelMode::DeepSleep },
+            SentinelEventKind::VerificationPassed { verification: 1 },
+            SentinelEventKind::VerificationFailed {
+                verification: 2,
+                attempt: 1,
+                error: "Chain integrity error: merkle mismatch".into(),
+                fatal: true,
+            },
+            SentinelEventKind::ModeChanged { from: SentinelMode::DeepSleep, to: SentinelMode::Emergency },
+        ]);
+        assert!(log.windows(2).all(|w| w[0].timestamp_ms <= w[1].timestamp_ms));
+        
+        // Bounded: only the newest events survive
+        let mut guardian = SovereignGuardian::new().with_event_log_capacity(2);
+        guardian.set_mode(SentinelMode::DeepSleep);
+        guardian.set_mode(SentinelMode::Active);
+        guardian.set_mode(SentinelMode::Emergency);
+        let kinds: Vec<_> = guardian.event_log().into_iter().map(|e| e.kind).collect();
+        assert_eq!(kinds, vec![
+            SentinelEventKind::ModeChanged { from: SentinelMode::DeepSleep, to: SentinelMode::Active },
+            SentinelEventKind::ModeChanged { from: SentinelMode::Active, to: SentinelMode::Emergency },
+        ]);
+    }
+    
+    #[test]
+    fn test_sentinel_event_serializes_tagged() {
+        let event = SentinelEvent {
+            timestamp_ms: 1_700_000_000_000,
+            kind: SentinelEventKind::ModeChanged { from: SentinelMode::Active, to: SentinelMode::Emergency },
+        };
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(
+            json,
+            r#"{"timestamp_ms":1700000000000,"event":"mode_changed","from":"Active","to":"Emergency"}"#
+        );
+    }
 }
@@ -75,6 +75,14 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     println!("--------------------------------------------------");
     println!("📅 February 2026 | OpenClaw Integration Active");
 
+    // --- Manifest self-test: refuse to boot on an inconsistent manifest ---
+    if let Err(violations) = axiom_core::guardian::SovereignInvariants::self_test() {
+        for violation in &violations {
+            eprintln!("❌ Safety manifest violation: {}", violation);
+        }
+        return Err(format!("Safety manifest self-test failed ({} violations)", violations.len()).into());
+    }
+
     // --- Network Diagnostics: Check bootstrap connectivity on startup ---
     network::check_bootstrap_connectivity();
 